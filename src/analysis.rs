@@ -1,12 +1,13 @@
 use crate::{
     Command, CommandResult, Runner,
-    baseline::{BaselineRecord, annotate, outlier_findings},
+    baseline::{BaselineRecord, Seasonality, annotate_seasonal, outlier_findings},
     collector::{CollectCtx, Collector},
-    finding::{Finding, Severity, sort_findings},
+    finding::{Finding, InhibitedFinding, Severity, inhibit, sort_findings},
     pattern::PatternEngine,
-    rule::RuleEngine,
+    rule::{RuleEngine, near_miss::NearMiss},
     runner,
-    signal::Signal,
+    signal::{Signal, catalog},
+    silence::{Silence, SilencedFinding, silence},
 };
 
 pub use crate::finding::ThresholdInfo;
@@ -16,7 +17,7 @@ pub use crate::report_context::{
 
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Debug, path::PathBuf, time::Duration};
+use std::{collections::HashMap, fmt::Debug, path::PathBuf, sync::Arc, time::Duration};
 use thiserror::Error;
 
 /// Error type
@@ -47,6 +48,11 @@ pub struct Analysis<'a, I: IntoIterator<Item = &'a Command> + Copy> {
     pattern_engine: Option<PatternEngine>,
     cgroup_path: Option<PathBuf>,
     baseline_records: Vec<BaselineRecord>,
+    baseline_min_history: usize,
+    baseline_seasonality: Option<Seasonality>,
+    previous_run: Option<Arc<BaselineRecord>>,
+    silences: Vec<Silence>,
+    near_miss_margin: Option<f64>,
     sample_duration: Option<Duration>,
     sample_interval: Option<Duration>,
 }
@@ -64,6 +70,11 @@ impl<'a, I: IntoIterator<Item = &'a Command> + Copy> Analysis<'a, I> {
             pattern_engine: None,
             cgroup_path: None,
             baseline_records: Vec::new(),
+            baseline_min_history: 1,
+            baseline_seasonality: None,
+            previous_run: None,
+            silences: Vec::new(),
+            near_miss_margin: None,
             sample_duration: None,
             sample_interval: None,
         }
@@ -130,12 +141,69 @@ impl<'a, I: IntoIterator<Item = &'a Command> + Copy> Analysis<'a, I> {
         }
     }
 
+    /// Only compare signals with at least `min_history` values in the
+    /// baseline records; the others get no baseline stats and no outlier
+    /// findings.
+    pub fn with_baseline_min_history(self, min_history: usize) -> Self {
+        Analysis {
+            baseline_min_history: min_history,
+            ..self
+        }
+    }
+
+    /// Compare each signal against the baseline records captured in the
+    /// same hour of the day or week, falling back to all records when that
+    /// bucket holds too few.
+    pub fn with_baseline_seasonality(self, seasonality: Seasonality) -> Self {
+        Analysis {
+            baseline_seasonality: Some(seasonality),
+            ..self
+        }
+    }
+
+    /// Install the most recent rolling baseline record, which `delta(...)`
+    /// predicates compare the current signals against.
+    pub fn with_previous_run(self, record: BaselineRecord) -> Self {
+        Analysis {
+            previous_run: Some(Arc::new(record)),
+            ..self
+        }
+    }
+
+    /// Install silences. Findings an active silence covers on the analysed
+    /// host move to `AnalysisReport::silenced`, after inhibition.
+    pub fn with_silences(self, silences: Vec<Silence>) -> Self {
+        Analysis { silences, ..self }
+    }
+
+    /// Report rules and patterns that did not fire but came within
+    /// `margin` percent of a threshold, or had most of their comparisons
+    /// hold, in `AnalysisReport::near_misses`.
+    pub fn with_near_misses(self, margin: f64) -> Self {
+        Analysis {
+            near_miss_margin: Some(margin),
+            ..self
+        }
+    }
+
     pub fn run(&self, context: Context) -> Result<AnalysisReport> {
         let hostinfo_results = self.run_commands(self.hostinfos)?;
         let command_results = self.run_commands_rep(self.commands, self.repetitions)?;
 
         let first_rep = command_results.first().map(|v| v.as_slice()).unwrap_or(&[]);
-        let (signals, findings, checked_ok) = self.run_diagnostics(first_rep);
+        let Diagnostics {
+            signals,
+            findings,
+            inhibited,
+            checked_ok,
+            near_misses,
+        } = self.run_diagnostics(first_rep, &context);
+        let (findings, silenced) = silence(
+            findings,
+            &self.silences,
+            context.hostname(),
+            context.date_time().date_naive(),
+        );
         let signal_thresholds = self
             .rule_engine
             .as_ref()
@@ -150,6 +218,9 @@ impl<'a, I: IntoIterator<Item = &'a Command> + Copy> Analysis<'a, I> {
             max_parallel_commands: self.max_parallel_commands,
             signals,
             findings,
+            inhibited,
+            silenced,
+            near_misses,
             checked_ok,
             signal_thresholds,
             vital_signs: VitalSigns::default(),
@@ -159,16 +230,17 @@ impl<'a, I: IntoIterator<Item = &'a Command> + Copy> Analysis<'a, I> {
         })
     }
 
-    fn run_diagnostics(&self, command_results: &[CommandResult]) -> (Vec<Signal>, Vec<Finding>, Vec<String>) {
+    fn run_diagnostics(&self, command_results: &[CommandResult], context: &Context) -> Diagnostics {
         if self.collectors.is_empty() && self.rule_engine.is_none() && self.baseline_records.is_empty() {
-            return (Vec::new(), Vec::new(), Vec::new());
+            return Diagnostics::default();
         }
         let ctx = CollectCtx {
             duration: self.sample_duration,
             interval: self.sample_interval,
             cgroup_path: self.cgroup_path.clone(),
-            baseline: None,
+            baseline: self.previous_run.clone(),
             cpu_count: std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            uname: Some(context.uname().to_string()),
         };
         let mut signals: Vec<Signal> = Vec::new();
 
@@ -193,14 +265,29 @@ impl<'a, I: IntoIterator<Item = &'a Command> + Copy> Analysis<'a, I> {
             }
         }
         if !self.baseline_records.is_empty() {
-            annotate(&mut signals, &self.baseline_records);
+            annotate_seasonal(
+                &mut signals,
+                &self.baseline_records,
+                self.baseline_min_history,
+                self.baseline_seasonality,
+            );
         }
         let (mut findings, checked_ok) = match &self.rule_engine {
             Some(engine) => engine.run(&signals, &ctx, &source_map),
             None => (Vec::new(), Vec::new()),
         };
+        let mut near_misses = Vec::new();
+        if let Some(margin) = self.near_miss_margin {
+            if let Some(engine) = &self.rule_engine {
+                near_misses.extend(engine.near_misses(&signals, &ctx, margin));
+            }
+            if let Some(pe) = &self.pattern_engine {
+                near_misses.extend(pe.near_misses(&signals, &findings, &ctx, margin));
+            }
+        }
         if let Some(pe) = &self.pattern_engine {
-            findings.extend(pe.run(&signals, &ctx));
+            let pattern_findings = pe.run_with_findings(&signals, &findings, &ctx);
+            findings.extend(pattern_findings);
         }
         if !self.baseline_records.is_empty() {
             findings.extend(outlier_findings(&signals));
@@ -208,7 +295,20 @@ impl<'a, I: IntoIterator<Item = &'a Command> + Copy> Analysis<'a, I> {
         if !findings.is_empty() {
             sort_findings(&mut findings);
         }
-        (signals, findings, checked_ok)
+        let mut inhibits = self.rule_engine.as_ref().map(|e| e.inhibits()).unwrap_or_default();
+        if let Some(pe) = &self.pattern_engine {
+            for (id, patterns) in pe.inhibits() {
+                inhibits.entry(id).or_default().extend(patterns);
+            }
+        }
+        let (findings, inhibited) = inhibit(findings, &inhibits);
+        Diagnostics {
+            signals,
+            findings,
+            inhibited,
+            checked_ok,
+            near_misses,
+        }
     }
 
     fn run_commands_rep(&self, commands: I, repetitions: usize) -> Result<Vec<Vec<CommandResult>>> {
@@ -228,6 +328,16 @@ impl<'a, I: IntoIterator<Item = &'a Command> + Copy> Analysis<'a, I> {
     }
 }
 
+/// Output of the diagnostic pipeline, before silences apply.
+#[derive(Default)]
+struct Diagnostics {
+    signals: Vec<Signal>,
+    findings: Vec<Finding>,
+    inhibited: Vec<InhibitedFinding>,
+    checked_ok: Vec<String>,
+    near_misses: Vec<NearMiss>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AnalysisReport {
     pub(crate) context: Context,
@@ -239,6 +349,17 @@ pub struct AnalysisReport {
     pub(crate) signals: Vec<Signal>,
     #[serde(default)]
     pub(crate) findings: Vec<Finding>,
+    /// Findings suppressed by the `inhibits` of another finding; not part of
+    /// `findings`.
+    #[serde(default)]
+    pub(crate) inhibited: Vec<InhibitedFinding>,
+    /// Findings covered by an active silence; not part of `findings`.
+    #[serde(default)]
+    pub(crate) silenced: Vec<SilencedFinding>,
+    /// Rules and patterns that did not fire but came close, with
+    /// `--near-miss`.
+    #[serde(default)]
+    pub(crate) near_misses: Vec<NearMiss>,
     #[serde(default)]
     pub(crate) checked_ok: Vec<String>,
     #[serde(default)]
@@ -269,6 +390,9 @@ impl AnalysisReport {
             max_parallel_commands,
             signals: Vec::new(),
             findings: Vec::new(),
+            inhibited: Vec::new(),
+            silenced: Vec::new(),
+            near_misses: Vec::new(),
             checked_ok: Vec::new(),
             signal_thresholds: HashMap::new(),
             vital_signs: VitalSigns::default(),
@@ -299,6 +423,9 @@ impl AnalysisReport {
             max_parallel_commands,
             signals,
             findings,
+            inhibited: Vec::new(),
+            silenced: Vec::new(),
+            near_misses: Vec::new(),
             checked_ok,
             signal_thresholds: HashMap::new(),
             vital_signs: VitalSigns::default(),
@@ -308,6 +435,11 @@ impl AnalysisReport {
         }
     }
 
+    pub fn with_inhibited(mut self, inhibited: Vec<InhibitedFinding>) -> Self {
+        self.inhibited = inhibited;
+        self
+    }
+
     pub fn with_flamegraph(mut self, svg: String) -> Self {
         self.flamegraph_svg = Some(svg);
         self
@@ -345,9 +477,39 @@ impl AnalysisReport {
         &self.findings
     }
 
+    pub fn inhibited(&self) -> &[InhibitedFinding] {
+        &self.inhibited
+    }
+
+    pub fn silenced(&self) -> &[SilencedFinding] {
+        &self.silenced
+    }
+
+    pub fn near_misses(&self) -> &[NearMiss] {
+        &self.near_misses
+    }
+
     pub fn checked_ok(&self) -> &[String] {
         &self.checked_ok
     }
+
+    /// The `CollectCtx` that `host.*` predicate paths resolve from when
+    /// re-evaluating rules against this report: the analysed host's uname and
+    /// its `host.cpu_count` signal. Sampling settings are not recorded.
+    pub fn collect_ctx(&self) -> CollectCtx {
+        let cpu_count = self
+            .signals
+            .iter()
+            .find(|s| s.id == "host.cpu_count")
+            .and_then(|s| s.value.as_f64())
+            .map(|n| n as usize)
+            .unwrap_or(0);
+        CollectCtx {
+            cpu_count,
+            uname: Some(self.context.uname().to_string()),
+            ..CollectCtx::default()
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -489,11 +651,12 @@ const USE_DIMENSIONS: &[(&str, &str)] = &[
     ("network", "errors"),
 ];
 
-/// Compute USE coverage from command results.
+/// Compute USE coverage from command results and collected signals.
 ///
 /// Always returns all 12 (resource × aspect) entries. An entry is covered when
-/// at least one command with that use_dimension returned a non-SkippedMissing result.
-pub fn compute_use_coverage(results: &[CommandResult]) -> Vec<UseCoverageEntry> {
+/// at least one command with that use_dimension returned a non-SkippedMissing
+/// result, or a signal was collected whose `signal::catalog` entry measures it.
+pub fn compute_use_coverage(results: &[CommandResult], signals: &[Signal]) -> Vec<UseCoverageEntry> {
     use std::collections::HashMap;
     let mut covered: HashMap<(String, String), bool> = HashMap::new();
 
@@ -515,6 +678,14 @@ pub fn compute_use_coverage(results: &[CommandResult]) -> Vec<UseCoverageEntry>
         }
     }
 
+    for dim in signals
+        .iter()
+        .filter_map(|s| catalog::lookup(&s.id))
+        .filter_map(|spec| spec.use_dimension())
+    {
+        covered.insert((dim.resource, dim.aspect), true);
+    }
+
     USE_DIMENSIONS
         .iter()
         .map(|(resource, aspect)| {
@@ -1,8 +1,9 @@
 //! Baselines + outlier detection (SDD §113–§116).
 //!
 //! Two flavours of baseline:
-//!   - **Named** (`record(name)` → `<dir>/<name>.json`): a snapshot the user
-//!     captures explicitly with `usereport baseline record --name green`.
+//!   - **Named** (`record(name)` → `<dir>/<name>.json`): snapshots the user
+//!     captures explicitly with `usereport baseline record --name green`,
+//!     one per run with `--runs N`, or combines with `baseline merge`.
 //!   - **Rolling** (`<dir>/_rolling.jsonl`): a sliding window of the last
 //!     `baseline_rolling_n` runs, appended automatically.
 //!
@@ -10,11 +11,24 @@
 //! p95, MAD, and z_score derived from the records. `outlier_findings(&signals)`
 //! turns annotated z-scores into auto findings (`|z|>Z_WARN_THRESHOLD` → warn,
 //! `|z|>Z_CRIT_THRESHOLD` → crit).
+//!
+//! With a `Seasonality`, a signal is only compared against records captured
+//! in the same hour of the day (or of the week), so a nightly batch job is
+//! compared with previous nights rather than with the afternoon.
 
 // Modified z-score thresholds per Iglewicz & Hoaglin (1993)
 const Z_WARN_THRESHOLD: f64 = 3.5;
 const Z_CRIT_THRESHOLD: f64 = 7.0;
 
+/// Fewest values a seasonal bucket needs before it is used instead of the
+/// whole history.
+const MIN_BUCKET_HISTORY: usize = 3;
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Local, Timelike};
+use serde::Serialize;
+
 use crate::finding::{Evidence, Finding, FindingKind, Severity};
 use crate::signal::{BaselineStats, Signal, SignalValue};
 
@@ -26,19 +40,64 @@ pub use store::{BaselineRecord, BaselineStore};
 
 /// Annotate each signal in-place with `BaselineStats` derived from the given
 /// rolling/named records. Signals not present in any record are left
-/// unannotated.
+/// unannotated, as are signals whose labels differ from the recorded ones,
+/// e.g. `cgroup.memory_bytes` of another cgroup.
 pub fn annotate(signals: &mut [Signal], records: &[BaselineRecord]) {
+    annotate_seasonal(signals, records, 1, None);
+}
+
+/// How baseline records are grouped by the time they were captured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Seasonality {
+    /// By hour of the day.
+    Hourly,
+    /// By hour of the week, e.g. Mondays 03:00–04:00.
+    Weekly,
+}
+
+impl Seasonality {
+    /// The bucket `at` falls in: its hour of the day, or of the week.
+    pub fn bucket(self, at: &DateTime<Local>) -> u32 {
+        match self {
+            Seasonality::Hourly => at.hour(),
+            Seasonality::Weekly => at.weekday().num_days_from_monday() * 24 + at.hour(),
+        }
+    }
+}
+
+/// Like `annotate`, with two refinements:
+///
+/// - Signals with fewer than `min_history` recorded values are left
+///   unannotated: a handful of values gives a median and MAD that any
+///   ordinary fluctuation deviates from.
+/// - With a `seasonality`, a signal is compared against the records from
+///   the bucket it was collected in. When that bucket holds fewer than
+///   `max(min_history, 3)` values, the whole history is used instead.
+pub fn annotate_seasonal(
+    signals: &mut [Signal],
+    records: &[BaselineRecord],
+    min_history: usize,
+    seasonality: Option<Seasonality>,
+) {
     if records.is_empty() {
         return;
     }
     for sig in signals.iter_mut() {
         let mut history: Vec<f64> = Vec::with_capacity(records.len());
+        let mut seasonal: Vec<f64> = Vec::new();
+        let bucket = seasonality.map(|s| (s, s.bucket(&sig.at)));
         for r in records {
-            if let Some(v) = r.signals.get(&sig.id) {
-                history.push(*v);
+            if let Some(v) = r.value_of(sig) {
+                history.push(v);
+                if bucket.is_some_and(|(s, b)| r.bucket(s) == b) {
+                    seasonal.push(v);
+                }
             }
         }
-        if history.is_empty() {
+        if seasonal.len() >= min_history.max(MIN_BUCKET_HISTORY) {
+            history = seasonal;
+        }
+        if history.is_empty() || history.len() < min_history {
             continue;
         }
         let p50 = median(&history).unwrap_or(0.0);
@@ -59,6 +118,37 @@ pub fn annotate(signals: &mut [Signal], records: &[BaselineRecord]) {
     }
 }
 
+/// Median and MAD of one signal across the runs of a baseline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SignalSummary {
+    pub id: String,
+    /// Number of runs that recorded the signal.
+    pub runs: usize,
+    pub median: f64,
+    pub mad: f64,
+}
+
+/// Per-signal median and MAD over `records`, sorted by ID. A MAD of 0 means
+/// the signal did not vary across the runs: any deviation from it has a
+/// z-score of 0, so it never produces an outlier.
+pub fn summarize(records: &[BaselineRecord]) -> Vec<SignalSummary> {
+    let mut values: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+    for r in records {
+        for (id, v) in &r.signals {
+            values.entry(id).or_default().push(*v);
+        }
+    }
+    values
+        .into_iter()
+        .map(|(id, history)| SignalSummary {
+            id: id.to_string(),
+            runs: history.len(),
+            median: median(&history).unwrap_or(0.0),
+            mad: mad(&history).unwrap_or(0.0),
+        })
+        .collect()
+}
+
 /// Per SDD §116: signals whose `|z_score| > Z_WARN_THRESHOLD` produce automatic
 /// warn findings; `|z_score| > Z_CRIT_THRESHOLD` produces crit findings.
 /// Findings cite the signal id.
@@ -81,6 +171,7 @@ pub fn outlier_findings(signals: &[Signal]) -> Vec<Finding> {
             signal_id: sig.id.clone(),
             observed: sig.value.clone(),
             source_commands: Vec::new(),
+            detail: None,
         }];
         findings.push(Finding {
             id: format!("baseline.outlier.{}", sig.id),
@@ -88,7 +179,7 @@ pub fn outlier_findings(signals: &[Signal]) -> Vec<Finding> {
             severity,
             summary: format!(
                 "{} is significantly outside its normal range (z-score: {:.2}, baseline median: {:.3})",
-                sig.id,
+                sig.key(),
                 baseline.z_score.abs(),
                 baseline.p50
             ),
@@ -109,7 +200,7 @@ pub fn outlier_findings(signals: &[Signal]) -> Vec<Finding> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::signal::{BaselineStats, Signal, SignalValue, Unit};
+    use crate::signal::{BaselineStats, Labels, Signal, SignalValue, Unit};
     use chrono::Local;
 
     fn signal_with_z(id: &str, z: f64) -> Signal {
@@ -121,6 +212,7 @@ mod tests {
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         };
         s.baseline = Some(BaselineStats {
             p50: 1.0,
@@ -131,6 +223,108 @@ mod tests {
         s
     }
 
+    #[test]
+    fn annotate_skips_history_recorded_with_other_labels() {
+        let observed = |cgroup: &str| {
+            Signal {
+                value: SignalValue::F64(100.0),
+                baseline: None,
+                ..signal_with_z("cgroup.memory_bytes", 0.0)
+            }
+            .with_label("cgroup", cgroup)
+        };
+        let records: Vec<BaselineRecord> = [10.0, 11.0, 12.0]
+            .iter()
+            .map(|v| {
+                BaselineRecord::from_signals(&[Signal {
+                    value: SignalValue::F64(*v),
+                    ..observed("/sys/fs/cgroup/app")
+                }])
+            })
+            .collect();
+
+        let mut signals = vec![observed("/sys/fs/cgroup/app"), observed("/sys/fs/cgroup/db")];
+        annotate(&mut signals, &records);
+        assert_eq!(signals[0].baseline.as_ref().map(|b| b.p50), Some(11.0));
+        assert!(signals[1].baseline.is_none(), "history of another cgroup");
+    }
+
+    #[test]
+    fn seasonal_annotate_compares_within_the_hour_bucket() {
+        use chrono::TimeZone;
+        // Mondays to Fridays, 12–16 October 2026.
+        let at = |day: u32, hour: u32| Local.with_ymd_and_hms(2026, 10, day, hour, 0, 0).earliest().unwrap();
+        let record = |day: u32, hour: u32, v: f64| {
+            let mut r = BaselineRecord::from_signals(&[]);
+            r.captured_at = at(day, hour);
+            r.signals.insert("cpu.usr_pct".to_string(), v);
+            r
+        };
+        // Busy at 03:00 during the nightly job, idle in the afternoon.
+        let mut records: Vec<BaselineRecord> = (12..=16).map(|d| record(d, 3, 90.0 + d as f64)).collect();
+        records.extend((12..=16).map(|d| record(d, 15, 5.0 + d as f64 / 10.0)));
+        let observed = |hour: u32| {
+            vec![Signal {
+                value: SignalValue::F64(105.0),
+                at: at(17, hour),
+                baseline: None,
+                ..signal_with_z("cpu.usr_pct", 0.0)
+            }]
+        };
+
+        let mut signals = observed(3);
+        annotate_seasonal(&mut signals, &records, 1, Some(Seasonality::Hourly));
+        let b = signals[0].baseline.as_ref().unwrap();
+        assert_eq!(b.p50, 104.0);
+        assert!(b.z_score.abs() < Z_WARN_THRESHOLD, "z = {}", b.z_score);
+
+        // Without seasonality the afternoons pull the median down.
+        let mut signals = observed(3);
+        annotate_seasonal(&mut signals, &records, 1, None);
+        assert!(signals[0].baseline.as_ref().unwrap().p50 < 90.0);
+
+        // No record from 09:00: the whole history is used.
+        let mut signals = observed(9);
+        annotate_seasonal(&mut signals, &records, 1, Some(Seasonality::Hourly));
+        assert!(signals[0].baseline.as_ref().unwrap().p50 < 90.0);
+
+        // Only one previous Saturday 03:00: too sparse for a weekly bucket.
+        assert_eq!(Seasonality::Weekly.bucket(&at(17, 3)), 5 * 24 + 3);
+        let mut signals = observed(3);
+        annotate_seasonal(&mut signals, &records, 1, Some(Seasonality::Weekly));
+        assert!(signals[0].baseline.as_ref().unwrap().p50 < 90.0);
+    }
+
+    #[test]
+    fn summarize_reports_median_and_mad_per_signal() {
+        let record = |cpu: f64, mem: Option<f64>| {
+            let mut r = BaselineRecord::from_signals(&[]);
+            r.signals.insert("cpu.usr_pct".to_string(), cpu);
+            if let Some(mem) = mem {
+                r.signals.insert("mem.free_pct".to_string(), mem);
+            }
+            r
+        };
+        let summary = summarize(&[record(10.0, Some(50.0)), record(12.0, None), record(20.0, Some(50.0))]);
+        assert_eq!(
+            summary,
+            vec![
+                SignalSummary {
+                    id: "cpu.usr_pct".to_string(),
+                    runs: 3,
+                    median: 12.0,
+                    mad: 2.0,
+                },
+                SignalSummary {
+                    id: "mem.free_pct".to_string(),
+                    runs: 2,
+                    median: 50.0,
+                    mad: 0.0,
+                },
+            ]
+        );
+    }
+
     #[test]
     fn z_below_warn_threshold_no_finding() {
         assert!(outlier_findings(&[signal_with_z("x", 3.49)]).is_empty());
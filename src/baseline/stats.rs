@@ -5,6 +5,8 @@
 //! and `mad`; `z_score` returns `0.0` when `mad == 0` (avoid divide-by-zero;
 //! a flat baseline cannot be exceeded).
 
+use std::time::Duration;
+
 use crate::signal::{SampleStats, Trend};
 
 /// Median of a slice of `f64`. `None` for empty input or all-non-finite input.
@@ -67,11 +69,18 @@ pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
     }
 }
 
-/// Compute `SampleStats` (min, max, p50, p95, trend) from a slice of f64
-/// samples. Returns `None` for empty input. Trend is determined by linear
-/// regression slope: |slope| < 5% of |p50| → Flat (with a floor of 0.01 when
-/// p50 ≈ 0); positive slope → Rising; negative slope → Falling.
+/// Compute `SampleStats` from a slice of f64 samples taken one second apart;
+/// see `sample_stats_every`.
 pub fn sample_stats(values: &[f64]) -> Option<SampleStats> {
+    sample_stats_every(values, Duration::from_secs(1))
+}
+
+/// Compute `SampleStats` from a slice of f64 samples taken `interval` apart.
+/// Returns `None` for empty input. `slope` is the least-squares regression
+/// slope in units per second. Trend is determined by the slope per sample:
+/// |slope| < 5% of |p50| → Flat (with a floor of 0.01 when p50 ≈ 0);
+/// positive slope → Rising; negative slope → Falling.
+pub fn sample_stats_every(values: &[f64], interval: Duration) -> Option<SampleStats> {
     if values.is_empty() {
         return None;
     }
@@ -80,21 +89,36 @@ pub fn sample_stats(values: &[f64]) -> Option<SampleStats> {
     let p50 = percentile(values, 50.0)?;
     let p95 = percentile(values, 95.0)?;
     let p99 = percentile(values, 99.0)?;
-    let trend = linear_trend(values, p50);
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let stddev = if values.len() < 2 {
+        0.0
+    } else {
+        (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+    };
+    let per_sample = regression_slope(values);
+    let secs = interval.as_secs_f64();
     Some(SampleStats {
         min,
         max,
         p50,
         p95,
         p99,
-        trend,
+        trend: trend_of(per_sample, p50),
+        mean: Some(mean),
+        stddev: Some(stddev),
+        cv: Some(if mean == 0.0 { 0.0 } else { stddev / mean.abs() }),
+        slope: Some(if secs > 0.0 { per_sample / secs } else { 0.0 }),
+        count: Some(values.len()),
     })
 }
 
-fn linear_trend(values: &[f64], p50: f64) -> Trend {
+/// Least-squares slope of `values` against their index; 0 for fewer than
+/// two values.
+fn regression_slope(values: &[f64]) -> f64 {
     let n = values.len();
     if n < 2 {
-        return Trend::Flat;
+        return 0.0;
     }
     let n_f = n as f64;
     let mean_x = (n_f - 1.0) / 2.0;
@@ -106,10 +130,10 @@ fn linear_trend(values: &[f64], p50: f64) -> Trend {
         num += x * (v - mean_y);
         den += x * x;
     }
-    if den == 0.0 {
-        return Trend::Flat;
-    }
-    let slope = num / den;
+    num / den
+}
+
+fn trend_of(slope: f64, p50: f64) -> Trend {
     let flat_threshold = (p50.abs() * 0.05).max(0.01);
     if slope.abs() < flat_threshold {
         Trend::Flat
@@ -175,4 +199,26 @@ mod tests {
         assert_eq!(percentile(&v, 0.0), Some(1.0));
         assert_eq!(percentile(&v, 100.0), Some(5.0));
     }
+
+    #[test]
+    fn sample_stats_moments_and_slope() {
+        let v = [100.0, 110.0, 120.0, 130.0];
+        let stats = sample_stats_every(&v, Duration::from_secs(5)).unwrap();
+        assert_eq!(stats.count, Some(4));
+        assert_eq!(stats.mean, Some(115.0));
+        let stddev = stats.stddev.unwrap();
+        assert!((stddev - 12.909_944).abs() < 1e-6, "stddev = {}", stddev);
+        let cv = stats.cv.unwrap();
+        assert!((cv - 0.112_260).abs() < 1e-6, "cv = {}", cv);
+        // 10 per sample, 5 s apart.
+        assert_eq!(stats.slope, Some(2.0));
+        assert_eq!(stats.trend, Trend::Rising);
+        assert_eq!(sample_stats(&v).unwrap().slope, Some(10.0));
+
+        let single = sample_stats(&[0.0]).unwrap();
+        assert_eq!(
+            (single.stddev, single.cv, single.slope),
+            (Some(0.0), Some(0.0), Some(0.0))
+        );
+    }
 }
@@ -1,7 +1,9 @@
 //! Persistent store for named + rolling baselines.
 //!
 //! Named baselines: `<dir>/<name>.json` — single JSON object capturing the
-//! signal id → value map at record time.
+//! signal id → value map at record time. A baseline of several runs keeps
+//! them under `runs`, with each signal's median across the runs in the
+//! top-level map, so a single-run reader still gets a usable record.
 //! Rolling baselines: `<dir>/_rolling.jsonl` — append-only JSONL, one record
 //! per line. Concurrent writes are serialised via `rustix::fs::flock` (SDD
 //! §116). The file is pruned to `window_n` records on every append.
@@ -15,7 +17,7 @@ use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::signal::{Signal, SignalValue};
+use crate::signal::{Labels, Signal, SignalValue};
 
 const ROLLING_FILENAME: &str = "_rolling.jsonl";
 
@@ -47,19 +49,94 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 pub struct BaselineRecord {
     pub captured_at: DateTime<Local>,
     pub signals: HashMap<String, f64>,
+    /// Labels of the labelled signals, by signal ID. Empty in records
+    /// captured before labels existed.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, Labels>,
 }
 
 impl BaselineRecord {
     pub fn from_signals(signals: &[Signal]) -> Self {
         let mut map = HashMap::new();
+        let mut labels = HashMap::new();
         for s in signals {
             if let Some(v) = signal_to_f64(&s.value) {
                 map.insert(s.id.clone(), v);
+                if !s.labels.is_empty() {
+                    labels.insert(s.id.clone(), s.labels.clone());
+                }
             }
         }
         BaselineRecord {
             captured_at: Local::now(),
             signals: map,
+            labels,
+        }
+    }
+
+    /// The seasonal bucket this record was captured in.
+    pub fn bucket(&self, seasonality: super::Seasonality) -> u32 {
+        seasonality.bucket(&self.captured_at)
+    }
+
+    /// The recorded value of `signal`: the value under its ID, provided
+    /// the labels recorded with it match. Records without labels for the ID
+    /// match any labels.
+    pub fn value_of(&self, signal: &Signal) -> Option<f64> {
+        let v = self.signals.get(&signal.id)?;
+        match self.labels.get(&signal.id) {
+            Some(labels) if *labels != signal.labels => None,
+            _ => Some(*v),
+        }
+    }
+}
+
+/// A named baseline as stored: the summary record, plus the runs it was
+/// built from when there is more than one.
+#[derive(Debug, Serialize, Deserialize)]
+struct NamedBaseline {
+    #[serde(flatten)]
+    summary: BaselineRecord,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    runs: Vec<BaselineRecord>,
+}
+
+impl NamedBaseline {
+    fn from_runs(records: &[BaselineRecord]) -> Self {
+        if records.len() <= 1 {
+            return NamedBaseline {
+                summary: records
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| BaselineRecord::from_signals(&[])),
+                runs: Vec::new(),
+            };
+        }
+        let mut labels = HashMap::new();
+        for r in records {
+            for (id, l) in &r.labels {
+                labels.entry(id.clone()).or_insert_with(|| l.clone());
+            }
+        }
+        let summary = BaselineRecord {
+            captured_at: records.iter().map(|r| r.captured_at).max().unwrap_or_else(Local::now),
+            signals: super::summarize(records)
+                .into_iter()
+                .map(|s| (s.id, s.median))
+                .collect(),
+            labels,
+        };
+        NamedBaseline {
+            summary,
+            runs: records.to_vec(),
+        }
+    }
+
+    fn into_runs(self) -> Vec<BaselineRecord> {
+        if self.runs.is_empty() {
+            vec![self.summary]
+        } else {
+            self.runs
         }
     }
 }
@@ -121,9 +198,13 @@ impl BaselineStore {
     }
 
     pub fn record(&self, name: &str, signals: &[Signal]) -> Result<()> {
+        self.record_runs(name, &[BaselineRecord::from_signals(signals)])
+    }
+
+    /// Store `records`, one per run, as the named baseline `name`.
+    pub fn record_runs(&self, name: &str, records: &[BaselineRecord]) -> Result<()> {
         self.ensure_dir()?;
-        let record = BaselineRecord::from_signals(signals);
-        let json = serde_json::to_vec_pretty(&record)?;
+        let json = serde_json::to_vec_pretty(&NamedBaseline::from_runs(records))?;
         let path = self.named_path(name);
         std::fs::write(&path, json).map_err(|e| Error::Io {
             path: path.clone(),
@@ -132,13 +213,21 @@ impl BaselineStore {
         Ok(())
     }
 
+    /// The named baseline as one record; for a baseline of several runs,
+    /// each signal's median across them.
     pub fn load(&self, name: &str) -> Result<Option<BaselineRecord>> {
+        Ok(self.load_named(name)?.map(|b| b.summary))
+    }
+
+    /// Every run of the named baseline, oldest first.
+    pub fn load_runs(&self, name: &str) -> Result<Option<Vec<BaselineRecord>>> {
+        Ok(self.load_named(name)?.map(NamedBaseline::into_runs))
+    }
+
+    fn load_named(&self, name: &str) -> Result<Option<NamedBaseline>> {
         let path = self.named_path(name);
         match std::fs::read(&path) {
-            Ok(bytes) => {
-                let r: BaselineRecord = serde_json::from_slice(&bytes)?;
-                Ok(Some(r))
-            }
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
             Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
             Err(e) => Err(Error::Io { path, source: e }),
         }
@@ -45,6 +45,11 @@ pub enum Error {
     /// baseline_rolling_n must be at least 1
     #[error("defaults.baseline_rolling_n must be >= 1, got {value}")]
     InvalidBaselineWindow { value: usize },
+    /// baseline_min_history must fit in the rolling window
+    #[error(
+        "defaults.baseline_min_history ({min}) exceeds defaults.baseline_rolling_n ({window}); the rolling baseline would never be used"
+    )]
+    InvalidBaselineMinHistory { min: usize, window: usize },
 }
 
 /// Result type
@@ -196,6 +201,12 @@ impl Config {
         if self.defaults.baseline_rolling_n == 0 {
             return Err(Error::InvalidBaselineWindow { value: 0 });
         }
+        if self.defaults.baseline_min_history > self.defaults.baseline_rolling_n {
+            return Err(Error::InvalidBaselineMinHistory {
+                min: self.defaults.baseline_min_history,
+                window: self.defaults.baseline_rolling_n,
+            });
+        }
         Ok(())
     }
 
@@ -308,6 +319,14 @@ pub struct Defaults {
     /// one record; older records are pruned when the file exceeds this count.
     #[serde(default = "default_baseline_rolling_n")]
     pub baseline_rolling_n: usize,
+    /// Baseline to compare every run against when `--baseline` is not
+    /// given: a named baseline, or `rolling` for the rolling window.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<String>,
+    /// Runs the rolling baseline must hold, and values a signal must have in
+    /// it, before the signal is compared against it.
+    #[serde(default = "default_baseline_min_history")]
+    pub baseline_min_history: usize,
 }
 
 impl Default for Defaults {
@@ -318,6 +337,8 @@ impl Default for Defaults {
             repetitions: 1,
             max_parallel_commands: 64,
             baseline_rolling_n: 24,
+            baseline: None,
+            baseline_min_history: 5,
         }
     }
 }
@@ -342,6 +363,10 @@ fn default_baseline_rolling_n() -> usize {
     Defaults::default().baseline_rolling_n
 }
 
+fn default_baseline_min_history() -> usize {
+    Defaults::default().baseline_min_history
+}
+
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone)]
 pub struct Hostinfo {
     pub commands: Vec<String>,
@@ -409,6 +434,26 @@ command = "uname -a"
         );
     }
 
+    #[test]
+    fn baseline_min_history_above_window_fails_validation() {
+        let config_txt = r#"
+[defaults]
+baseline_rolling_n = 4
+baseline_min_history = 5
+
+[[profile]]
+name = "default"
+commands = ["uname"]
+
+[[command]]
+name = "uname"
+command = "uname -a"
+"#;
+        let config = Config::from_str(config_txt).expect("syntax ok");
+        let msg = config.validate().unwrap_err().to_string();
+        assert!(msg.contains("baseline_min_history (5)"), "got: {msg}");
+    }
+
     #[test]
     fn defaults_baseline_rolling_n_is_24() {
         // SDD §116: rolling baseline window defaults to 24.
@@ -1,7 +1,7 @@
 use crate::{
     Analysis, AnalysisReport, Command, Config, Context, Renderer, ThreadRunner,
     analysis::{compute_use_coverage, compute_vital_signs},
-    baseline::BaselineStore,
+    baseline::{self, BaselineRecord, BaselineStore, Seasonality, SignalSummary},
     collector::{
         Collector, cgroup::CgroupCollector, cpu::CpuCollector, cpufreq::CpuFreqCollector, disk::DiskCollector,
         dmesg::DmesgCollector, host::HostCollector, interrupts::InterruptsCollector, memory::MemoryCollector,
@@ -10,10 +10,19 @@ use crate::{
     diff,
     finding::{Finding, Severity},
     llm::LlmOutput,
-    pattern::PatternEngine,
+    pattern::{Pattern, PatternEngine, PatternsLoadResult, PatternsLoader},
     renderer,
-    rule::{Rule, RuleEngine, RulesLoader, builtin::builtin_rules},
-    workload::load_workload_rules,
+    rule::{
+        Rule, RuleEngine, RuleSource, RulesLoadResult, RulesLoader, SignalIndex,
+        builtin::{builtin_rule_files, builtin_rules},
+        fixture::RuleTest,
+        lint::{LintIssue, Linter, Scope, ValueKind},
+        parse_rules_toml, parse_rules_with_tests, rule_files,
+        trace::Trace,
+    },
+    signal::catalog::{self, SignalSpec},
+    silence::{Silence, load_silences},
+    workload::{WORKLOADS, load_workload_rules},
 };
 #[cfg(feature = "bpf")]
 use crate::{collector::bpf::BpfCollector, rule::builtin::bpf_rules};
@@ -120,9 +129,30 @@ pub struct Opt {
     /// Annotate signals with a named baseline (loaded from
     /// ${XDG_DATA_HOME}/usereport/baselines/<NAME>.json) and emit
     /// auto-outlier findings (|z|>3.5 → warn, |z|>7.0 → crit).
+    /// `--baseline rolling` uses the last runs on this host instead.
     /// See also: 'usereport baseline record --name <NAME>' to create a baseline.
     #[arg(long, value_name = "NAME")]
     pub baseline: Option<String>,
+    /// Compare each signal only against baseline runs from the same hour of
+    /// the day (`hourly`) or of the week (`weekly`), falling back to all runs
+    /// when there are too few from that hour.
+    #[arg(long, value_name = "PERIOD", value_enum)]
+    pub baseline_seasonality: Option<Seasonality>,
+    /// Silences file of acknowledged findings (see README); defaults to
+    /// ${XDG_CONFIG_HOME}/usereport/silences.toml when that file exists.
+    /// Silenced findings are listed separately and do not count for --exit-on.
+    #[arg(long, value_name = "PATH")]
+    pub silences: Option<PathBuf>,
+    /// Directory of user pattern files; defaults to
+    /// ${XDG_CONFIG_HOME}/usereport/patterns.d. A pattern with the ID of a
+    /// built-in one replaces it.
+    #[arg(long, value_name = "DIR")]
+    pub patterns_dir: Option<PathBuf>,
+    /// List rules and patterns that did not fire but came close as watch
+    /// entries: a failing threshold within PCT percent (default 10), or most
+    /// comparisons holding.
+    #[arg(long, value_name = "PCT", num_args = 0..=1, default_missing_value = "10")]
+    pub near_miss: Option<f64>,
     /// Add or remove commands from selected profile by prefixing the command's name with '+' or
     /// '-', respectively, e.g., +uname -dmesg; you may need to use '--' to signify the end of the
     /// options
@@ -156,7 +186,7 @@ pub struct Opt {
 
 #[derive(Debug, clap::Subcommand)]
 pub enum Subcommand {
-    /// Manage named baselines (record / list / show / delete).
+    /// Manage named baselines (record / merge / list / show / delete).
     Baseline {
         #[command(subcommand)]
         action: BaselineAction,
@@ -183,11 +213,21 @@ pub enum Subcommand {
         output: OutputType,
     },
     /// Print the definition, what raises it, what to investigate, and links for a rule or signal ID.
-    /// When no ID is given, lists all known topics.
-    #[command(after_help = "Example: usereport explain net.retransmit_elevated")]
+    /// When no ID is given, lists all known topics. With --against, also traces how the rule's
+    /// predicate evaluates against the signals of a saved JSON report.
+    #[command(
+        after_help = "Examples:\n  usereport explain net.retransmit_elevated\n  usereport explain net.retransmit_elevated --against incident.json"
+    )]
     Explain {
         #[arg(value_name = "ID", help = "Rule ID, signal ID, or command name to explain")]
         id: Option<String>,
+        #[arg(
+            long,
+            value_name = "REPORT",
+            requires = "id",
+            help = "Evaluate the rule against the signals of a JSON report"
+        )]
+        against: Option<PathBuf>,
     },
     /// Check whether all binaries used by the selected profile(s) are installed on $PATH.
     #[command(after_help = "Example: usereport check --profile net")]
@@ -215,12 +255,53 @@ pub enum Subcommand {
         #[arg(long)]
         redact: bool,
     },
+    /// Work with rule and pattern definitions.
+    Rules {
+        #[command(subcommand)]
+        action: RulesAction,
+    },
+    /// List the signals the built-in collectors emit, with unit, kind and USE dimension.
+    #[command(
+        after_help = "Examples:\n  usereport signals\n  usereport signals 'disk.*'\n  usereport signals --resource memory"
+    )]
+    Signals {
+        /// Only signals whose ID matches this glob, e.g. `net.*`, or a concrete ID such as `disk.sda.util_pct`.
+        #[arg(value_name = "PATTERN")]
+        pattern: Option<String>,
+        /// Only signals measuring this USE resource, e.g. `cpu` or `disk`.
+        #[arg(long)]
+        resource: Option<String>,
+        /// Output format: `text` (default) or `json`.
+        #[arg(long, default_value = "text", value_parser = |s: &str| match s {
+            "text" => Ok(OutputType::Text),
+            "json" => Ok(OutputType::Json),
+            _ => Err(format!("valid values for signals --output: text, json; got '{}'", s)),
+        })]
+        output: OutputType,
+    },
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum RulesAction {
+    /// Statically check built-in rules, rules.d, workload packs, patterns and patterns.d for unknown
+    /// signals, impossible comparisons, unreachable tiers and duplicate IDs.
+    #[command(after_help = "Example: usereport rules lint")]
+    Lint,
+    /// Run the `[[rule.test]]` and `[[pattern.test]]` blocks embedded in rule and pattern files.
+    /// Without paths, tests the built-in rules, workload packs, rules.d, built-in patterns and
+    /// patterns.d.
+    #[command(after_help = "Example: usereport rules test ./rules")]
+    Test {
+        /// Rule or pattern files, or directories of `*.toml` files.
+        #[arg(value_name = "PATH")]
+        paths: Vec<PathBuf>,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
 pub enum BaselineAction {
     /// Capture the current run as a named baseline.
-    #[command(after_help = "Example: usereport baseline record --name prod-healthy")]
+    #[command(after_help = "Example: usereport baseline record --name prod-healthy --runs 10 --every 30s")]
     Record {
         #[arg(
             long,
@@ -232,6 +313,24 @@ pub enum BaselineAction {
         /// Overwrite an existing baseline with the same name.
         #[arg(long)]
         force: bool,
+        /// Number of runs to capture. Outliers need the spread of several
+        /// runs: a baseline of one run never produces any.
+        #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Time between the start of two runs (e.g. 30s, 5m).
+        #[arg(long, value_name = "INTERVAL", default_value = "30s")]
+        every: String,
+    },
+    /// Combine the runs of several named baselines into one.
+    #[command(after_help = "Example: usereport baseline merge --name green monday tuesday")]
+    Merge {
+        #[arg(long, value_name = "NAME", help = "Name of the merged baseline")]
+        name: String,
+        #[arg(value_name = "SOURCE", required = true, help = "Baselines whose runs to combine")]
+        sources: Vec<String>,
+        /// Overwrite an existing baseline with the same name.
+        #[arg(long)]
+        force: bool,
     },
     /// List stored baselines.
     #[command(after_help = "Example: usereport baseline list")]
@@ -240,11 +339,14 @@ pub enum BaselineAction {
         #[arg(long, default_value = "text", value_parser = clap::builder::PossibleValuesParser::new(["text", "json"]))]
         output: String,
     },
-    /// Show a stored baseline's contents.
-    #[command(after_help = "Example: usereport baseline show --name prod-healthy")]
+    /// Summarise a stored baseline: each signal's median and MAD across its runs.
+    #[command(after_help = "Example: usereport baseline show prod-healthy")]
     Show {
         #[arg(value_name = "NAME", help = "Name of the baseline to display")]
         name: String,
+        /// Output format: `text` (default), `json` (the summary) or `raw` (the stored runs, as JSON).
+        #[arg(long, default_value = "text", value_parser = clap::builder::PossibleValuesParser::new(["text", "json", "raw"]))]
+        output: String,
     },
     /// Delete a stored baseline.
     #[command(after_help = "Example: usereport baseline delete --name old-baseline --force")]
@@ -331,6 +433,19 @@ impl clap::ValueEnum for OutputType {
     }
 }
 
+impl clap::ValueEnum for Seasonality {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Seasonality::Hourly, Seasonality::Weekly]
+    }
+
+    fn to_possible_value(&self) -> Option<clap::builder::PossibleValue> {
+        Some(match self {
+            Seasonality::Hourly => clap::builder::PossibleValue::new("hourly"),
+            Seasonality::Weekly => clap::builder::PossibleValue::new("weekly"),
+        })
+    }
+}
+
 /// Exit-code policy controlled by `--exit-on`. See SDD §103.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
 pub enum ExitOn {
@@ -400,7 +515,7 @@ pub fn main() -> miette::Result<()> {
         return run_check(&config, profile.as_deref());
     }
 
-    if let Some(Subcommand::Explain { id }) = &opt.command {
+    if let Some(Subcommand::Explain { id, against }) = &opt.command {
         let config = opt
             .config
             .as_ref()
@@ -410,12 +525,34 @@ pub fn main() -> miette::Result<()> {
             .context("could not load configuration file")?;
         // Validate lightly — skip profile/command cross-validation since explain
         // should work even with partial configs.
+        let patterns_dir = opt.patterns_dir.as_deref();
         return match id.as_deref() {
-            Some(id_str) => run_explain(id_str, &config),
-            None => run_explain_list(&config),
+            Some(id_str) => run_explain(id_str, against.as_deref(), &config, patterns_dir),
+            None => run_explain_list(&config, patterns_dir),
         };
     }
 
+    if let Some(Subcommand::Rules {
+        action: RulesAction::Lint,
+    }) = &opt.command
+    {
+        let config = opt
+            .config
+            .as_ref()
+            .map(Config::from_file)
+            .unwrap_or_else(|| Config::from_str(defaults::CONFIG))
+            .into_diagnostic()
+            .context("could not load configuration file")?;
+        return run_rules_lint(&config, opt.patterns_dir.as_deref());
+    }
+
+    if let Some(Subcommand::Rules {
+        action: RulesAction::Test { paths },
+    }) = &opt.command
+    {
+        return run_rules_test(paths, opt.patterns_dir.as_deref());
+    }
+
     // Phase 2: subcommand dispatch (baseline / diff). The default code path
     // (no subcommand) preserves the existing report-generation behaviour.
     if let Some(cmd) = opt.command.as_ref() {
@@ -554,14 +691,12 @@ fn run_subcommand(cmd: &Subcommand) -> miette::Result<()> {
     match cmd {
         Subcommand::Baseline { action } => run_baseline(action),
         Subcommand::Diff { a, b, output } => run_diff(a, b, output),
-        Subcommand::Explain { id } => {
-            let config = Config::from_str(defaults::CONFIG).expect("builtin default config is always valid");
-            match id.as_deref() {
-                Some(id_str) => run_explain(id_str, &config),
-                None => run_explain_list(&config),
-            }
-        }
+        Subcommand::Explain { .. } => unreachable!("Explain is handled before run_subcommand"),
         Subcommand::Check { .. } => unreachable!("Check is handled before run_subcommand"),
+        Subcommand::Rules { action } => match action {
+            RulesAction::Lint => unreachable!("Lint is handled before run_subcommand"),
+            RulesAction::Test { .. } => unreachable!("Test is handled before run_subcommand"),
+        },
         Subcommand::Convert {
             input,
             output,
@@ -575,7 +710,68 @@ fn run_subcommand(cmd: &Subcommand) -> miette::Result<()> {
             output_file.as_deref(),
             *redact,
         ),
+        Subcommand::Signals {
+            pattern,
+            resource,
+            output,
+        } => run_signals(pattern.as_deref(), resource.as_deref(), output),
+    }
+}
+
+fn run_signals(pattern: Option<&str>, resource: Option<&str>, output: &OutputType) -> miette::Result<()> {
+    let specs = select_signals(pattern, resource);
+    let stdout = std::io::stdout();
+    let mut handle = stdout.lock();
+    match output {
+        OutputType::Json => {
+            serde_json::to_writer_pretty(&mut handle, &specs).into_diagnostic()?;
+            writeln!(handle).into_diagnostic()?;
+        }
+        _ => show_signals_inner(&specs, std::io::stdout().is_terminal(), &mut handle),
     }
+    Ok(())
+}
+
+/// Catalog entries whose ID matches `pattern` and that measure `resource`.
+pub fn select_signals(pattern: Option<&str>, resource: Option<&str>) -> Vec<&'static SignalSpec> {
+    catalog::builtin()
+        .filter(|spec| pattern.is_none_or(|p| crate::rule::segment_matches(p, spec.id) || spec.matches(p)))
+        .filter(|spec| resource.is_none_or(|r| spec.resource == Some(r)))
+        .collect()
+}
+
+pub fn show_signals_inner(specs: &[&SignalSpec], is_tty: bool, out: &mut dyn Write) {
+    use comfy_table::{Attribute, Cell};
+    let mut table = Table::new();
+    table.load_preset(comfy_table::presets::UTF8_FULL_CONDENSED);
+    table.set_header(vec![
+        Cell::new("ID").add_attribute(Attribute::Bold),
+        Cell::new("Kind").add_attribute(Attribute::Bold),
+        Cell::new("Unit").add_attribute(Attribute::Bold),
+        Cell::new("USE").add_attribute(Attribute::Bold),
+        Cell::new("Description").add_attribute(Attribute::Bold),
+    ]);
+    if is_tty {
+        table.enforce_styling();
+    }
+    for spec in specs {
+        let use_dim = match (spec.resource, spec.aspect) {
+            (Some(resource), Some(aspect)) => format!("{resource}/{aspect}"),
+            _ => "-".to_string(),
+        };
+        table.add_row(vec![
+            spec.id.to_string(),
+            spec.kind.as_str().to_string(),
+            if spec.unit.is_none() {
+                "-".to_string()
+            } else {
+                spec.unit.to_string()
+            },
+            use_dim,
+            spec.description.to_string(),
+        ]);
+    }
+    let _ = writeln!(out, "{table}");
 }
 
 fn run_check(config: &Config, profile_filter: Option<&str>) -> miette::Result<()> {
@@ -730,11 +926,15 @@ pub fn run_check_inner(
     Ok(missing)
 }
 
-fn collect_signals_for_baseline() -> Vec<crate::Signal> {
+fn collect_signals_for_baseline(run: u32, runs: u32) -> Vec<crate::Signal> {
     use crate::collector::{CollectCtx, Collector};
     let ctx = CollectCtx::default();
     if std::io::IsTerminal::is_terminal(&std::io::stderr()) {
-        eprintln!("Recording baseline: collecting signals...");
+        if runs > 1 {
+            eprintln!("Recording baseline: collecting signals (run {}/{})...", run, runs);
+        } else {
+            eprintln!("Recording baseline: collecting signals...");
+        }
     }
     let collectors: Vec<Box<dyn Collector>> = vec![
         Box::new(HostCollector::new()),
@@ -760,13 +960,19 @@ fn collect_signals_for_baseline() -> Vec<crate::Signal> {
 fn run_baseline(action: &BaselineAction) -> miette::Result<()> {
     let store = BaselineStore::xdg().map_err(|e| miette::miette!("locate baseline directory: {}", e))?;
     match action {
-        BaselineAction::Record { name, force } => {
+        BaselineAction::Record {
+            name,
+            force,
+            runs,
+            every,
+        } => {
             let label = name.as_deref().unwrap_or("default");
             if label.trim().is_empty() {
                 return Err(miette!(
                     "Baseline name is required — use --name <NAME> (e.g. --name prod-healthy)."
                 ));
             }
+            check_not_reserved(label)?;
             if !force {
                 let exists = store
                     .load(label)
@@ -777,18 +983,65 @@ fn run_baseline(action: &BaselineAction) -> miette::Result<()> {
                     return Err(miette!("baseline '{}' already exists; use --force to overwrite", label));
                 }
             }
-            let signals = collect_signals_for_baseline();
+            let every = parse_duration(every)?;
+            let mut records = Vec::new();
+            for run in 1..=*runs {
+                let started = std::time::Instant::now();
+                records.push(BaselineRecord::from_signals(&collect_signals_for_baseline(run, *runs)));
+                if run < *runs {
+                    std::thread::sleep(every.saturating_sub(started.elapsed()));
+                }
+            }
             store
-                .record(label, &signals)
+                .record_runs(label, &records)
                 .into_diagnostic()
                 .with_context(|| format!("record baseline '{}'", label))?;
             println!(
-                "recorded baseline '{}' at {}\nRun 'usereport --baseline {}' on future runs to compare against this baseline.",
+                "recorded baseline '{}' ({}) at {}\nRun 'usereport --baseline {}' on future runs to compare against this baseline.",
                 label,
+                runs_label(records.len()),
                 store.dir().join(format!("{}.json", label)).display(),
                 label
             );
         }
+        BaselineAction::Merge { name, sources, force } => {
+            check_not_reserved(name)?;
+            if !force && !sources.contains(name) {
+                let exists = store
+                    .load(name)
+                    .into_diagnostic()
+                    .with_context(|| format!("check baseline '{}'", name))?
+                    .is_some();
+                if exists {
+                    return Err(miette!("baseline '{}' already exists; use --force to overwrite", name));
+                }
+            }
+            let mut records = Vec::new();
+            for source in sources {
+                let runs = store
+                    .load_runs(source)
+                    .into_diagnostic()
+                    .with_context(|| format!("load baseline '{}'", source))?
+                    .ok_or_else(|| {
+                        miette!(
+                            "baseline '{}' not found\nRun 'usereport baseline list' to see available baselines.",
+                            source
+                        )
+                    })?;
+                records.extend(runs);
+            }
+            records.sort_by_key(|r| r.captured_at);
+            store
+                .record_runs(name, &records)
+                .into_diagnostic()
+                .with_context(|| format!("record baseline '{}'", name))?;
+            println!(
+                "merged {} into baseline '{}' ({})",
+                sources.join(", "),
+                name,
+                runs_label(records.len())
+            );
+        }
         BaselineAction::List { output } => {
             let names = store.list().into_diagnostic().context("list baselines")?;
             if names.is_empty() {
@@ -801,12 +1054,23 @@ fn run_baseline(action: &BaselineAction) -> miette::Result<()> {
                 }
             }
         }
-        BaselineAction::Show { name } => match store
-            .load(name)
+        BaselineAction::Show { name, output } => match store
+            .load_runs(name)
             .into_diagnostic()
             .with_context(|| format!("load baseline '{}'", name))?
         {
-            Some(record) => println!("{}", serde_json::to_string_pretty(&record).into_diagnostic()?),
+            Some(records) if output == "raw" => {
+                println!("{}", serde_json::to_string_pretty(&records).into_diagnostic()?);
+            }
+            Some(records) => {
+                let summary = baseline::summarize(&records);
+                if output == "json" {
+                    println!("{}", serde_json::to_string_pretty(&summary).into_diagnostic()?);
+                } else {
+                    let is_tty = std::io::stdout().is_terminal();
+                    show_baseline_inner(name, &records, &summary, is_tty, &mut std::io::stdout())?;
+                }
+            }
             None => {
                 return Err(miette!(
                     "baseline '{}' not found\nRun 'usereport baseline list' to see available baselines.",
@@ -828,19 +1092,90 @@ fn run_baseline(action: &BaselineAction) -> miette::Result<()> {
     Ok(())
 }
 
-fn run_diff(a_path: &PathBuf, b_path: &PathBuf, output: &OutputType) -> miette::Result<()> {
-    let a_bytes = std::fs::read(a_path)
-        .into_diagnostic()
-        .with_context(|| format!("read {}", a_path.display()))?;
-    let b_bytes = std::fs::read(b_path)
-        .into_diagnostic()
-        .with_context(|| format!("read {}", b_path.display()))?;
-    let a: AnalysisReport = serde_json::from_slice(&a_bytes)
+/// `--baseline rolling` selects the rolling window, not a named baseline.
+const ROLLING_BASELINE: &str = "rolling";
+
+fn check_not_reserved(name: &str) -> miette::Result<()> {
+    if name == ROLLING_BASELINE {
+        return Err(miette!(
+            "'{}' is reserved for the rolling baseline ('usereport --baseline {}'); choose another name.",
+            name,
+            name
+        ));
+    }
+    Ok(())
+}
+
+fn runs_label(n: usize) -> String {
+    format!("{} {}", n, if n == 1 { "run" } else { "runs" })
+}
+
+/// Print the per-signal summary of baseline `name`, built from `records`.
+pub fn show_baseline_inner(
+    name: &str,
+    records: &[BaselineRecord],
+    summary: &[SignalSummary],
+    is_tty: bool,
+    out: &mut dyn Write,
+) -> miette::Result<()> {
+    use comfy_table::{Attribute, Cell};
+    let first = records.iter().map(|r| r.captured_at).min();
+    let last = records.iter().map(|r| r.captured_at).max();
+    match (first, last) {
+        (Some(first), Some(last)) if records.len() > 1 => writeln!(
+            out,
+            "baseline '{}': {}, {} to {}",
+            name,
+            runs_label(records.len()),
+            first.format("%Y-%m-%d %H:%M:%S"),
+            last.format("%Y-%m-%d %H:%M:%S")
+        ),
+        (Some(at), _) => writeln!(out, "baseline '{}': 1 run, {}", name, at.format("%Y-%m-%d %H:%M:%S")),
+        _ => writeln!(out, "baseline '{}': no runs", name),
+    }
+    .into_diagnostic()?;
+    if records.len() < 2 {
+        writeln!(
+            out,
+            "A single run has no spread, so it produces no outliers. Record more with --runs or combine baselines with 'usereport baseline merge'."
+        )
+        .into_diagnostic()?;
+    }
+    let mut table = Table::new();
+    table.load_preset(comfy_table::presets::UTF8_FULL_CONDENSED);
+    table.set_header(vec![
+        Cell::new("Signal").add_attribute(Attribute::Bold),
+        Cell::new("Runs").add_attribute(Attribute::Bold),
+        Cell::new("Median").add_attribute(Attribute::Bold),
+        Cell::new("MAD").add_attribute(Attribute::Bold),
+    ]);
+    if is_tty {
+        table.enforce_styling();
+    }
+    for s in summary {
+        table.add_row(vec![
+            s.id.clone(),
+            s.runs.to_string(),
+            format!("{}", (s.median * 1000.0).round() / 1000.0),
+            format!("{}", (s.mad * 1000.0).round() / 1000.0),
+        ]);
+    }
+    writeln!(out, "{table}").into_diagnostic()?;
+    Ok(())
+}
+
+fn read_report(path: &Path) -> miette::Result<AnalysisReport> {
+    let bytes = std::fs::read(path)
         .into_diagnostic()
-        .with_context(|| format!("parse {}", a_path.display()))?;
-    let b: AnalysisReport = serde_json::from_slice(&b_bytes)
+        .with_context(|| format!("read {}", path.display()))?;
+    serde_json::from_slice(&bytes)
         .into_diagnostic()
-        .with_context(|| format!("parse {}", b_path.display()))?;
+        .with_context(|| format!("parse {}", path.display()))
+}
+
+fn run_diff(a_path: &Path, b_path: &Path, output: &OutputType) -> miette::Result<()> {
+    let a = read_report(a_path)?;
+    let b = read_report(b_path)?;
     let report = diff::diff(&a, &b);
     let stdout = std::io::stdout();
     let mut handle = stdout.lock();
@@ -877,14 +1212,7 @@ fn run_convert(
         return Err(miette!("--output template requires --output-template <PATH>"));
     }
     let report: AnalysisReport = match input {
-        Some(path) => {
-            let bytes = std::fs::read(path)
-                .into_diagnostic()
-                .with_context(|| format!("read {}", path.display()))?;
-            serde_json::from_slice(&bytes)
-                .into_diagnostic()
-                .with_context(|| format!("parse {}", path.display()))?
-        }
+        Some(path) => read_report(path)?,
         None => serde_json::from_reader(std::io::stdin())
             .into_diagnostic()
             .context("parse JSON report from stdin")?,
@@ -917,10 +1245,352 @@ fn run_convert(
     Ok(())
 }
 
-pub fn run_explain_list(config: &Config) -> miette::Result<()> {
+/// `$XDG_CONFIG_HOME/usereport`, falling back to `$HOME/.config/usereport`.
+fn user_config_dir() -> Option<PathBuf> {
+    std::env::var("XDG_CONFIG_HOME")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var("HOME").ok().map(|h| PathBuf::from(h).join(".config")))
+        .map(|base| base.join("usereport"))
+}
+
+/// Silences from `path`, or from `<user config dir>/silences.toml` when it
+/// exists. A file that cannot be read or parsed is skipped with a warn
+/// finding, as a broken rule file is.
+fn user_silences(path: Option<&Path>) -> (Vec<Silence>, Vec<Finding>) {
+    let path = match path {
+        Some(path) => path.to_path_buf(),
+        None => match user_config_dir()
+            .map(|dir| dir.join("silences.toml"))
+            .filter(|p| p.exists())
+        {
+            Some(path) => path,
+            None => return (Vec::new(), Vec::new()),
+        },
+    };
+    match load_silences(&path) {
+        Ok(silences) => (silences, Vec::new()),
+        Err(e) => {
+            let (id, suggest) = match e {
+                crate::silence::Error::Read(_) => (
+                    "silences.file_unreadable",
+                    format!("Check file permissions on {}", path.display()),
+                ),
+                _ => (
+                    "silences.malformed_file",
+                    format!("Check the silence entries in {}", path.display()),
+                ),
+            };
+            (
+                Vec::new(),
+                vec![Finding {
+                    id: id.to_string(),
+                    kind: crate::finding::FindingKind::Rule,
+                    severity: Severity::Warn,
+                    summary: format!("skipped silences file {}: {}", path.display(), e),
+                    evidence: vec![],
+                    suggest: vec![suggest],
+                }],
+            )
+        }
+    }
+}
+
+/// Load the built-in patterns plus user patterns from `dir`, or from
+/// `<user config dir>/patterns.d` when `dir` is `None`.
+fn load_patterns(dir: Option<&Path>) -> PatternsLoadResult {
+    let mut builtins = PatternEngine::empty();
+    for (_, toml_text) in defaults::PATTERNS {
+        match PatternEngine::from_toml(toml_text) {
+            Ok(loaded) => builtins.extend_from(loaded),
+            Err(e) => log::warn!("failed to load builtin pattern: {}", e),
+        }
+    }
+    let mut loader = PatternsLoader::new().with_builtins(builtins);
+    if let Some(dir) = dir.map(Path::to_path_buf).or_else(user_patterns_dir) {
+        loader = loader.with_user_dir(dir);
+    }
+    loader.load()
+}
+
+fn user_patterns_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join("patterns.d"))
+}
+
+/// Load `builtins` plus user rules from `<user config dir>/rules.d`.
+fn load_rules(builtins: Vec<Rule>) -> RulesLoadResult {
+    let mut loader = RulesLoader::new().with_builtins(builtins);
+    if let Some(dir) = user_config_dir() {
+        loader = loader.with_user_dir(dir.join("rules.d"));
+    }
+    loader.load()
+}
+
+fn run_rules_lint(config: &Config, patterns_dir: Option<&Path>) -> miette::Result<()> {
+    let user_dir = user_config_dir().map(|dir| dir.join("rules.d"));
+    let patterns_dir = patterns_dir.map(Path::to_path_buf).or_else(user_patterns_dir);
+    let (checked, issues) = lint_rules(config, user_dir.as_deref(), patterns_dir.as_deref());
+    let stdout = std::io::stdout();
+    let mut handle = stdout.lock();
+    for issue in &issues {
+        writeln!(handle, "{issue}").into_diagnostic()?;
+    }
+    if issues.is_empty() {
+        writeln!(handle, "{checked} rules and patterns checked, no issues found").into_diagnostic()?;
+        return Ok(());
+    }
+    Err(miette!(
+        "{} {} in {} rules and patterns",
+        issues.len(),
+        if issues.len() == 1 { "issue" } else { "issues" },
+        checked
+    ))
+}
+
+/// Lint built-in rules, workload packs, the rule files in `user_dir`, the
+/// built-in patterns and the pattern files in `patterns_dir` against the
+/// signals declared by the collectors and by `config`'s `[[command.extract]]`
+/// entries. Returns the number of rules and patterns checked alongside the
+/// issues.
+fn lint_rules(config: &Config, user_dir: Option<&Path>, patterns_dir: Option<&Path>) -> (usize, Vec<LintIssue>) {
+    let mut linter = Linter::new();
+    linter.declare_catalog();
+    for cmd in &config.commands {
+        for extract in cmd.extract() {
+            linter.declare_with_unit(&extract.signal_id, ValueKind::Number, extract.unit);
+        }
+    }
+    let lint_file = |linter: &mut Linter, origin: &str, scope: Scope, src: &str| match parse_rules_toml(src) {
+        Ok(rules) => linter.check_rules(origin, scope, &rules),
+        Err(e) => linter.parse_error(origin, e.to_string()),
+    };
+    for (name, src) in builtin_rule_files() {
+        lint_file(&mut linter, &format!("contrib/rules/{name}"), Scope::Builtin, src);
+    }
+    for (name, src) in WORKLOADS {
+        let origin = format!("contrib/rules/workloads/{name}.toml");
+        lint_file(&mut linter, &origin, Scope::Workload(name.to_string()), src);
+    }
+    let lint_pattern_file =
+        |linter: &mut Linter, origin: &str, scope: Scope, src: &str| match PatternEngine::from_toml(src) {
+            Ok(engine) => {
+                for pattern in engine.patterns() {
+                    linter.check_pattern(origin, scope.clone(), &pattern.id, &pattern.when);
+                    let texts = std::iter::once(&pattern.summary).chain(&pattern.suggest);
+                    linter.check_templates(origin, &pattern.id, texts.map(String::as_str));
+                }
+            }
+            Err(e) => linter.parse_error(origin, e.to_string()),
+        };
+    if let Some(dir) = user_dir {
+        lint_dir(&mut linter, dir, |linter, origin, src| {
+            lint_file(linter, origin, Scope::User, src)
+        });
+    }
+    for (name, src) in defaults::PATTERNS {
+        lint_pattern_file(&mut linter, &format!("contrib/patterns/{name}"), Scope::Pattern, src);
+    }
+    if let Some(dir) = patterns_dir {
+        lint_dir(&mut linter, dir, |linter, origin, src| {
+            lint_pattern_file(linter, origin, Scope::UserPattern, src)
+        });
+    }
+    (linter.checked(), linter.finish())
+}
+
+/// Feed every `*.toml` file in `dir` to `lint`. An absent directory is
+/// skipped; unreadable files and directories become parse issues.
+fn lint_dir(linter: &mut Linter, dir: &Path, lint: impl Fn(&mut Linter, &str, &str)) {
+    match rule_files(dir) {
+        Ok(paths) => {
+            for path in paths {
+                let origin = path.display().to_string();
+                match std::fs::read_to_string(&path) {
+                    Ok(src) => lint(linter, &origin, &src),
+                    Err(e) => linter.parse_error(&origin, e.to_string()),
+                }
+            }
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => linter.parse_error(&dir.display().to_string(), e.to_string()),
+    }
+}
+
+fn run_rules_test(paths: &[PathBuf], patterns_dir: Option<&Path>) -> miette::Result<()> {
+    let mut rows = Vec::new();
+    if paths.is_empty() {
+        for (name, src) in builtin_rule_files() {
+            run_embedded_tests(&format!("contrib/rules/{name}"), src, &mut rows);
+        }
+        for (name, src) in WORKLOADS {
+            run_embedded_tests(&format!("contrib/rules/workloads/{name}.toml"), src, &mut rows);
+        }
+        for (name, src) in defaults::PATTERNS {
+            run_embedded_tests(&format!("contrib/patterns/{name}"), src, &mut rows);
+        }
+    }
+    let user_dir = user_config_dir().map(|dir| dir.join("rules.d"));
+    let patterns_dir = patterns_dir.map(Path::to_path_buf).or_else(user_patterns_dir);
+    let dirs_or_files: Vec<PathBuf> = if paths.is_empty() {
+        user_dir
+            .into_iter()
+            .chain(patterns_dir)
+            .filter(|d| d.is_dir())
+            .collect()
+    } else {
+        paths.to_vec()
+    };
+    for path in dirs_or_files {
+        let files = if path.is_dir() {
+            rule_files(&path)
+                .into_diagnostic()
+                .with_context(|| format!("could not read {}", path.display()))?
+        } else {
+            vec![path]
+        };
+        for file in files {
+            let origin = file.display().to_string();
+            match std::fs::read_to_string(&file) {
+                Ok(src) => run_embedded_tests(&origin, &src, &mut rows),
+                Err(e) => rows.push(TestRow::error(&origin, e.to_string())),
+            }
+        }
+    }
+
     let stdout = std::io::stdout();
     let mut handle = stdout.lock();
-    let all_rules = builtin_rules();
+    if rows.is_empty() {
+        writeln!(handle, "No [[rule.test]] or [[pattern.test]] blocks found.").into_diagnostic()?;
+        return Ok(());
+    }
+    let failed = run_rules_test_inner(&rows, std::io::stdout().is_terminal(), &mut handle)?;
+    if failed > 0 {
+        return Err(miette!("{} of {} rule tests failed", failed, rows.len()));
+    }
+    Ok(())
+}
+
+/// One row of the `usereport rules test` table.
+struct TestRow {
+    origin: String,
+    id: String,
+    label: String,
+    outcome: Result<(), String>,
+}
+
+impl TestRow {
+    fn error(origin: &str, message: String) -> Self {
+        TestRow {
+            origin: origin.to_string(),
+            id: String::new(),
+            label: String::new(),
+            outcome: Err(message),
+        }
+    }
+}
+
+/// The severity a rule or pattern fired at for a test's fixture, if it fired.
+type FiredAt = Box<dyn Fn(&RuleTest) -> Option<Severity>>;
+
+/// Run the embedded tests of one rule or pattern file against that file's
+/// definitions, through the same engines a report uses. A file that does not
+/// parse becomes a single failing row.
+fn run_embedded_tests(origin: &str, src: &str, rows: &mut Vec<TestRow>) {
+    let is_pattern_file = toml::from_str::<toml::Table>(src)
+        .map(|t| t.contains_key("pattern"))
+        .unwrap_or(false);
+    let (tests, fired): (Vec<_>, FiredAt) = if is_pattern_file {
+        match PatternEngine::from_toml(src) {
+            Ok(engine) => {
+                let tests = engine.tests().to_vec();
+                let fired = move |t: &RuleTest| {
+                    let findings = engine.run_with_findings(&t.signals, &t.findings, &t.ctx);
+                    findings.iter().find(|f| f.id == t.id).map(|f| f.severity)
+                };
+                (tests, Box::new(fired))
+            }
+            Err(e) => return rows.push(TestRow::error(origin, e.to_string())),
+        }
+    } else {
+        match parse_rules_with_tests(src) {
+            Ok((rules, tests)) => {
+                let engine = RuleEngine::new(rules);
+                let fired = move |t: &RuleTest| {
+                    let (findings, _) = engine.run(&t.signals, &t.ctx, &std::collections::HashMap::new());
+                    findings.iter().find(|f| f.id == t.id).map(|f| f.severity)
+                };
+                (tests, Box::new(fired))
+            }
+            Err(e) => return rows.push(TestRow::error(origin, e.to_string())),
+        }
+    };
+    let mut index_in_rule = std::collections::HashMap::new();
+    for test in &tests {
+        let index = index_in_rule.entry(test.id.clone()).or_insert(0usize);
+        rows.push(TestRow {
+            origin: origin.to_string(),
+            id: test.id.clone(),
+            label: test.label(*index),
+            outcome: test.check(fired(test)),
+        });
+        *index += 1;
+    }
+}
+
+fn run_rules_test_inner(rows: &[TestRow], is_tty: bool, out: &mut dyn Write) -> miette::Result<usize> {
+    use comfy_table::{Attribute, Cell, ContentArrangement, presets};
+    let mut table = Table::new();
+    table.load_preset(presets::UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("File").add_attribute(Attribute::Bold),
+        Cell::new("ID").add_attribute(Attribute::Bold),
+        Cell::new("Test").add_attribute(Attribute::Bold),
+        Cell::new("Result").add_attribute(Attribute::Bold),
+    ]);
+    if is_tty {
+        table.enforce_styling();
+    }
+    let mut failed = 0usize;
+    for row in rows {
+        let result = match &row.outcome {
+            Ok(()) => "PASS".to_string(),
+            Err(e) => {
+                failed += 1;
+                format!("FAIL: {e}")
+            }
+        };
+        table.add_row(vec![
+            Cell::new(&row.origin),
+            Cell::new(&row.id),
+            Cell::new(&row.label),
+            Cell::new(result),
+        ]);
+    }
+    // Color after rendering, as in `run_check_inner`, so widths stay right.
+    let rendered = format!("{table}");
+    let rendered = if is_tty {
+        let colored = regex::Regex::new(r"\bPASS\b")
+            .unwrap()
+            .replace_all(&rendered, "\x1b[32mPASS\x1b[0m")
+            .into_owned();
+        regex::Regex::new(r"\bFAIL\b")
+            .unwrap()
+            .replace_all(&colored, "\x1b[31mFAIL\x1b[0m")
+            .into_owned()
+    } else {
+        rendered
+    };
+    writeln!(out, "{rendered}").into_diagnostic()?;
+    Ok(failed)
+}
+
+pub fn run_explain_list(config: &Config, patterns_dir: Option<&Path>) -> miette::Result<()> {
+    let stdout = std::io::stdout();
+    let mut handle = stdout.lock();
+    let all_rules = load_rules(builtin_rules()).rules;
+    let patterns = load_patterns(patterns_dir).engine;
     let all_signal_ids: Vec<&str> = config
         .commands
         .iter()
@@ -940,11 +1610,14 @@ pub fn run_explain_list(config: &Config) -> miette::Result<()> {
     for r in &all_rules {
         push(format!("  {} (rule)", r.id));
     }
+    for p in patterns.patterns() {
+        push(format!("  {} (pattern)", p.id));
+    }
     for sid in &all_signal_ids {
         push(format!("  {} (signal)", sid));
     }
-    for (sid, _) in builtin_collector_signals() {
-        push(format!("  {} (collector signal)", sid));
+    for spec in catalog::builtin() {
+        push(format!("  {} (collector signal)", spec.id));
     }
     for (name, _, _) in builtin_profiling_tools() {
         push(format!("  {} (profiling tool)", name));
@@ -954,19 +1627,47 @@ pub fn run_explain_list(config: &Config) -> miette::Result<()> {
     Ok(())
 }
 
-pub fn run_explain(id: &str, config: &Config) -> miette::Result<()> {
+pub fn run_explain(
+    id: &str,
+    against: Option<&Path>,
+    config: &Config,
+    patterns_dir: Option<&Path>,
+) -> miette::Result<()> {
     let is_tty = std::io::stdout().is_terminal();
     let stdout = std::io::stdout();
     let mut handle = stdout.lock();
 
+    let loaded = load_rules(builtin_rules());
+    if let Some(path) = against {
+        let Some(rule) = loaded.rules.iter().find(|r| r.id == id) else {
+            return Err(miette!("--against needs a rule ID; '{}' is not a loaded rule", id));
+        };
+        let report = read_report(path)?;
+        let source = loaded.sources.get(id).cloned().unwrap_or(RuleSource::Builtin);
+        run_explain_inner(rule, &source, is_tty, &mut handle)?;
+        return run_explain_against(rule, &report, &path.display().to_string(), is_tty, &mut handle);
+    }
+
     // Check commands in config first
     if let Some(cmd) = config.commands.iter().find(|c| c.name() == id) {
         return run_explain_command(cmd, is_tty, &mut handle);
     }
 
-    let all_rules = builtin_rules();
-    if let Some(rule) = all_rules.iter().find(|r| r.id == id) {
-        return run_explain_inner(rule, is_tty, &mut handle);
+    if let Some(rule) = loaded.rules.iter().find(|r| r.id == id) {
+        let source = loaded.sources.get(id).cloned().unwrap_or(RuleSource::Builtin);
+        return run_explain_inner(rule, &source, is_tty, &mut handle);
+    }
+    if let Some(path) = loaded.disabled.get(id) {
+        writeln!(handle, "ID:       {id}").into_diagnostic()?;
+        writeln!(handle, "Status:   disabled by {}", path.display()).into_diagnostic()?;
+        return Ok(());
+    }
+    let all_rules = loaded.rules;
+
+    let patterns = load_patterns(patterns_dir);
+    if let Some(pattern) = patterns.engine.patterns().iter().find(|p| p.id == id) {
+        let source = patterns.sources.get(id).cloned().unwrap_or(RuleSource::Builtin);
+        return run_explain_pattern(pattern, &source, is_tty, &mut handle);
     }
 
     // Collect all signal IDs from config extract definitions
@@ -993,12 +1694,9 @@ pub fn run_explain(id: &str, config: &Config) -> miette::Result<()> {
         return Ok(());
     }
 
-    // Look up builtin collector signals
-    if let Some((_, desc)) = builtin_collector_signals().iter().find(|(sid, _)| *sid == id) {
-        writeln!(handle, "Signal ID:   {id}").into_diagnostic()?;
-        writeln!(handle, "Emitted by:  built-in collector").into_diagnostic()?;
-        writeln!(handle, "Description: {desc}").into_diagnostic()?;
-        return Ok(());
+    // Look up builtin collector signals, per-device IDs included.
+    if let Some(spec) = catalog::lookup(id) {
+        return run_explain_signal(id, spec, &mut handle);
     }
 
     // Look up profiling tools
@@ -1022,11 +1720,14 @@ pub fn run_explain(id: &str, config: &Config) -> miette::Result<()> {
     for r in &all_rules {
         push_known(format!("  {} (rule)", r.id));
     }
+    for p in patterns.engine.patterns() {
+        push_known(format!("  {} (pattern)", p.id));
+    }
     for (sid, _) in &all_signal_ids {
         push_known(format!("  {} (signal)", sid));
     }
-    for (sid, _) in builtin_collector_signals() {
-        push_known(format!("  {} (collector signal)", sid));
+    for spec in catalog::builtin() {
+        push_known(format!("  {} (collector signal)", spec.id));
     }
     for (name, _, _) in builtin_profiling_tools() {
         push_known(format!("  {} (profiling tool)", name));
@@ -1035,67 +1736,22 @@ pub fn run_explain(id: &str, config: &Config) -> miette::Result<()> {
     Err(miette!("unknown topic '{}'\n\nKnown topics:\n{}", id, known.join("\n"),))
 }
 
-pub fn builtin_collector_signals() -> &'static [(&'static str, &'static str)] {
-    &[
-        // CpuCollector
-        ("cpu.usr_pct", "CPU user-space time (%)"),
-        ("cpu.sys_pct", "CPU kernel time (%)"),
-        ("cpu.idle_pct", "CPU idle time (%)"),
-        ("cpu.iowait_pct", "CPU time waiting on I/O (%)"),
-        ("cpu.ctxt_per_sec", "Context switches per second"),
-        ("cpu.run_queue", "Runnable processes in the run queue"),
-        // CpuFreqCollector
-        ("cpu.freq_ratio", "Current CPU frequency / max frequency ratio"),
-        ("cpu.temp_celsius", "Maximum CPU temperature (°C)"),
-        // HostCollector
-        ("host.cpu_count", "Logical CPU count"),
-        ("host.mem_total_bytes", "Total physical memory (bytes)"),
-        ("host.load_avg_1m", "1-minute load average"),
-        // MemoryCollector
-        ("mem.total_mb", "Total RAM (MB)"),
-        ("mem.used_mb", "Used RAM (MB)"),
-        ("mem.free_mb", "Free RAM (MB)"),
-        ("mem.available_mb", "Available RAM (MB, Linux only)"),
-        ("mem.free_pct", "Free RAM (%)"),
-        ("swap.total_mb", "Total swap space (MB)"),
-        ("swap.used_mb", "Used swap space (MB)"),
-        ("swap.free_mb", "Free swap space (MB)"),
-        ("vmstat.swap_in", "Pages swapped in from disk"),
-        // NetworkCollector
-        ("net.rx_drops", "Total RX drop counter across interfaces"),
-        ("net.retrans_pct", "TCP retransmit ratio (%)"),
-        ("net.tw_count", "TCP TIME_WAIT socket count"),
-        ("net.connect_failures", "TCP connection attempt failures"),
-        ("net.estab_resets", "TCP established-connection resets"),
-        // DiskCollector
-        ("disk.max_util_pct", "Highest per-device disk utilisation (%)"),
-        ("disk.max_await_ms", "Highest per-device I/O latency (ms)"),
-        // InterruptsCollector
-        ("net.max_cpu_irq_pct", "Highest per-CPU NIC interrupt load (%)"),
-        // CgroupCollector
-        ("cgroup.memory_bytes", "cgroup memory usage (bytes)"),
-        ("cgroup.memory_limit_bytes", "cgroup memory limit (bytes)"),
-        ("cgroup.oom_kills", "cgroup OOM kill count"),
-        ("cgroup.pids_current", "cgroup current PID count"),
-        ("cgroup.cpu_throttled_usec", "cgroup CPU throttle time (µs)"),
-        // DmesgCollector
-        ("dmesg.oom_count", "OOM kill events in dmesg"),
-        ("dmesg.blocked_task_count", "Blocked-task (hung-task) events in dmesg"),
-        ("dmesg.fs_error_count", "Filesystem error events in dmesg"),
-        ("dmesg.segfault_count", "Segfault events in dmesg"),
-        ("dmesg.mce_count", "Machine-check exception events in dmesg"),
-        ("dmesg.nic_flap_count", "NIC link-flap events in dmesg"),
-        ("dmesg.io_error_count", "I/O error events in dmesg"),
-        // BpfCollector (feature-gated; IDs are dynamic but documented here)
-        ("bpf.runqlat.usecs", "Run-queue latency p50 (µs)"),
-        ("bpf.biolatency.usecs", "Block I/O latency p50 (µs)"),
-        ("bpf.cachestat.usecs", "Page-cache hit latency p50 (µs)"),
-        ("bpf.runqlat.available", "Whether runqlat is available in PATH"),
-        ("bpf.biolatency.available", "Whether biolatency is available in PATH"),
-        ("bpf.tcpretrans.available", "Whether tcpretrans is available in PATH"),
-        ("bpf.execsnoop.available", "Whether execsnoop is available in PATH"),
-        ("bpf.cachestat.available", "Whether cachestat is available in PATH"),
-    ]
+fn run_explain_signal(id: &str, spec: &SignalSpec, out: &mut dyn Write) -> miette::Result<()> {
+    writeln!(out, "Signal ID:   {id}").into_diagnostic()?;
+    if spec.id != id {
+        writeln!(out, "Family:      {}", spec.id).into_diagnostic()?;
+    }
+    writeln!(out, "Emitted by:  {} collector", spec.collector).into_diagnostic()?;
+    writeln!(out, "Kind:        {}", spec.kind.as_str()).into_diagnostic()?;
+    writeln!(out, "Unit:        {}", spec.unit).into_diagnostic()?;
+    if let (Some(resource), Some(aspect)) = (spec.resource, spec.aspect) {
+        writeln!(out, "USE:         {resource} {aspect}").into_diagnostic()?;
+    }
+    if let Some(label) = spec.label {
+        writeln!(out, "Label:       {label}").into_diagnostic()?;
+    }
+    writeln!(out, "Description: {}", spec.description).into_diagnostic()?;
+    Ok(())
 }
 
 /// (name, description, install hint)
@@ -1153,27 +1809,43 @@ pub fn run_explain_command(cmd: &crate::command::Command, _is_tty: bool, out: &m
     Ok(())
 }
 
-fn run_explain_inner(rule: &Rule, is_tty: bool, out: &mut dyn Write) -> miette::Result<()> {
-    let label = match rule.severity {
+fn severity_label(severity: Severity, is_tty: bool) -> String {
+    let label = match severity {
         Severity::Crit => "CRIT",
         Severity::Warn => "WARN",
         Severity::Info => "INFO",
     }
     .to_string();
-    let severity_str = if is_tty {
+    if is_tty {
         use owo_colors::OwoColorize as _;
-        match rule.severity {
+        match severity {
             Severity::Crit => label.red().to_string(),
             Severity::Warn => label.yellow().to_string(),
             Severity::Info => label.blue().to_string(),
         }
     } else {
         label
-    };
+    }
+}
+
+fn run_explain_inner(rule: &Rule, source: &RuleSource, is_tty: bool, out: &mut dyn Write) -> miette::Result<()> {
+    // Multi-level rules list every tier, least severe first.
+    let mut severities: Vec<Severity> = rule.tiers().map(|(_, s)| s).collect();
+    severities.sort_by_key(|s| std::cmp::Reverse(s.rank()));
+    severities.dedup();
+    let severity_str = severities
+        .into_iter()
+        .map(|s| severity_label(s, is_tty))
+        .collect::<Vec<_>>()
+        .join(" / ");
 
     writeln!(out, "ID:       {}", rule.id).into_diagnostic()?;
     writeln!(out, "Severity: {}", severity_str).into_diagnostic()?;
     writeln!(out, "Summary:  {}", rule.summary).into_diagnostic()?;
+    writeln!(out, "Source:   {}", source).into_diagnostic()?;
+    if !rule.inhibits.is_empty() {
+        writeln!(out, "Inhibits: {}", rule.inhibits.join(", ")).into_diagnostic()?;
+    }
     if let Some(desc) = &rule.description {
         writeln!(out).into_diagnostic()?;
         writeln!(out, "{}", desc).into_diagnostic()?;
@@ -1195,6 +1867,70 @@ fn run_explain_inner(rule: &Rule, is_tty: bool, out: &mut dyn Write) -> miette::
     Ok(())
 }
 
+fn run_explain_pattern(
+    pattern: &Pattern,
+    source: &RuleSource,
+    is_tty: bool,
+    out: &mut dyn Write,
+) -> miette::Result<()> {
+    writeln!(out, "ID:       {}", pattern.id).into_diagnostic()?;
+    writeln!(out, "Severity: {}", severity_label(pattern.severity, is_tty)).into_diagnostic()?;
+    writeln!(out, "Summary:  {}", pattern.summary).into_diagnostic()?;
+    writeln!(out, "Source:   {}", source).into_diagnostic()?;
+    if !pattern.inhibits.is_empty() {
+        writeln!(out, "Inhibits: {}", pattern.inhibits.join(", ")).into_diagnostic()?;
+    }
+    if !pattern.suggest.is_empty() {
+        writeln!(out).into_diagnostic()?;
+        writeln!(out, "To investigate:").into_diagnostic()?;
+        for s in &pattern.suggest {
+            writeln!(out, "  {}", s).into_diagnostic()?;
+        }
+    }
+    Ok(())
+}
+
+/// Trace every tier of `rule` against the signals of a saved report: each
+/// term with its resolved values, whether it held, and the signals the
+/// report lacks.
+fn run_explain_against(
+    rule: &Rule,
+    report: &AnalysisReport,
+    label: &str,
+    is_tty: bool,
+    out: &mut dyn Write,
+) -> miette::Result<()> {
+    let ctx = report.collect_ctx();
+    let index = SignalIndex::build(report.signals());
+    let mut absent: Vec<String> = Vec::new();
+    writeln!(out).into_diagnostic()?;
+    writeln!(out, "Evaluation against {}:", label).into_diagnostic()?;
+    for (when, severity) in rule.tiers() {
+        let trace = Trace::evaluate(when, &index, &ctx);
+        let outcome = if trace.holds { "holds" } else { "does not hold" };
+        writeln!(out).into_diagnostic()?;
+        writeln!(out, "  {} tier {}:", severity_label(severity, is_tty), outcome).into_diagnostic()?;
+        for line in trace.to_string().lines() {
+            writeln!(out, "    {}", line).into_diagnostic()?;
+        }
+        for id in trace.absent_signals() {
+            if !absent.contains(&id) {
+                absent.push(id);
+            }
+        }
+    }
+    writeln!(out).into_diagnostic()?;
+    match rule.matching_tier(&index, &ctx) {
+        Some((_, severity)) => writeln!(out, "Result:   fires at {}", severity_label(severity, is_tty)),
+        None => writeln!(out, "Result:   does not fire"),
+    }
+    .into_diagnostic()?;
+    if !absent.is_empty() {
+        writeln!(out, "Absent:   {}", absent.join(", ")).into_diagnostic()?;
+    }
+    Ok(())
+}
+
 /// Run CPU profiling for `duration_secs` seconds using perf (or bpftrace when
 /// `use_bpf` is true and bpftrace is on PATH). Returns `Ok(Some(svg))` on
 /// success, `Ok(None)` when no profiling tool is available.
@@ -1356,38 +2092,22 @@ fn generate_report(opt: &Opt, config: &Config, profile_name: &str) -> miette::Re
         Box::new(CgroupCollector::new()),
         Box::new(DmesgCollector::new()),
     ];
-    // Load builtin rules + user rules from $XDG_CONFIG_HOME/usereport/rules.d
-    let user_rules_dir = std::env::var("XDG_CONFIG_HOME")
-        .ok()
-        .filter(|s| !s.is_empty())
-        .map(PathBuf::from)
-        .or_else(|| std::env::var("HOME").ok().map(|h| PathBuf::from(h).join(".config")))
-        .map(|base| base.join("usereport/rules.d"));
-    let mut loader = RulesLoader::new().with_builtins(builtin_rules());
-    if let Some(dir) = user_rules_dir {
-        loader = loader.with_user_dir(dir);
-    }
-    let rules_result = loader.load();
-    let mut all_rules = rules_result.rules;
+    let mut builtins = builtin_rules();
     if opt.bpf {
         collectors.push(Box::new(BpfCollector::new()));
-        all_rules.extend(bpf_rules());
+        builtins.extend(bpf_rules());
     }
     // Phase 8: merge workload-specific rules when --workload is set to a known pack.
     let workload_rules = load_workload_rules(&opt.workload)
         .into_diagnostic()
         .with_context(|| format!("invalid --workload value '{}'", opt.workload))?;
-    all_rules.extend(workload_rules);
-    let rule_engine = RuleEngine::new(all_rules);
+    builtins.extend(workload_rules);
+    // User rules may override or disable any of the above by id.
+    let rules_result = load_rules(builtins);
+    let rule_engine = RuleEngine::new(rules_result.rules);
 
-    // Load builtin patterns from contrib/patterns/
-    let mut pattern_engine = PatternEngine::empty();
-    for toml_text in defaults::PATTERNS {
-        match PatternEngine::from_toml(toml_text) {
-            Ok(loaded) => pattern_engine.extend_from(loaded),
-            Err(e) => log::warn!("failed to load builtin pattern: {}", e),
-        }
-    }
+    // Built-in patterns from contrib/patterns/, then the user's patterns.d.
+    let patterns_result = load_patterns(opt.patterns_dir.as_deref());
 
     // Phase 4: parse --duration / --interval and thread them into the collector context.
     let sample_duration = opt
@@ -1405,27 +2125,73 @@ fn generate_report(opt: &Opt, config: &Config, profile_name: &str) -> miette::Re
         .context("invalid --interval value")?
         .or_else(|| sample_duration.map(|_| default_interval));
 
+    let (silences, silence_findings) = user_silences(opt.silences.as_deref());
+
     let mut analysis = Analysis::new(Box::new(runner), &hostinfo, &commands)
         .with_max_parallel_commands(parallel)
         .with_repetitions(repetitions)
         .with_diagnostics(collectors, rule_engine)
-        .with_pattern_engine(pattern_engine);
+        .with_pattern_engine(patterns_result.engine)
+        .with_silences(silences);
     if let Some(d) = sample_duration {
         analysis = analysis.with_sample_duration(d, sample_interval.unwrap_or(default_interval));
     }
     if let Some(cgroup_path) = opt.cgroup.clone() {
         analysis = analysis.with_cgroup(cgroup_path);
     }
-    if let Some(name) = opt.baseline.as_deref() {
+    if let Some(margin) = opt.near_miss {
+        analysis = analysis.with_near_misses(margin);
+    }
+    // `delta(...)` predicates compare against the last run's rolling record.
+    // Without one they are absent, so a missing or unreadable file only
+    // costs those terms.
+    let rolling = match BaselineStore::xdg().map(|store| store.load_rolling()) {
+        Ok(Ok(records)) => records,
+        Ok(Err(e)) | Err(e) => {
+            log::warn!("could not load rolling baseline: {}", e);
+            Vec::new()
+        }
+    };
+    if let Some(record) = rolling.last() {
+        analysis = analysis.with_previous_run(record.clone());
+    }
+    let baseline = opt.baseline.as_deref().or(config.defaults.baseline.as_deref());
+    if let Some(seasonality) = opt.baseline_seasonality {
+        if baseline.is_none() {
+            eprintln!("Warning: --baseline-seasonality has no effect unless --baseline is also set");
+        } else {
+            analysis = analysis.with_baseline_seasonality(seasonality);
+        }
+    }
+    if baseline == Some(ROLLING_BASELINE) {
+        let min_history = config.defaults.baseline_min_history;
+        if rolling.len() < min_history {
+            eprintln!(
+                "Note: the rolling baseline holds {} of the {} runs needed; outlier detection starts once {} more {} recorded.",
+                rolling.len(),
+                min_history,
+                min_history - rolling.len(),
+                if min_history - rolling.len() == 1 {
+                    "run is"
+                } else {
+                    "runs are"
+                }
+            );
+        } else {
+            analysis = analysis
+                .with_baseline_records(rolling)
+                .with_baseline_min_history(min_history);
+        }
+    } else if let Some(name) = baseline {
         let store = BaselineStore::xdg()
             .into_diagnostic()
             .context("locate baseline directory")?;
         match store
-            .load(name)
+            .load_runs(name)
             .into_diagnostic()
             .with_context(|| format!("load baseline '{}'", name))?
         {
-            Some(record) => analysis = analysis.with_baseline_records(vec![record]),
+            Some(records) => analysis = analysis.with_baseline_records(records),
             None => {
                 return Err(miette!(
                     "baseline '{}' not found\nRun 'usereport baseline list' to see available baselines.",
@@ -1443,11 +2209,13 @@ fn generate_report(opt: &Opt, config: &Config, profile_name: &str) -> miette::Re
 
     // Append any findings from loading user rules (e.g. malformed TOML files).
     report.findings.extend(rules_result.load_findings);
+    report.findings.extend(patterns_result.load_findings);
+    report.findings.extend(silence_findings);
 
     // Compute at-a-glance overview fields.
     let first_results: Vec<_> = report.command_results().first().map(|v| v.to_vec()).unwrap_or_default();
     report.vital_signs = compute_vital_signs(report.signals(), report.findings());
-    report.use_coverage = compute_use_coverage(&first_results);
+    report.use_coverage = compute_use_coverage(&first_results, report.signals());
     if let Ok(profile) = config.profile(profile_name) {
         report.followup_recommendations = profile.followup.clone();
     }
@@ -1732,13 +2500,22 @@ mod defaults {
     #[cfg(target_os = "linux")]
     pub(crate) static CONFIG: &str = include_str!("../../contrib/linux.conf");
 
-    pub(crate) static PATTERNS: &[&str] = &[
-        include_str!("../../contrib/patterns/lock_contention.toml"),
-        include_str!("../../contrib/patterns/nfs_stall.toml"),
-        include_str!("../../contrib/patterns/slab_leak.toml"),
-        include_str!("../../contrib/patterns/socket_leak.toml"),
-        include_str!("../../contrib/patterns/thundering_herd.toml"),
-        include_str!("../../contrib/patterns/time_wait.toml"),
+    pub(crate) static PATTERNS: &[(&str, &str)] = &[
+        (
+            "lock_contention.toml",
+            include_str!("../../contrib/patterns/lock_contention.toml"),
+        ),
+        ("nfs_stall.toml", include_str!("../../contrib/patterns/nfs_stall.toml")),
+        ("slab_leak.toml", include_str!("../../contrib/patterns/slab_leak.toml")),
+        (
+            "socket_leak.toml",
+            include_str!("../../contrib/patterns/socket_leak.toml"),
+        ),
+        (
+            "thundering_herd.toml",
+            include_str!("../../contrib/patterns/thundering_herd.toml"),
+        ),
+        ("time_wait.toml", include_str!("../../contrib/patterns/time_wait.toml")),
     ];
 }
 
@@ -1766,6 +2543,10 @@ mod tests {
             show_profiles: false,
             show_commands: false,
             baseline: None,
+            baseline_seasonality: None,
+            silences: None,
+            patterns_dir: None,
+            near_miss: None,
             duration: None,
             interval: None,
             redact: false,
@@ -1791,6 +2572,13 @@ mod tests {
         assert_eq!(opt.cgroup, None);
     }
 
+    #[test]
+    fn opt_parses_baseline_seasonality() {
+        use clap::Parser;
+        let opt = Opt::try_parse_from(["usereport", "--baseline-seasonality", "weekly"]).expect("parse");
+        assert_eq!(opt.baseline_seasonality, Some(Seasonality::Weekly));
+    }
+
     #[test]
     fn test_filter_plus_adds_to_add_set() {
         let specs = vec!["+foo".to_string()];
@@ -1916,6 +2704,8 @@ mod tests {
             evidence_ids: vec![],
             suggest: vec!["check something".to_string()],
             links: vec![],
+            levels: vec![],
+            inhibits: vec![],
         }
     }
 
@@ -1923,7 +2713,7 @@ mod tests {
     fn run_explain_inner_tty_warn_contains_ansi() {
         let rule = make_rule(Severity::Warn);
         let mut buf: Vec<u8> = Vec::new();
-        run_explain_inner(&rule, true, &mut buf).unwrap();
+        run_explain_inner(&rule, &RuleSource::Builtin, true, &mut buf).unwrap();
         let output = String::from_utf8(buf).unwrap();
         assert!(
             output.contains('\x1b'),
@@ -1937,7 +2727,7 @@ mod tests {
     fn run_explain_inner_tty_info_contains_ansi() {
         let rule = make_rule(Severity::Info);
         let mut buf: Vec<u8> = Vec::new();
-        run_explain_inner(&rule, true, &mut buf).unwrap();
+        run_explain_inner(&rule, &RuleSource::Builtin, true, &mut buf).unwrap();
         let output = String::from_utf8(buf).unwrap();
         assert!(
             output.contains('\x1b'),
@@ -1951,7 +2741,7 @@ mod tests {
     fn run_explain_inner_no_tty_no_ansi() {
         let rule = make_rule(Severity::Crit);
         let mut buf: Vec<u8> = Vec::new();
-        run_explain_inner(&rule, false, &mut buf).unwrap();
+        run_explain_inner(&rule, &RuleSource::Builtin, false, &mut buf).unwrap();
         let output = String::from_utf8(buf).unwrap();
         assert!(
             !output.contains('\x1b'),
@@ -1961,6 +2751,213 @@ mod tests {
         assert!(output.contains("CRIT"), "expected 'CRIT' token in output");
     }
 
+    #[test]
+    fn run_explain_inner_lists_every_level_severity() {
+        let mut rule = make_rule(Severity::Crit);
+        rule.levels.push(crate::rule::RuleLevel {
+            when: rule.when.clone(),
+            severity: Severity::Warn,
+        });
+        let mut buf: Vec<u8> = Vec::new();
+        run_explain_inner(&rule, &RuleSource::Builtin, false, &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("Severity: WARN / CRIT"), "got: {:?}", output);
+    }
+
+    #[test]
+    fn run_explain_inner_shows_rule_source() {
+        let rule = make_rule(Severity::Warn);
+        let source = RuleSource::User {
+            path: PathBuf::from("/etc/rules.d/site.toml"),
+            overrides_builtin: true,
+        };
+        let mut buf: Vec<u8> = Vec::new();
+        run_explain_inner(&rule, &source, false, &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(
+            output.contains("Source:   /etc/rules.d/site.toml (overrides built-in)"),
+            "got: {:?}",
+            output
+        );
+    }
+
+    #[test]
+    fn run_explain_pattern_shows_patterns_d_source() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("site.toml");
+        std::fs::write(
+            &path,
+            r#"
+[[pattern]]
+id = "site.busy"
+when = "load.avg1 > 4"
+severity = "warn"
+summary = "site is busy"
+suggest = ["top"]
+"#,
+        )
+        .unwrap();
+        let loaded = load_patterns(Some(tmp.path()));
+        let pattern = loaded.engine.patterns().iter().find(|p| p.id == "site.busy").unwrap();
+        let mut buf: Vec<u8> = Vec::new();
+        run_explain_pattern(pattern, &loaded.sources["site.busy"], false, &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(
+            output.contains(&format!("Source:   {}", path.display())),
+            "got: {:?}",
+            output
+        );
+        assert!(output.contains("To investigate:\n  top"), "got: {:?}", output);
+    }
+
+    #[test]
+    fn run_explain_against_traces_each_tier_and_lists_absent_signals() {
+        let toml = r#"
+[[rule]]
+id = "net.retrans"
+when = "net.retrans_pct > 1 AND net.segs_out > 1000"
+severity = "warn"
+summary = ""
+
+[[rule.level]]
+when = "net.retrans_pct > 5"
+severity = "crit"
+"#;
+        let rule = parse_rules_toml(toml).unwrap().remove(0);
+        let signal = crate::signal::Signal {
+            id: "net.retrans_pct".to_string(),
+            value: crate::signal::SignalValue::F64(2.5),
+            unit: crate::signal::Unit::Pct,
+            at: chrono::Local::now(),
+            samples: None,
+            stats: None,
+            baseline: None,
+            labels: crate::signal::Labels::new(),
+        };
+        let report =
+            AnalysisReport::new_with_diagnostics(Context::new(), vec![], vec![], 1, 64, vec![signal], vec![], vec![]);
+        let mut buf: Vec<u8> = Vec::new();
+        run_explain_against(&rule, &report, "incident.json", false, &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("Evaluation against incident.json:"), "got: {output}");
+        assert!(output.contains("  WARN tier does not hold:"), "got: {output}");
+        assert!(
+            output.contains("      [true]  net.retrans_pct > 1  (2.5 > 1)"),
+            "got: {output}"
+        );
+        assert!(
+            output.contains("    [false] net.retrans_pct > 5  (2.5 > 5)"),
+            "got: {output}"
+        );
+        assert!(output.contains("Result:   does not fire"), "got: {output}");
+        assert!(output.contains("Absent:   net.segs_out"), "got: {output}");
+    }
+
+    #[test]
+    fn lint_rules_flags_only_unemitted_workload_signals_in_bundled_files() {
+        let config = Config::from_str(defaults::CONFIG).unwrap();
+        let (checked, issues) = lint_rules(&config, None, None);
+        assert!(checked >= 15, "checked {checked}");
+        for issue in &issues {
+            assert_eq!(issue.kind, crate::rule::lint::LintKind::UnknownSignal, "{issue}");
+            assert!(issue.origin.starts_with("contrib/rules/workloads/"), "{issue}");
+        }
+    }
+
+    #[test]
+    fn lint_rules_reads_user_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("site.toml"),
+            "[[rule]]\nid = \"net.time_wait_high\"\nwhen = \"net.tw_count > 1\"\nseverity = \"warn\"\nsummary = \"\"\n",
+        )
+        .unwrap();
+        std::fs::write(tmp.path().join("broken.toml"), "[[rule]\n").unwrap();
+        let config = Config::from_str(defaults::CONFIG).unwrap();
+        let (_, issues) = lint_rules(&config, Some(tmp.path()), None);
+        let user: Vec<&LintIssue> = issues
+            .iter()
+            .filter(|i| i.origin.starts_with(&*tmp.path().to_string_lossy()))
+            .collect();
+        assert_eq!(user.len(), 1, "{issues:?}");
+        assert_eq!(user[0].kind, crate::rule::lint::LintKind::Parse);
+        assert!(user[0].origin.ends_with("broken.toml"));
+    }
+
+    #[test]
+    fn lint_rules_reads_patterns_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("site.toml"),
+            "[[pattern]]\nid = \"time_wait_exhaustion\"\nwhen = \"net.tw_count > 1\"\nseverity = \"warn\"\nsummary = \"\"\n\n\
+             [[pattern]]\nid = \"api.conntrack\"\nwhen = \"api.p99_ms > 500\"\nseverity = \"warn\"\nsummary = \"\"\n",
+        )
+        .unwrap();
+        let config = Config::from_str(defaults::CONFIG).unwrap();
+        let (_, issues) = lint_rules(&config, None, Some(tmp.path()));
+        let user: Vec<&LintIssue> = issues
+            .iter()
+            .filter(|i| i.origin.starts_with(&*tmp.path().to_string_lossy()))
+            .collect();
+        // Replacing a built-in pattern is not a duplicate.
+        assert_eq!(user.len(), 1, "{issues:?}");
+        assert_eq!(user[0].kind, crate::rule::lint::LintKind::UnknownSignal);
+        assert_eq!(user[0].id.as_deref(), Some("api.conntrack"));
+    }
+
+    #[test]
+    fn user_silences_reports_bad_files_as_findings() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("silences.toml");
+        std::fs::write(&path, "[[silence]]\nid = \"a\"\nreason = \"r\"\n").unwrap();
+        let (silences, findings) = user_silences(Some(&path));
+        assert!(silences.is_empty());
+        assert_eq!(findings[0].id, "silences.malformed_file");
+        assert!(
+            findings[0].summary.contains("missing `expires`"),
+            "{}",
+            findings[0].summary
+        );
+
+        let (_, findings) = user_silences(Some(&tmp.path().join("absent.toml")));
+        assert_eq!(findings[0].id, "silences.file_unreadable");
+    }
+
+    #[test]
+    fn bundled_embedded_tests_pass() {
+        let mut rows = Vec::new();
+        for (name, src) in builtin_rule_files() {
+            run_embedded_tests(name, src, &mut rows);
+        }
+        for (name, src) in defaults::PATTERNS {
+            run_embedded_tests(name, src, &mut rows);
+        }
+        assert!(
+            rows.iter().any(|r| r.origin == "time_wait.toml"),
+            "pattern tests must run"
+        );
+        for row in &rows {
+            assert_eq!(row.outcome, Ok(()), "{} {} {}", row.origin, row.id, row.label);
+        }
+    }
+
+    #[test]
+    fn run_rules_test_inner_counts_failures() {
+        let mut rows = Vec::new();
+        run_embedded_tests(
+            "site.toml",
+            "[[rule]]\nid = \"x\"\nwhen = \"a > 1\"\nseverity = \"warn\"\nsummary = \"\"\n\n[[rule.test]]\nname = \"low\"\nsignals = { a = 0 }\nfires = true\n",
+            &mut rows,
+        );
+        run_embedded_tests("broken.toml", "[[rule]\n", &mut rows);
+        let mut buf: Vec<u8> = Vec::new();
+        let failed = run_rules_test_inner(&rows, false, &mut buf).unwrap();
+        let output = String::from_utf8(buf).unwrap();
+        assert_eq!(failed, 2);
+        assert!(output.contains("FAIL: expected to fire, did not fire"), "got: {output}");
+        assert!(output.contains("broken.toml"), "got: {output}");
+    }
+
     #[test]
     fn create_progress_bar_drains_channel_and_returns() {
         use crate::runner::thread::{EventKind, ProgressEvent};
@@ -10,7 +10,7 @@ use chrono::Local;
 
 use crate::baseline::stats::sample_stats;
 use crate::collector::{CollectCtx, Result};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
 
 /// Histogram-producing tools and event-tracing tools bundled as a single collector.
 pub const TOOLS: &[&str] = &["runqlat", "biolatency", "tcpretrans", "execsnoop", "cachestat"];
@@ -59,6 +59,7 @@ impl super::Collector for BpfCollector {
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             });
 
             if let Some(binary) = resolved {
@@ -101,6 +102,7 @@ fn run_histogram_tool(tool: &str, binary: &str, now: chrono::DateTime<Local>) ->
         samples: Some(samples),
         stats,
         baseline: None,
+        labels: Labels::new(),
     })
 }
 
@@ -1,7 +1,7 @@
 //! Cgroup v1/v2 collector (SDD Req 10).
 //!
 //! Detects cgroup v1 vs v2 and reads cpu.stat, memory.current, memory.max,
-//! memory.events, io.stat, and pids.current. Uses ctx.cgroup_path when set;
+//! memory.events, io.stat, pids.current and (v2 only) cgroup.controllers. Uses ctx.cgroup_path when set;
 //! otherwise auto-detects via /proc/self/cgroup. Returns empty Vec gracefully
 //! when not running inside a cgroup or on hosts without /sys/fs/cgroup.
 
@@ -10,7 +10,65 @@ use std::path::{Path, PathBuf};
 use chrono::Local;
 
 use super::{CollectCtx, Collector, Result};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit, label};
+
+const ID: &str = "cgroup";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        ID,
+        "cgroup.memory_bytes",
+        Unit::Bytes,
+        SignalKind::Gauge,
+        "cgroup memory usage (bytes)",
+    )
+    .with_use("memory", "utilization")
+    .labelled(label::CGROUP),
+    SignalSpec::new(
+        ID,
+        "cgroup.memory_limit_bytes",
+        Unit::Bytes,
+        SignalKind::Gauge,
+        "cgroup memory limit (bytes)",
+    )
+    .labelled(label::CGROUP),
+    SignalSpec::new(
+        ID,
+        "cgroup.oom_kills",
+        Unit::Count,
+        SignalKind::Counter,
+        "cgroup OOM kill count",
+    )
+    .with_use("memory", "errors")
+    .labelled(label::CGROUP),
+    SignalSpec::new(
+        ID,
+        "cgroup.pids_current",
+        Unit::Count,
+        SignalKind::Gauge,
+        "cgroup current PID count",
+    )
+    .labelled(label::CGROUP),
+    SignalSpec::new(
+        ID,
+        "cgroup.cpu_throttled_usec",
+        Unit::Microseconds,
+        SignalKind::Counter,
+        "cgroup CPU throttle time (µs)",
+    )
+    .with_use("cpu", "saturation")
+    .labelled(label::CGROUP),
+    SignalSpec::new(
+        ID,
+        "cgroup.controllers",
+        Unit::None,
+        SignalKind::Text,
+        "Enabled cgroup v2 controllers (text)",
+    )
+    .labelled(label::CGROUP),
+];
 
 #[derive(Debug, Clone, Default)]
 pub struct CgroupCollector;
@@ -23,7 +81,7 @@ impl CgroupCollector {
 
 impl Collector for CgroupCollector {
     fn id(&self) -> &str {
-        "cgroup"
+        ID
     }
 
     fn collect(&self, ctx: &CollectCtx) -> Result<Vec<Signal>> {
@@ -41,7 +99,11 @@ impl Collector for CgroupCollector {
             collect_v1(&base, &mut signals, now);
         }
 
-        Ok(signals)
+        let cgroup = base.display().to_string();
+        Ok(signals
+            .into_iter()
+            .map(|s| s.with_label(label::CGROUP, cgroup.as_str()))
+            .collect())
     }
 }
 
@@ -70,6 +132,20 @@ fn detect_cgroup_v2_path() -> Option<PathBuf> {
 }
 
 fn collect_v2(base: &Path, signals: &mut Vec<Signal>, now: chrono::DateTime<Local>) {
+    // Space-separated controller list, e.g. "cpu io memory pids"; rules test
+    // it with `contains(cgroup.controllers, "memory")`.
+    if let Ok(controllers) = std::fs::read_to_string(base.join("cgroup.controllers")) {
+        signals.push(Signal {
+            id: "cgroup.controllers".to_string(),
+            value: SignalValue::Text(controllers.trim().to_string()),
+            unit: Unit::None,
+            at: now,
+            samples: None,
+            stats: None,
+            baseline: None,
+            labels: Labels::new(),
+        });
+    }
     if let Some(v) = read_u64(base.join("memory.current")) {
         push(signals, "cgroup.memory_bytes", v as f64, Unit::Bytes, now);
     }
@@ -144,6 +220,7 @@ fn push(signals: &mut Vec<Signal>, id: &str, val: f64, unit: Unit, now: chrono::
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     });
 }
 
@@ -191,6 +268,11 @@ mod tests {
             ids
         );
         assert!(ids.contains(&"cgroup.oom_kills"), "missing oom_kills: {:?}", ids);
+        let controllers = signals
+            .iter()
+            .find(|s| s.id == "cgroup.controllers")
+            .expect("controllers");
+        assert_eq!(controllers.value, SignalValue::Text("cpu memory pids".to_string()));
         assert!(ids.contains(&"cgroup.pids_current"), "missing pids_current: {:?}", ids);
         assert!(
             ids.contains(&"cgroup.cpu_throttled_usec"),
@@ -205,6 +287,21 @@ mod tests {
         assert_eq!(oom.value, crate::signal::SignalValue::F64(3.0));
     }
 
+    #[test]
+    fn collect_labels_signals_with_the_cgroup() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("memory.current"), "1024\n").unwrap();
+        let ctx = CollectCtx {
+            cgroup_path: Some(dir.path().to_path_buf()),
+            ..CollectCtx::default()
+        };
+        let signals = CgroupCollector::new().collect(&ctx).unwrap();
+        let mem = signals.iter().find(|s| s.id == "cgroup.memory_bytes").unwrap();
+        let cgroup = dir.path().display().to_string();
+        assert_eq!(mem.label(label::CGROUP), Some(cgroup.as_str()));
+        assert_eq!(mem.key(), format!("cgroup.memory_bytes{{cgroup={cgroup}}}"));
+    }
+
     #[test]
     fn mem_max_unlimited_emits_zero() {
         let dir = tempfile::tempdir().unwrap();
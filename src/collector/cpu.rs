@@ -5,9 +5,50 @@ use std::time::{Duration, Instant};
 use chrono::Local;
 
 use super::{CollectCtx, Collector, Result};
-use crate::baseline::stats::sample_stats;
+use crate::baseline::stats::sample_stats_every;
 use crate::collector::platform::{CpuSnapshot, read_cpu_snapshot};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+const ID: &str = "cpu";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        ID,
+        "cpu.usr_pct",
+        Unit::Pct,
+        SignalKind::Gauge,
+        "CPU user-space time (%)",
+    )
+    .with_use("cpu", "utilization"),
+    SignalSpec::new(ID, "cpu.sys_pct", Unit::Pct, SignalKind::Gauge, "CPU kernel time (%)")
+        .with_use("cpu", "utilization"),
+    SignalSpec::new(ID, "cpu.idle_pct", Unit::Pct, SignalKind::Gauge, "CPU idle time (%)")
+        .with_use("cpu", "utilization"),
+    SignalSpec::new(
+        ID,
+        "cpu.iowait_pct",
+        Unit::Pct,
+        SignalKind::Gauge,
+        "CPU time waiting on I/O (%)",
+    ),
+    SignalSpec::new(
+        ID,
+        "cpu.ctxt_per_sec",
+        Unit::Count,
+        SignalKind::Rate,
+        "Context switches per second",
+    ),
+    SignalSpec::new(
+        ID,
+        "cpu.run_queue",
+        Unit::Count,
+        SignalKind::Gauge,
+        "Runnable processes in the run queue",
+    )
+    .with_use("cpu", "saturation"),
+];
 
 const MIN_WINDOW: Duration = Duration::from_secs(1);
 
@@ -91,7 +132,7 @@ impl CpuCollector {
 
 impl Collector for CpuCollector {
     fn id(&self) -> &str {
-        "cpu"
+        ID
     }
 
     fn supports_sampling(&self) -> bool {
@@ -165,7 +206,7 @@ impl CpuCollector {
             .into_iter()
             .map(|(id, (unit, vals))| {
                 let value = vals.last().copied().unwrap_or(0.0);
-                let stats = sample_stats(&vals);
+                let stats = sample_stats_every(&vals, interval);
                 Signal {
                     id,
                     value: crate::signal::SignalValue::F64(value),
@@ -174,6 +215,7 @@ impl CpuCollector {
                     samples: Some(vals),
                     stats,
                     baseline: None,
+                    labels: Labels::new(),
                 }
             })
             .collect();
@@ -267,5 +309,6 @@ fn push(signals: &mut Vec<Signal>, id: &str, v: f64, unit: Unit, at: chrono::Dat
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     });
 }
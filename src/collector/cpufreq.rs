@@ -6,7 +6,28 @@
 use chrono::Local;
 
 use super::{CollectCtx, Collector, Result};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+const ID: &str = "cpufreq";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        ID,
+        "cpu.freq_ratio",
+        Unit::None,
+        SignalKind::Gauge,
+        "Current CPU frequency / max frequency ratio",
+    ),
+    SignalSpec::new(
+        ID,
+        "cpu.temp_celsius",
+        Unit::Celsius,
+        SignalKind::Gauge,
+        "Maximum CPU temperature (°C)",
+    ),
+];
 
 #[derive(Debug, Clone, Default)]
 pub struct CpuFreqCollector;
@@ -19,7 +40,7 @@ impl CpuFreqCollector {
 
 impl Collector for CpuFreqCollector {
     fn id(&self) -> &str {
-        "cpufreq"
+        ID
     }
 
     fn collect(&self, _ctx: &CollectCtx) -> Result<Vec<Signal>> {
@@ -36,6 +57,7 @@ impl Collector for CpuFreqCollector {
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             });
         }
         if let Some(temp) = snap.temp_celsius {
@@ -47,6 +69,7 @@ impl Collector for CpuFreqCollector {
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             });
         }
 
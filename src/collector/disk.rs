@@ -10,7 +10,66 @@ use chrono::Local;
 
 use super::{CollectCtx, Collector, Result};
 use crate::collector::platform::{DiskDevSnapshot, read_disk_snapshots};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit, label};
+
+const ID: &str = "disk";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        ID,
+        "disk.max_util_pct",
+        Unit::Pct,
+        SignalKind::Gauge,
+        "Highest per-device disk utilisation (%)",
+    )
+    .with_use("disk", "utilization"),
+    SignalSpec::new(
+        ID,
+        "disk.max_await_ms",
+        Unit::MillisPerOp,
+        SignalKind::Gauge,
+        "Highest per-device I/O latency (ms)",
+    )
+    .with_use("disk", "saturation"),
+    SignalSpec::new(
+        ID,
+        "disk.*.util_pct",
+        Unit::Pct,
+        SignalKind::Gauge,
+        "Per-device disk utilisation (%)",
+    )
+    .with_use("disk", "utilization")
+    .labelled(label::DEVICE),
+    SignalSpec::new(
+        ID,
+        "disk.*.await_ms",
+        Unit::MillisPerOp,
+        SignalKind::Gauge,
+        "Per-device I/O latency (ms)",
+    )
+    .with_use("disk", "saturation")
+    .labelled(label::DEVICE),
+    SignalSpec::new(
+        ID,
+        "disk.*.read_iops",
+        Unit::Iops,
+        SignalKind::Rate,
+        "Per-device read operations per second",
+    )
+    .with_use("disk", "utilization")
+    .labelled(label::DEVICE),
+    SignalSpec::new(
+        ID,
+        "disk.*.write_iops",
+        Unit::Iops,
+        SignalKind::Rate,
+        "Per-device write operations per second",
+    )
+    .with_use("disk", "utilization")
+    .labelled(label::DEVICE),
+];
 
 const MIN_WINDOW: Duration = Duration::from_secs(1);
 
@@ -43,23 +102,25 @@ impl DiskCollector {
                 let write_t_delta = b.write_time_ms.saturating_sub(a.write_time_ms) as f64;
                 let io_t_delta = b.io_time_ms.saturating_sub(a.io_time_ms) as f64;
 
-                push(
+                push_device(
                     &mut signals,
-                    &format!("disk.{}.read_iops", dev),
+                    dev,
+                    "read_iops",
                     read_delta / elapsed_secs,
                     Unit::Iops,
                     now,
                 );
-                push(
+                push_device(
                     &mut signals,
-                    &format!("disk.{}.write_iops", dev),
+                    dev,
+                    "write_iops",
                     write_delta / elapsed_secs,
                     Unit::Iops,
                     now,
                 );
                 let util = (io_t_delta / (elapsed_secs * 1000.0)) * 100.0;
                 let util = util.min(100.0);
-                push(&mut signals, &format!("disk.{}.util_pct", dev), util, Unit::Pct, now);
+                push_device(&mut signals, dev, "util_pct", util, Unit::Pct, now);
                 max_util = Some(max_util.unwrap_or(f64::NEG_INFINITY).max(util));
                 let total_ios = read_delta + write_delta;
                 let await_ms = if total_ios > 0.0 {
@@ -67,13 +128,7 @@ impl DiskCollector {
                 } else {
                     0.0
                 };
-                push(
-                    &mut signals,
-                    &format!("disk.{}.await_ms", dev),
-                    await_ms,
-                    Unit::MillisPerOp,
-                    now,
-                );
+                push_device(&mut signals, dev, "await_ms", await_ms, Unit::MillisPerOp, now);
                 max_await = Some(max_await.unwrap_or(f64::NEG_INFINITY).max(await_ms));
             }
         }
@@ -114,16 +169,18 @@ impl DiskCollector {
             let read_delta = b_dev.read_ios.saturating_sub(a_dev.read_ios) as f64;
             let write_delta = b_dev.write_ios.saturating_sub(a_dev.write_ios) as f64;
 
-            push(
+            push_device(
                 &mut signals,
-                &format!("disk.{}.read_iops", b_dev.name),
+                &b_dev.name,
+                "read_iops",
                 read_delta / elapsed_secs,
                 Unit::Iops,
                 now,
             );
-            push(
+            push_device(
                 &mut signals,
-                &format!("disk.{}.write_iops", b_dev.name),
+                &b_dev.name,
+                "write_iops",
                 write_delta / elapsed_secs,
                 Unit::Iops,
                 now,
@@ -133,13 +190,7 @@ impl DiskCollector {
                 let io_t_delta = b_io.saturating_sub(a_io) as f64;
                 let util = (io_t_delta / (elapsed_secs * 1000.0)) * 100.0;
                 let util = util.min(100.0);
-                push(
-                    &mut signals,
-                    &format!("disk.{}.util_pct", b_dev.name),
-                    util,
-                    Unit::Pct,
-                    now,
-                );
+                push_device(&mut signals, &b_dev.name, "util_pct", util, Unit::Pct, now);
                 max_util = Some(max_util.unwrap_or(f64::NEG_INFINITY).max(util));
 
                 let read_t_delta = b_dev
@@ -156,13 +207,7 @@ impl DiskCollector {
                 } else {
                     0.0
                 };
-                push(
-                    &mut signals,
-                    &format!("disk.{}.await_ms", b_dev.name),
-                    await_ms,
-                    Unit::MillisPerOp,
-                    now,
-                );
+                push_device(&mut signals, &b_dev.name, "await_ms", await_ms, Unit::MillisPerOp, now);
                 max_await = Some(max_await.unwrap_or(f64::NEG_INFINITY).max(await_ms));
             }
         }
@@ -180,7 +225,7 @@ impl DiskCollector {
 
 impl Collector for DiskCollector {
     fn id(&self) -> &str {
-        "disk"
+        ID
     }
 
     fn source_commands(&self) -> &[&str] {
@@ -235,6 +280,14 @@ fn parse_diskstats(s: &str) -> std::collections::HashMap<String, DiskStats> {
     out
 }
 
+/// Push `disk.<dev>.<metric>`, labelled with the device.
+fn push_device(signals: &mut Vec<Signal>, dev: &str, metric: &str, v: f64, unit: Unit, at: chrono::DateTime<Local>) {
+    push(signals, &format!("disk.{}.{}", dev, metric), v, unit, at);
+    if let Some(signal) = signals.last_mut() {
+        signal.labels.insert(label::DEVICE.to_string(), dev.to_string());
+    }
+}
+
 fn push(signals: &mut Vec<Signal>, id: &str, v: f64, unit: Unit, at: chrono::DateTime<Local>) {
     signals.push(Signal {
         id: id.to_string(),
@@ -244,5 +297,6 @@ fn push(signals: &mut Vec<Signal>, id: &str, v: f64, unit: Unit, at: chrono::Dat
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     });
 }
@@ -10,7 +10,68 @@ use regex::Regex;
 use std::sync::OnceLock;
 
 use crate::collector::{CollectCtx, Result};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+const ID: &str = "dmesg";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        ID,
+        "dmesg.oom_count",
+        Unit::Count,
+        SignalKind::Counter,
+        "OOM kill events in dmesg",
+    )
+    .with_use("memory", "errors"),
+    SignalSpec::new(
+        ID,
+        "dmesg.blocked_task_count",
+        Unit::Count,
+        SignalKind::Counter,
+        "Blocked-task (hung-task) events in dmesg",
+    ),
+    SignalSpec::new(
+        ID,
+        "dmesg.fs_error_count",
+        Unit::Count,
+        SignalKind::Counter,
+        "Filesystem error events in dmesg",
+    )
+    .with_use("disk", "errors"),
+    SignalSpec::new(
+        ID,
+        "dmesg.segfault_count",
+        Unit::Count,
+        SignalKind::Counter,
+        "Segfault events in dmesg",
+    ),
+    SignalSpec::new(
+        ID,
+        "dmesg.mce_count",
+        Unit::Count,
+        SignalKind::Counter,
+        "Machine-check exception events in dmesg",
+    )
+    .with_use("cpu", "errors"),
+    SignalSpec::new(
+        ID,
+        "dmesg.nic_flap_count",
+        Unit::Count,
+        SignalKind::Counter,
+        "NIC link-flap events in dmesg",
+    )
+    .with_use("network", "errors"),
+    SignalSpec::new(
+        ID,
+        "dmesg.io_error_count",
+        Unit::Count,
+        SignalKind::Counter,
+        "I/O error events in dmesg",
+    )
+    .with_use("disk", "errors"),
+];
 
 #[derive(Debug, Default)]
 pub struct DmesgCollector;
@@ -43,6 +104,7 @@ impl DmesgCollector {
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             })
             .collect()
     }
@@ -50,7 +112,7 @@ impl DmesgCollector {
 
 impl super::Collector for DmesgCollector {
     fn id(&self) -> &str {
-        "dmesg"
+        ID
     }
 
     fn collect(&self, _ctx: &CollectCtx) -> Result<Vec<Signal>> {
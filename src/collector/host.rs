@@ -3,7 +3,36 @@
 use chrono::Local;
 
 use crate::collector::{CollectCtx, Result};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+const ID: &str = "host";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        ID,
+        "host.cpu_count",
+        Unit::Count,
+        SignalKind::Gauge,
+        "Logical CPU count",
+    ),
+    SignalSpec::new(
+        ID,
+        "host.mem_total_bytes",
+        Unit::Bytes,
+        SignalKind::Gauge,
+        "Total physical memory (bytes)",
+    ),
+    SignalSpec::new(
+        ID,
+        "host.load_avg_1m",
+        Unit::None,
+        SignalKind::Gauge,
+        "1-minute load average",
+    )
+    .with_use("cpu", "saturation"),
+];
 
 #[derive(Debug, Default)]
 pub struct HostCollector;
@@ -16,7 +45,7 @@ impl HostCollector {
 
 impl super::Collector for HostCollector {
     fn id(&self) -> &str {
-        "host"
+        ID
     }
 
     fn source_commands(&self) -> &[&str] {
@@ -46,20 +75,22 @@ impl super::Collector for HostCollector {
             Signal {
                 id: "host.cpu_count".to_string(),
                 value: SignalValue::F64(cpu_count),
-                unit: Unit::None,
+                unit: Unit::Count,
                 at: now,
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             },
             Signal {
                 id: "host.mem_total_bytes".to_string(),
                 value: SignalValue::F64(mem_total),
-                unit: Unit::None,
+                unit: Unit::Bytes,
                 at: now,
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             },
             Signal {
                 id: "host.load_avg_1m".to_string(),
@@ -69,6 +100,7 @@ impl super::Collector for HostCollector {
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             },
         ])
     }
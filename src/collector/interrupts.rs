@@ -7,7 +7,20 @@
 use chrono::Local;
 
 use super::{CollectCtx, Collector, Result};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+const ID: &str = "interrupts";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[SignalSpec::new(
+    ID,
+    "net.max_cpu_irq_pct",
+    Unit::Pct,
+    SignalKind::Gauge,
+    "Highest per-CPU NIC interrupt load (%)",
+)
+.with_use("network", "saturation")];
 
 #[derive(Debug, Clone, Default)]
 pub struct InterruptsCollector;
@@ -20,7 +33,7 @@ impl InterruptsCollector {
 
 impl Collector for InterruptsCollector {
     fn id(&self) -> &str {
-        "interrupts"
+        ID
     }
 
     fn collect(&self, _ctx: &CollectCtx) -> Result<Vec<Signal>> {
@@ -39,6 +52,7 @@ impl Collector for InterruptsCollector {
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             });
         }
         Ok(signals)
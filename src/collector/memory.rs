@@ -6,7 +6,60 @@ use chrono::Local;
 
 use super::{CollectCtx, Collector, Error, Result};
 use crate::collector::platform::{MemSnapshot, read_mem_snapshot};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+const ID: &str = "memory";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(ID, "mem.total_mb", Unit::Megabytes, SignalKind::Gauge, "Total RAM (MB)"),
+    SignalSpec::new(ID, "mem.used_mb", Unit::Megabytes, SignalKind::Gauge, "Used RAM (MB)")
+        .with_use("memory", "utilization"),
+    SignalSpec::new(ID, "mem.free_mb", Unit::Megabytes, SignalKind::Gauge, "Free RAM (MB)")
+        .with_use("memory", "utilization"),
+    SignalSpec::new(
+        ID,
+        "mem.available_mb",
+        Unit::Megabytes,
+        SignalKind::Gauge,
+        "Available RAM (MB, Linux only)",
+    )
+    .with_use("memory", "utilization"),
+    SignalSpec::new(ID, "mem.free_pct", Unit::Pct, SignalKind::Gauge, "Free RAM (%)")
+        .with_use("memory", "utilization"),
+    SignalSpec::new(
+        ID,
+        "swap.total_mb",
+        Unit::Megabytes,
+        SignalKind::Gauge,
+        "Total swap space (MB)",
+    ),
+    SignalSpec::new(
+        ID,
+        "swap.used_mb",
+        Unit::Megabytes,
+        SignalKind::Gauge,
+        "Used swap space (MB)",
+    )
+    .with_use("memory", "saturation"),
+    SignalSpec::new(
+        ID,
+        "swap.free_mb",
+        Unit::Megabytes,
+        SignalKind::Gauge,
+        "Free swap space (MB)",
+    )
+    .with_use("memory", "saturation"),
+    SignalSpec::new(
+        ID,
+        "vmstat.swap_in",
+        Unit::Count,
+        SignalKind::Counter,
+        "Pages swapped in from disk",
+    )
+    .with_use("memory", "saturation"),
+];
 
 const MIN_WINDOW: Duration = Duration::from_secs(1);
 
@@ -61,7 +114,7 @@ impl MemoryCollector {
 
 impl Collector for MemoryCollector {
     fn id(&self) -> &str {
-        "memory"
+        ID
     }
 
     #[cfg(target_os = "linux")]
@@ -166,5 +219,6 @@ fn push(signals: &mut Vec<Signal>, id: &str, v: f64, unit: Unit, at: chrono::Dat
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     });
 }
@@ -10,7 +10,8 @@ use std::time::Duration;
 
 use thiserror::Error;
 
-use crate::signal::Signal;
+use crate::baseline::BaselineRecord;
+use crate::signal::{Signal, SignalValue};
 
 #[cfg(feature = "bpf")]
 pub mod bpf;
@@ -43,8 +44,36 @@ pub struct CollectCtx {
     pub duration: Option<Duration>,
     pub interval: Option<Duration>,
     pub cgroup_path: Option<PathBuf>,
-    pub baseline: Option<Arc<()>>,
+    /// The most recent rolling baseline record, which `delta(...)`
+    /// predicates compare against.
+    pub baseline: Option<Arc<BaselineRecord>>,
     pub cpu_count: usize,
+    /// `uname` string of the analysed host (see `Context::uname`), used to
+    /// resolve the `host.os`, `host.kernel`, `host.arch` and `host.uname`
+    /// predicate paths.
+    pub uname: Option<String>,
+}
+
+impl CollectCtx {
+    /// Resolve a `host.*` ID that comes from this context rather than from a
+    /// collector signal. Returns `None` for any other ID.
+    pub fn host_value(&self, id: &str) -> Option<SignalValue> {
+        if id == "host.cpu_count" {
+            return Some(SignalValue::I64(self.cpu_count as i64));
+        }
+        // Context::uname is "<sysname> <nodename> <release> <version...> <machine>";
+        // only the version may contain spaces.
+        let uname = self.uname.as_deref().filter(|u| !u.is_empty())?;
+        let fields: Vec<&str> = uname.split_whitespace().collect();
+        let value = match id {
+            "host.uname" => uname,
+            "host.os" => fields.first()?,
+            "host.kernel" => fields.get(2)?,
+            "host.arch" if fields.len() > 3 => fields.last()?,
+            _ => return None,
+        };
+        Some(SignalValue::Text(value.to_string()))
+    }
 }
 
 pub trait Collector: std::fmt::Debug + Send + Sync {
@@ -10,7 +10,54 @@ use chrono::Local;
 
 use super::{CollectCtx, Collector, Result};
 use crate::collector::platform::{NetSnapshot, read_net_snapshot};
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::signal::catalog::{SignalKind, SignalSpec};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+const ID: &str = "network";
+
+/// Signals this collector emits; see `signal::catalog`.
+pub const SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        ID,
+        "net.rx_drops",
+        Unit::Count,
+        SignalKind::Counter,
+        "Total RX drop counter across interfaces",
+    )
+    .with_use("network", "errors"),
+    SignalSpec::new(
+        ID,
+        "net.retrans_pct",
+        Unit::Pct,
+        SignalKind::Gauge,
+        "TCP retransmit ratio (%)",
+    )
+    .with_use("network", "errors"),
+    SignalSpec::new(
+        ID,
+        "net.tw_count",
+        Unit::Count,
+        SignalKind::Gauge,
+        "TCP TIME_WAIT socket count",
+    )
+    .with_use("network", "saturation"),
+    SignalSpec::new(
+        ID,
+        "net.connect_failures",
+        Unit::Count,
+        SignalKind::Counter,
+        "TCP connection attempt failures",
+    )
+    .with_use("network", "errors"),
+    SignalSpec::new(
+        ID,
+        "net.estab_resets",
+        Unit::Count,
+        SignalKind::Counter,
+        "TCP established-connection resets",
+    )
+    .with_use("network", "errors"),
+];
 
 // Default matches `sar -n TCP,ETCP 1 5`. When --interval is set, the
 // collector uses that value so each measurement window aligns with the
@@ -108,7 +155,7 @@ impl NetworkCollector {
 
 impl Collector for NetworkCollector {
     fn id(&self) -> &str {
-        "network"
+        ID
     }
 
     #[cfg(target_os = "linux")]
@@ -212,6 +259,7 @@ fn push(signals: &mut Vec<Signal>, id: &str, val: f64, unit: Unit, now: chrono::
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     });
 }
 
@@ -3,6 +3,10 @@
 //! SDD §117–§118: per-signal deltas plus three finding sections (only-in-a,
 //! only-in-b, severity-changed). Default text output; `--output json`
 //! re-uses `serde_json` over the `DiffReport` struct.
+//!
+//! Signals are matched by `Signal::key`, so labelled signals whose ID does
+//! not embed the label, e.g. `cgroup.memory_bytes` of two different cgroups,
+//! show up as only-in-a and only-in-b rather than as a delta.
 
 use std::collections::HashMap;
 
@@ -10,11 +14,14 @@ use serde::Serialize;
 
 use crate::analysis::AnalysisReport;
 use crate::finding::{Finding, Severity};
-use crate::signal::SignalValue;
+use crate::signal::{Labels, Signal, SignalValue};
 
 #[derive(Debug, Serialize)]
 pub struct SignalDelta {
+    /// `Signal::key`: the ID, plus any labels it does not embed.
     pub signal_id: String,
+    #[serde(skip_serializing_if = "Labels::is_empty")]
+    pub labels: Labels,
     pub value_in_a: f64,
     pub value_in_b: f64,
     pub delta: f64,
@@ -41,35 +48,28 @@ pub struct DiffReport {
 pub fn diff(a: &AnalysisReport, b: &AnalysisReport) -> DiffReport {
     let mut out = DiffReport::default();
 
-    let map_a: HashMap<&str, f64> = a
-        .signals()
-        .iter()
-        .filter_map(|s| signal_to_f64(&s.value).map(|v| (s.id.as_str(), v)))
-        .collect();
-    let map_b: HashMap<&str, f64> = b
-        .signals()
-        .iter()
-        .filter_map(|s| signal_to_f64(&s.value).map(|v| (s.id.as_str(), v)))
-        .collect();
+    let map_a = numeric_signals(a);
+    let map_b = numeric_signals(b);
 
-    for (id, va) in &map_a {
-        match map_b.get(id) {
-            Some(vb) => {
+    for (key, (signal, va)) in &map_a {
+        match map_b.get(key) {
+            Some((_, vb)) => {
                 if (va - vb).abs() > f64::EPSILON {
                     out.signal_deltas.push(SignalDelta {
-                        signal_id: id.to_string(),
+                        signal_id: key.clone(),
+                        labels: signal.labels.clone(),
                         value_in_a: *va,
                         value_in_b: *vb,
                         delta: vb - va,
                     });
                 }
             }
-            None => out.signals_only_in_a.push(id.to_string()),
+            None => out.signals_only_in_a.push(key.clone()),
         }
     }
-    for id in map_b.keys() {
-        if !map_a.contains_key(id) {
-            out.signals_only_in_b.push(id.to_string());
+    for key in map_b.keys() {
+        if !map_a.contains_key(key) {
+            out.signals_only_in_b.push(key.clone());
         }
     }
     out.signals_only_in_a.sort();
@@ -105,6 +105,15 @@ pub fn diff(a: &AnalysisReport, b: &AnalysisReport) -> DiffReport {
     out
 }
 
+/// Numeric signals of `report` by `Signal::key`.
+fn numeric_signals(report: &AnalysisReport) -> HashMap<String, (&Signal, f64)> {
+    report
+        .signals()
+        .iter()
+        .filter_map(|s| signal_to_f64(&s.value).map(|v| (s.key(), (s, v))))
+        .collect()
+}
+
 fn signal_to_f64(v: &SignalValue) -> Option<f64> {
     match v {
         SignalValue::F64(x) => Some(*x),
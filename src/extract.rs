@@ -1,5 +1,5 @@
 use crate::cli::config::{Aggregate, CommandExtract};
-use crate::signal::{Signal, SignalValue};
+use crate::signal::{Labels, Signal, SignalValue};
 use regex::Regex;
 
 pub fn extract_signals(command_name: &str, stdout: &str, extracts: &[CommandExtract]) -> Vec<Signal> {
@@ -18,6 +18,7 @@ pub fn extract_signals(command_name: &str, stdout: &str, extracts: &[CommandExtr
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: Labels::new(),
             });
             continue;
         }
@@ -64,6 +65,7 @@ pub fn extract_signals(command_name: &str, stdout: &str, extracts: &[CommandExtr
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         });
     }
 
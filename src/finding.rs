@@ -4,6 +4,7 @@
 //! the report. Each carries the rule (or pattern) that fired, severity, the
 //! supporting `Evidence`, and ordered next-step `suggest` commands.
 
+use std::collections::HashMap;
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
@@ -39,6 +40,10 @@ pub struct Evidence {
     pub observed: SignalValue,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub source_commands: Vec<String>,
+    /// How the signal met the condition when `observed` alone does not say,
+    /// e.g. how many samples breached a `sustained(...)` term.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
 }
 
 impl fmt::Display for Severity {
@@ -61,12 +66,24 @@ impl Severity {
     }
 }
 
-/// Threshold info for one signal, extracted from rule predicates.
+/// Threshold info for one signal, extracted from rule predicates. The
+/// top-level fields describe the most severe threshold.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThresholdInfo {
     pub severity: Severity,
     pub op: String,
     pub value: f64,
+    /// Every distinct threshold on the signal, least severe first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<ThresholdTier>,
+}
+
+/// One threshold from one rule tier.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThresholdTier {
+    pub severity: Severity,
+    pub op: String,
+    pub value: f64,
 }
 
 /// Sort findings in-place per SDD §101: severity Crit → Warn → Info, then
@@ -81,3 +98,124 @@ pub fn sort_findings(findings: &mut [Finding]) {
         }
     });
 }
+
+/// A finding held back because another finding that fired declares it in
+/// `inhibits`, e.g. `mem.pressure` while `dmesg.oom_kill` fires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InhibitedFinding {
+    /// IDs of the firing findings that inhibit this one, sorted and unique.
+    pub by: Vec<String>,
+    pub finding: Finding,
+}
+
+/// Split `findings` into those that stay and those inhibited by another
+/// finding in the same set. `inhibits` maps a finding ID to the ID patterns
+/// it suppresses; `*` matches any run of characters, dots included, so
+/// `cpu.*` covers every `cpu.` finding. A finding never inhibits itself, and
+/// two findings that inhibit each other both stay. Inhibited findings still
+/// inhibit others, so suppression follows chains of root causes.
+pub fn inhibit(
+    findings: Vec<Finding>,
+    inhibits: &HashMap<String, Vec<String>>,
+) -> (Vec<Finding>, Vec<InhibitedFinding>) {
+    let targets = |by: &str, id: &str| {
+        by != id
+            && inhibits
+                .get(by)
+                .is_some_and(|patterns| patterns.iter().any(|p| crate::rule::segment_matches(p, id)))
+    };
+    let by: Vec<Vec<String>> = findings
+        .iter()
+        .map(|f| {
+            let mut by: Vec<String> = findings
+                .iter()
+                .filter(|g| targets(&g.id, &f.id) && !targets(&f.id, &g.id))
+                .map(|g| g.id.clone())
+                .collect();
+            by.sort();
+            by.dedup();
+            by
+        })
+        .collect();
+    let mut kept = Vec::new();
+    let mut inhibited = Vec::new();
+    for (finding, by) in findings.into_iter().zip(by) {
+        if by.is_empty() {
+            kept.push(finding);
+        } else {
+            inhibited.push(InhibitedFinding { by, finding });
+        }
+    }
+    (kept, inhibited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(id: &str, severity: Severity) -> Finding {
+        Finding {
+            id: id.to_string(),
+            kind: FindingKind::Rule,
+            severity,
+            summary: String::new(),
+            evidence: Vec::new(),
+            suggest: Vec::new(),
+        }
+    }
+
+    fn inhibits(entries: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        entries
+            .iter()
+            .map(|(id, patterns)| (id.to_string(), patterns.iter().map(|p| p.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn root_finding_suppresses_matching_findings() {
+        let findings = vec![
+            finding("mem.pressure", Severity::Crit),
+            finding("cpu.iowait_elevated", Severity::Warn),
+            finding("dmesg.oom_kill", Severity::Warn),
+            finding("net.retransmit_elevated", Severity::Warn),
+        ];
+        let (kept, inhibited) = inhibit(findings, &inhibits(&[("dmesg.oom_kill", &["mem.pressure", "cpu.*"])]));
+        let kept: Vec<&str> = kept.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(kept, vec!["dmesg.oom_kill", "net.retransmit_elevated"]);
+        let inhibited: Vec<(&str, &[String])> = inhibited
+            .iter()
+            .map(|i| (i.finding.id.as_str(), i.by.as_slice()))
+            .collect();
+        assert_eq!(
+            inhibited,
+            vec![
+                ("mem.pressure", &["dmesg.oom_kill".to_string()][..]),
+                ("cpu.iowait_elevated", &["dmesg.oom_kill".to_string()][..]),
+            ]
+        );
+    }
+
+    #[test]
+    fn self_and_mutual_inhibition_keep_findings() {
+        let findings = vec![finding("a.x", Severity::Warn), finding("b.x", Severity::Warn)];
+        let (kept, inhibited) = inhibit(findings, &inhibits(&[("a.x", &["*"]), ("b.x", &["a.*"])]));
+        assert_eq!(kept.len(), 2);
+        assert!(inhibited.is_empty());
+    }
+
+    #[test]
+    fn inhibitors_sharing_an_id_are_listed_once() {
+        let findings = vec![
+            finding("dmesg.oom_kill", Severity::Warn),
+            finding("cgroup.oom", Severity::Warn),
+            finding("dmesg.oom_kill", Severity::Crit),
+            finding("mem.pressure", Severity::Warn),
+        ];
+        let (_, inhibited) = inhibit(
+            findings,
+            &inhibits(&[("dmesg.oom_kill", &["mem.*"]), ("cgroup.oom", &["mem.*"])]),
+        );
+        assert_eq!(inhibited.len(), 1);
+        assert_eq!(inhibited[0].by, vec!["cgroup.oom", "dmesg.oom_kill"]);
+    }
+}
@@ -45,6 +45,9 @@ pub mod runner;
 /// Typed metric values (Phase 1+).
 pub mod signal;
 
+/// Acknowledged findings silenced until an expiry date.
+pub mod silence;
+
 /// Named workload rule packs (Phase 8+).
 pub mod workload;
 
@@ -11,7 +11,9 @@ use crate::analysis::AnalysisReport;
 use crate::command::CommandResult;
 use crate::finding::Finding;
 use crate::redact::Redactor;
-use crate::signal::{Signal, SignalValue, Unit};
+use crate::rule::near_miss::NearMiss;
+use crate::signal::catalog::{self, SignalKind};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
 
 /// One command's stdout, truncated to `MAX_EXCERPT_CHARS`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,21 +23,33 @@ pub struct LlmExcerpt {
 }
 
 /// Lean signal representation for LLM output — omits null sampling fields and
-/// per-signal timestamps (redundant with the top-level `date_time`).
+/// per-signal timestamps (redundant with the top-level `date_time`). Signals
+/// of the built-in collectors carry their kind and description from
+/// `signal::catalog`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LlmSignal {
     pub id: String,
     pub value: SignalValue,
     #[serde(skip_serializing_if = "Unit::is_none")]
     pub unit: Unit,
+    #[serde(default, skip_serializing_if = "Labels::is_empty")]
+    pub labels: Labels,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<SignalKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 impl From<Signal> for LlmSignal {
     fn from(s: Signal) -> Self {
+        let spec = catalog::lookup(&s.id);
         Self {
             id: s.id,
             value: s.value,
             unit: s.unit,
+            labels: s.labels,
+            kind: spec.map(|spec| spec.kind),
+            description: spec.map(|spec| spec.description.to_string()),
         }
     }
 }
@@ -48,6 +62,10 @@ pub struct LlmOutput {
     pub host: LlmHost,
     pub signals: Vec<LlmSignal>,
     pub findings: Vec<Finding>,
+    /// Rules and patterns that came close to firing, with `--near-miss`.
+    /// Hints at incipient problems; not findings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub near_misses: Vec<NearMiss>,
     pub checked_ok: Vec<String>,
     pub raw_excerpts: Vec<LlmExcerpt>,
 }
@@ -123,6 +141,7 @@ impl LlmOutput {
             host,
             signals: llm_signals,
             findings: report.findings().to_vec(),
+            near_misses: report.near_misses().to_vec(),
             checked_ok: report.checked_ok().to_vec(),
             raw_excerpts,
         };
@@ -4,15 +4,34 @@
 //! fires when its `when` predicate evaluates to true. AND predicates require
 //! all constituent signals to be present (a missing signal makes the predicate
 //! false); OR predicates can fire on a subset of signals — if any branch
-//! evaluates to true the pattern fires. Pattern findings are distinguished from
-//! rule findings by `kind = FindingKind::Pattern`.
+//! evaluates to true the pattern fires. `NOT` and parentheses follow the rule
+//! grammar (see `crate::rule`); evidence covers every signal referenced by the
+//! predicate, negated terms included, plus the concrete signals that satisfied
+//! any `any()`/`all()`/aggregate glob terms. Pattern findings are distinguished from
+//! rule findings by `kind = FindingKind::Pattern`. `summary` and `suggest` are
+//! templates, rendered as for rules.
+//!
+//! Predicates may also test the findings of the rule pass through
+//! `finding.<id>` and `finding.<id>.severity` paths, so a correlation can be
+//! built from vetted rules instead of repeating their thresholds. Each such
+//! finding that fired becomes an evidence entry `finding.<id>` holding its
+//! severity, with its summary as the detail.
+//!
+//! `PatternsLoader` adds user patterns from a `patterns.d` directory to the
+//! built-ins, the way `RulesLoader` does for `rules.d`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use serde::Deserialize;
 use thiserror::Error;
 
 use crate::collector::CollectCtx;
 use crate::finding::{Evidence, Finding, FindingKind, Severity};
-use crate::rule::{Predicate, SignalIndex};
+use crate::rule::fixture::{RuleTest, TestToml};
+use crate::rule::near_miss::{NearMiss, near_miss};
+use crate::rule::template::TemplateContext;
+use crate::rule::{Predicate, RuleSource, SignalIndex, SignalMatch, load_user_files, severity_name};
 use crate::signal::{Signal, SignalValue};
 
 #[derive(Debug, Error)]
@@ -33,23 +52,62 @@ pub struct Pattern {
     pub severity: Severity,
     pub summary: String,
     pub suggest: Vec<String>,
+    /// IDs of findings this pattern's finding suppresses when it fires.
+    pub inhibits: Vec<String>,
 }
 
 /// Evaluates patterns against a signal set after the rule pass.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct PatternEngine {
     patterns: Vec<Pattern>,
+    tests: Vec<RuleTest>,
 }
 
 impl PatternEngine {
     /// Create an engine with no patterns.
     pub fn empty() -> Self {
-        Self { patterns: Vec::new() }
+        Self {
+            patterns: Vec::new(),
+            tests: Vec::new(),
+        }
     }
 
     /// Move all patterns from `other` into this engine.
     pub fn extend_from(&mut self, other: PatternEngine) {
         self.patterns.extend(other.patterns);
+        self.tests.extend(other.tests);
+    }
+
+    /// Move all patterns from `other` into this engine, replacing any
+    /// pattern with the same ID in place along with its tests.
+    pub fn override_from(&mut self, other: PatternEngine) {
+        let ids: Vec<&str> = other.patterns.iter().map(|p| p.id.as_str()).collect();
+        self.tests.retain(|t| !ids.contains(&t.id.as_str()));
+        self.tests.extend(other.tests);
+        for pattern in other.patterns {
+            match self.patterns.iter_mut().find(|p| p.id == pattern.id) {
+                Some(existing) => *existing = pattern,
+                None => self.patterns.push(pattern),
+            }
+        }
+    }
+
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    /// The `[[pattern.test]]` blocks of every pattern, in definition order.
+    pub fn tests(&self) -> &[RuleTest] {
+        &self.tests
+    }
+
+    /// The `inhibits` declarations of every pattern that has any, by pattern ID.
+    pub fn inhibits(&self) -> std::collections::HashMap<String, Vec<String>> {
+        self.patterns
+            .iter()
+            .filter(|p| !p.inhibits.is_empty())
+            .map(|p| (p.id.clone(), p.inhibits.clone()))
+            .collect()
     }
 
     /// Parse patterns from a TOML string (used in tests and for loading files).
@@ -66,11 +124,22 @@ impl PatternEngine {
             summary: String,
             #[serde(default)]
             suggest: Vec<String>,
+            #[serde(default)]
+            inhibits: Vec<String>,
+            #[serde(default)]
+            test: Vec<TestToml>,
         }
 
         let file: File = toml::from_str(text)?;
         let mut patterns = Vec::with_capacity(file.pattern.len());
+        let mut tests = Vec::new();
         for def in file.pattern {
+            for test in def.test {
+                tests.push(test.into_test(&def.id).map_err(|e| Error::Predicate {
+                    id: def.id.clone(),
+                    source: e,
+                })?);
+            }
             let when = Predicate::parse(&def.when).map_err(|e| Error::Predicate {
                 id: def.id.clone(),
                 source: e,
@@ -92,26 +161,59 @@ impl PatternEngine {
                 severity,
                 summary: def.summary,
                 suggest: def.suggest,
+                inhibits: def.inhibits,
             });
         }
-        Ok(Self { patterns })
+        Ok(Self { patterns, tests })
     }
 
     /// Run all patterns against the given signals; return findings that fired.
+    /// `finding.*` paths see no findings.
     pub fn run(&self, signals: &[Signal], ctx: &CollectCtx) -> Vec<Finding> {
-        let idx = SignalIndex::build(signals);
+        self.run_with_findings(signals, &[], ctx)
+    }
+
+    /// Patterns that did not fire but came close; see
+    /// `rule::near_miss`. `rule_findings` are visible to `finding.*` paths,
+    /// as in `run_with_findings`.
+    pub fn near_misses(
+        &self,
+        signals: &[Signal],
+        rule_findings: &[Finding],
+        ctx: &CollectCtx,
+        margin: f64,
+    ) -> Vec<NearMiss> {
+        let idx = SignalIndex::build(signals).with_findings(rule_findings);
+        self.patterns
+            .iter()
+            .filter_map(|p| near_miss(&p.id, FindingKind::Pattern, p.severity, &p.when, &idx, ctx, margin))
+            .collect()
+    }
+
+    /// Like `run`, with `findings` (normally the rule pass's) visible to
+    /// `finding.*` paths.
+    pub fn run_with_findings(&self, signals: &[Signal], rule_findings: &[Finding], ctx: &CollectCtx) -> Vec<Finding> {
+        let idx = SignalIndex::build(signals).with_findings(rule_findings);
         let mut findings = Vec::new();
         for pattern in &self.patterns {
             if pattern.when.evaluate(&idx, ctx) {
-                let referenced: std::collections::HashSet<String> = pattern.when.signal_ids().into_iter().collect();
-                let evidence = collect_evidence(signals, &referenced, ctx);
+                let matches = pattern.when.matched_signals(&idx, ctx);
+                let referenced: std::collections::HashSet<String> = pattern
+                    .when
+                    .signal_ids()
+                    .into_iter()
+                    .chain(matches.iter().map(|m| m.id.clone()))
+                    .collect();
+                let mut evidence = collect_evidence(signals, &referenced, &matches, ctx);
+                evidence.extend(finding_evidence(rule_findings, &referenced, &idx));
+                let vars = TemplateContext::new(&idx, ctx, &evidence, &matches);
                 findings.push(Finding {
                     id: pattern.id.clone(),
                     kind: FindingKind::Pattern,
                     severity: pattern.severity,
-                    summary: pattern.summary.clone(),
+                    summary: vars.render(&pattern.summary),
                     evidence,
-                    suggest: pattern.suggest.clone(),
+                    suggest: pattern.suggest.iter().map(|s| vars.render(s)).collect(),
                 });
             }
         }
@@ -119,9 +221,108 @@ impl PatternEngine {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct PatternsLoader {
+    builtins: PatternEngine,
+    user_dir: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub struct PatternsLoadResult {
+    pub engine: PatternEngine,
+    pub load_findings: Vec<Finding>,
+    /// Where each pattern id was last defined.
+    pub sources: HashMap<String, RuleSource>,
+}
+
+impl PatternsLoader {
+    pub fn new() -> Self {
+        PatternsLoader::default()
+    }
+
+    pub fn with_builtins(mut self, engine: PatternEngine) -> Self {
+        self.builtins = engine;
+        self
+    }
+
+    pub fn with_user_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.user_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Load the built-in patterns, then the `*.toml` files of the user dir
+    /// (if any) in path order. A user pattern whose id is already loaded
+    /// replaces the earlier definition in place. As for rules, a malformed
+    /// or unreadable file becomes a `warn` finding and is skipped.
+    pub fn load(self) -> PatternsLoadResult {
+        let mut engine = self.builtins;
+        let mut sources: HashMap<String, RuleSource> = engine
+            .patterns
+            .iter()
+            .map(|p| (p.id.clone(), RuleSource::Builtin))
+            .collect();
+        let mut load_findings = Vec::new();
+        if let Some(dir) = self.user_dir {
+            load_findings = load_user_files(&dir, FindingKind::Pattern, PatternEngine::from_toml, |loaded, path| {
+                for pattern in &loaded.patterns {
+                    let overrides_builtin = matches!(
+                        sources.get(&pattern.id),
+                        Some(RuleSource::Builtin)
+                            | Some(RuleSource::User {
+                                overrides_builtin: true,
+                                ..
+                            })
+                    );
+                    sources.insert(
+                        pattern.id.clone(),
+                        RuleSource::User {
+                            path: path.to_path_buf(),
+                            overrides_builtin,
+                        },
+                    );
+                }
+                engine.override_from(loaded);
+            });
+        }
+        PatternsLoadResult {
+            engine,
+            load_findings,
+            sources,
+        }
+    }
+}
+
+/// One evidence entry per fired finding a predicate references, in the order
+/// the findings were produced.
+fn finding_evidence(
+    findings: &[Finding],
+    referenced: &std::collections::HashSet<String>,
+    idx: &SignalIndex<'_>,
+) -> Vec<Evidence> {
+    let mut seen = std::collections::HashSet::new();
+    findings
+        .iter()
+        .filter(|f| {
+            let path = format!("finding.{}", f.id);
+            (referenced.contains(&path) || referenced.contains(&format!("{path}.severity")))
+                && seen.insert(f.id.as_str())
+        })
+        .filter_map(|f| {
+            let severity = idx.finding(&f.id)?;
+            Some(Evidence {
+                signal_id: format!("finding.{}", f.id),
+                observed: SignalValue::Text(severity_name(severity).to_string()),
+                source_commands: Vec::new(),
+                detail: Some(f.summary.clone()),
+            })
+        })
+        .collect()
+}
+
 fn collect_evidence(
     signals: &[Signal],
     referenced: &std::collections::HashSet<String>,
+    matches: &[SignalMatch],
     ctx: &CollectCtx,
 ) -> Vec<Evidence> {
     let mut evidence: Vec<Evidence> = signals
@@ -131,23 +332,19 @@ fn collect_evidence(
             signal_id: s.id.clone(),
             observed: s.value.clone(),
             source_commands: Vec::new(),
+            detail: matches.iter().find(|m| m.id == s.id).and_then(|m| m.detail.clone()),
         })
         .collect();
 
     // Include host.* signals that come from CollectCtx rather than the signal slice.
     for id in referenced {
-        if id.starts_with("host.") {
-            let observed = match id.as_str() {
-                "host.cpu_count" => Some(SignalValue::I64(ctx.cpu_count as i64)),
-                _ => None,
-            };
-            if let Some(observed) = observed {
-                evidence.push(Evidence {
-                    signal_id: id.clone(),
-                    observed,
-                    source_commands: Vec::new(),
-                });
-            }
+        if let Some(observed) = ctx.host_value(id) {
+            evidence.push(Evidence {
+                signal_id: id.clone(),
+                observed,
+                source_commands: Vec::new(),
+                detail: None,
+            });
         }
     }
 
@@ -166,6 +363,47 @@ mod tests {
         assert!(other.patterns.is_empty(), "merging two empty engines must stay empty");
     }
 
+    #[test]
+    fn loader_overrides_by_id_and_reports_malformed_files() {
+        let builtin = r#"
+[[pattern]]
+id = "p.keep"
+when = "a > 1"
+severity = "info"
+summary = "keep"
+
+[[pattern]]
+id = "p.replace"
+when = "a > 1"
+severity = "info"
+summary = "built-in"
+"#;
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("site.toml"),
+            "[[pattern]]\nid = \"p.replace\"\nwhen = \"a > 2\"\nseverity = \"crit\"\nsummary = \"site\"\n\n\
+             [[pattern]]\nid = \"p.new\"\nwhen = \"a > 3\"\nseverity = \"warn\"\nsummary = \"new\"\n",
+        )
+        .unwrap();
+        std::fs::write(tmp.path().join("broken.toml"), "[[pattern]\n").unwrap();
+        let result = PatternsLoader::new()
+            .with_builtins(PatternEngine::from_toml(builtin).unwrap())
+            .with_user_dir(tmp.path())
+            .load();
+        let ids: Vec<&str> = result.engine.patterns().iter().map(|p| p.id.as_str()).collect();
+        assert_eq!(ids, vec!["p.keep", "p.replace", "p.new"]);
+        assert_eq!(result.engine.patterns()[1].severity, Severity::Crit);
+        assert!(matches!(
+            result.sources["p.replace"],
+            RuleSource::User {
+                overrides_builtin: true,
+                ..
+            }
+        ));
+        assert_eq!(result.load_findings.len(), 1);
+        assert_eq!(result.load_findings[0].id, "patterns.malformed_user_file");
+    }
+
     #[test]
     fn extend_from_merges_patterns() {
         let toml = r#"
@@ -189,6 +427,7 @@ summary = "test pattern"
             samples: None,
             stats: None,
             baseline: None,
+            labels: crate::signal::Labels::new(),
         }];
         let ctx = crate::collector::CollectCtx::default();
         let findings = merged.run(&signals, &ctx);
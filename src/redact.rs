@@ -95,6 +95,12 @@ impl Redactor {
             }
         }
 
+        for miss in &mut output.near_misses {
+            for term in &mut miss.failing {
+                term.detail = term.detail.as_deref().map(|d| self.redact_text(d));
+            }
+        }
+
         for signal in &mut output.signals {
             if let SignalValue::Text(ref s) = signal.value {
                 signal.value = SignalValue::Text(self.redact_text(s));
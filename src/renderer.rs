@@ -90,8 +90,12 @@ pub mod json {
 pub mod jinja {
     use super::*;
 
+    use minijinja::Value;
+    use minijinja::value::ViaDeserialize;
     use std::{fs::File, io::Read, path::Path};
 
+    use crate::signal::{self, Signal, Unit};
+
     #[derive(Default, Debug, Eq, PartialEq, Clone)]
     pub struct TemplateRenderer {
         template: String,
@@ -137,6 +141,20 @@ pub mod jinja {
             .unwrap_or(s)
     }
 
+    /// `signals | label_tables`: the per-device tables of `signal::label_tables`.
+    fn label_tables(signals: ViaDeserialize<Vec<Signal>>) -> Value {
+        Value::from_serialize(signal::label_tables(&signals))
+    }
+
+    /// `value | human(unit)`: a number in `unit` as `signal::humanize` formats
+    /// it, e.g. `1.5 GiB`. Anything else is passed through.
+    fn human(value: Value, unit: ViaDeserialize<Unit>) -> Value {
+        match f64::try_from(value.clone()) {
+            Ok(n) if value.is_number() => Value::from(signal::humanize(n, *unit)),
+            _ => value,
+        }
+    }
+
     impl<W: Write> Renderer<W> for TemplateRenderer {
         fn render(&self, report: &AnalysisReport, mut w: W) -> Result<()> {
             let mut env = minijinja::Environment::new();
@@ -146,6 +164,8 @@ pub mod jinja {
                 env.set_auto_escape_callback(|_| minijinja::AutoEscape::None);
             }
             env.add_filter("rfc2822", rfc2822);
+            env.add_filter("label_tables", label_tables);
+            env.add_filter("human", human);
             let output = env.render_str(&self.template, report)?;
             w.write_all(output.as_bytes())
                 .map_err(|e| Error::WriteOutputFailed { source: e })?;
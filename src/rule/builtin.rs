@@ -15,18 +15,20 @@ const DMESG_RULES: &str = include_str!("../../contrib/rules/dmesg.toml");
 #[cfg(feature = "bpf")]
 const BPF_RULES: &str = include_str!("../../contrib/rules/bpf.toml");
 
+const RULE_FILES: [(&str, &str); 5] = [
+    ("cpu.toml", CPU_RULES),
+    ("memory.toml", MEMORY_RULES),
+    ("disk.toml", DISK_RULES),
+    ("network.toml", NETWORK_RULES),
+    ("dmesg.toml", DMESG_RULES),
+];
+
 /// Returns the bundled built-in rule set. Panics if a bundled TOML file fails
 /// to parse — this is a build-time invariant, not a runtime concern, and a
 /// panic here is preferable to silently dropping rules.
 pub fn builtin_rules() -> Vec<Rule> {
     let mut rules = Vec::new();
-    for (name, src) in [
-        ("cpu.toml", CPU_RULES),
-        ("memory.toml", MEMORY_RULES),
-        ("disk.toml", DISK_RULES),
-        ("network.toml", NETWORK_RULES),
-        ("dmesg.toml", DMESG_RULES),
-    ] {
+    for (name, src) in RULE_FILES {
         match parse_rules_toml(src) {
             Ok(mut more) => rules.append(&mut more),
             Err(e) => panic!("built-in rule file {} failed to parse: {}", name, e),
@@ -44,6 +46,16 @@ pub fn bpf_rules() -> Vec<Rule> {
     }
 }
 
+/// The bundled rule files as `(file name, TOML source)`, including
+/// `bpf.toml` when the `bpf` feature is enabled.
+pub fn builtin_rule_files() -> Vec<(&'static str, &'static str)> {
+    #[allow(unused_mut)]
+    let mut files = RULE_FILES.to_vec();
+    #[cfg(feature = "bpf")]
+    files.push(("bpf.toml", BPF_RULES));
+    files
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,215 @@
+//! Embedded rule and pattern tests (`[[rule.test]]`, `[[pattern.test]]`).
+//!
+//! A test pins a fixture signal map to the outcome its rule or pattern should
+//! produce:
+//!
+//! ```toml
+//! [[rule.test]]
+//! name     = "fires above 1%"
+//! signals  = { "net.retrans_pct" = 2.5 }
+//! fires    = true
+//! severity = "warn"
+//! ```
+//!
+//! Numbers, bools and strings become single-value signals; an array of
+//! numbers becomes a sampled signal for `sustained(...)` and the `.p95`-style
+//! suffixes. `host.cpu_count` and `host.uname` set the `CollectCtx` fields the
+//! `host.*` paths resolve from. `severity` is optional and only checked when
+//! the test expects the rule to fire.
+//!
+//! A `[[pattern.test]]` can also list the rule findings its `finding.*` paths
+//! see, by ID and severity: `findings = { "cpu.iowait_elevated" = "warn" }`.
+
+use serde::Deserialize;
+
+use super::{Error, Result, parse_severity};
+use crate::collector::CollectCtx;
+use crate::finding::{Finding, FindingKind, Severity};
+use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+/// One embedded test of a rule or pattern.
+#[derive(Debug, Clone)]
+pub struct RuleTest {
+    /// ID of the rule or pattern under test.
+    pub id: String,
+    pub name: Option<String>,
+    pub signals: Vec<Signal>,
+    pub ctx: CollectCtx,
+    pub fires: bool,
+    pub severity: Option<Severity>,
+    /// Rule findings visible to `finding.*` paths.
+    pub findings: Vec<Finding>,
+}
+
+impl RuleTest {
+    /// Compare the severity the rule or pattern fired at (`None` when it did
+    /// not fire) with the expectation; `Err` describes the mismatch.
+    pub fn check(&self, fired: Option<Severity>) -> std::result::Result<(), String> {
+        match (self.fires, fired, self.severity) {
+            (true, None, _) => Err("expected to fire, did not fire".to_string()),
+            (false, Some(got), _) => Err(format!("expected not to fire, fired at {}", got)),
+            (true, Some(got), Some(want)) if got != want => Err(format!("expected {}, fired at {}", want, got)),
+            _ => Ok(()),
+        }
+    }
+
+    /// `name`, or the test's position within its rule when unnamed.
+    pub fn label(&self, index: usize) -> String {
+        self.name.clone().unwrap_or_else(|| format!("test #{}", index + 1))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct TestToml {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    signals: toml::Table,
+    fires: bool,
+    #[serde(default)]
+    severity: Option<String>,
+    #[serde(default)]
+    findings: toml::Table,
+}
+
+impl TestToml {
+    pub(crate) fn into_test(self, id: &str) -> Result<RuleTest> {
+        let err = |e: String| Error::Predicate(format!("test of '{}': {}", id, e));
+        let mut ctx = CollectCtx::default();
+        let mut signals = Vec::new();
+        for (signal_id, value) in self.signals {
+            match (signal_id.as_str(), value) {
+                ("host.cpu_count", toml::Value::Integer(n)) if n >= 0 => ctx.cpu_count = n as usize,
+                ("host.uname", toml::Value::String(s)) => ctx.uname = Some(s),
+                (_, value) => signals.push(fixture_signal(signal_id, value).map_err(err)?),
+            }
+        }
+        let mut findings = Vec::new();
+        for (finding_id, value) in self.findings {
+            let severity = value
+                .as_str()
+                .ok_or_else(|| format!("finding '{}': severity must be a string", finding_id))
+                .and_then(parse_severity)
+                .map_err(err)?;
+            findings.push(Finding {
+                id: finding_id,
+                kind: FindingKind::Rule,
+                severity,
+                summary: String::new(),
+                evidence: Vec::new(),
+                suggest: Vec::new(),
+            });
+        }
+        Ok(RuleTest {
+            id: id.to_string(),
+            name: self.name,
+            signals,
+            ctx,
+            fires: self.fires,
+            severity: self.severity.as_deref().map(parse_severity).transpose().map_err(err)?,
+            findings,
+        })
+    }
+}
+
+fn fixture_signal(id: String, value: toml::Value) -> std::result::Result<Signal, String> {
+    let (value, samples) = match value {
+        toml::Value::Integer(n) => (SignalValue::I64(n), None),
+        toml::Value::Float(f) => (SignalValue::F64(f), None),
+        toml::Value::Boolean(b) => (SignalValue::Bool(b), None),
+        toml::Value::String(s) => (SignalValue::Text(s), None),
+        toml::Value::Array(items) => {
+            let samples = items
+                .iter()
+                .map(|v| match v {
+                    toml::Value::Integer(n) => Some(*n as f64),
+                    toml::Value::Float(f) => Some(*f),
+                    _ => None,
+                })
+                .collect::<Option<Vec<f64>>>()
+                .filter(|s| !s.is_empty())
+                .ok_or_else(|| format!("signal '{}': samples must be a non-empty array of numbers", id))?;
+            (SignalValue::F64(*samples.last().expect("non-empty")), Some(samples))
+        }
+        other => return Err(format!("signal '{}': unsupported value {}", id, other)),
+    };
+    Ok(Signal {
+        id,
+        value,
+        unit: Unit::None,
+        at: chrono::Local::now(),
+        samples,
+        stats: None,
+        baseline: None,
+        labels: Labels::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::rule::{RuleEngine, parse_rules_with_tests};
+
+    const SUSTAINED: &str = r#"
+[[rule]]
+id = "cpu.iowait_sustained"
+when = "sustained(cpu.iowait_pct > 30, 3)"
+severity = "warn"
+summary = ""
+
+[[rule.test]]
+signals = { "cpu.iowait_pct" = [40, 45, 10, 50], "host.uname" = "Linux h 6.8.0 #1 x86_64" }
+fires = true
+severity = "crit"
+
+[[rule.test]]
+name = "one spike"
+signals = { "cpu.iowait_pct" = [40, 5, 10, 5] }
+fires = false
+"#;
+
+    #[test]
+    fn parses_fixtures_into_signals_and_ctx() {
+        let (_, tests) = parse_rules_with_tests(SUSTAINED).expect("parse");
+        assert_eq!(tests.len(), 2);
+        assert_eq!(tests[0].id, "cpu.iowait_sustained");
+        assert_eq!(tests[0].label(0), "test #1");
+        assert_eq!(tests[1].label(1), "one spike");
+        assert_eq!(
+            tests[0].signals[0].samples.as_deref(),
+            Some(&[40.0, 45.0, 10.0, 50.0][..])
+        );
+        assert_eq!(
+            tests[0].ctx.host_value("host.kernel"),
+            Some(SignalValue::Text("6.8.0".into()))
+        );
+    }
+
+    #[test]
+    fn check_reports_fire_and_severity_mismatches() {
+        let (rules, tests) = parse_rules_with_tests(SUSTAINED).expect("parse");
+        let engine = RuleEngine::new(rules);
+        let outcomes: Vec<_> = tests
+            .iter()
+            .map(|t| {
+                let (findings, _) = engine.run(&t.signals, &t.ctx, &HashMap::new());
+                t.check(findings.first().map(|f| f.severity))
+            })
+            .collect();
+        assert_eq!(outcomes[0], Err("expected CRIT, fired at WARN".to_string()));
+        assert_eq!(outcomes[1], Ok(()));
+        assert_eq!(
+            tests[1].check(Some(Severity::Info)),
+            Err("expected not to fire, fired at INFO".to_string())
+        );
+    }
+
+    #[test]
+    fn non_numeric_samples_rejected() {
+        let toml = "[[rule]]\nid = \"x\"\nwhen = \"a > 1\"\nseverity = \"warn\"\nsummary = \"\"\n\n[[rule.test]]\nsignals = { a = [1, \"two\"] }\nfires = true\n";
+        let err = parse_rules_with_tests(toml).unwrap_err();
+        assert!(err.to_string().contains("test of 'x': signal 'a'"), "{err}");
+    }
+}
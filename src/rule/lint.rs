@@ -0,0 +1,757 @@
+//! Static checks over rule and pattern definitions (`usereport rules lint`).
+//!
+//! A `Linter` is told which signals exist and what kind of value each holds,
+//! then fed rule sets and patterns. It reports references to signals nobody
+//! emits, comparisons that can never hold because the two sides have
+//! different kinds or units, tiers of multi-level rules that can never win,
+//! IDs defined twice, and `summary`/`suggest` text that looks like a
+//! template but does not compile, so it is shown verbatim. Signal IDs may be
+//! declared as globs (`disk.*.util_pct`) to cover per-device families. In
+//! patterns, `finding.<id>` paths must name a rule or pattern checked
+//! earlier; in rules they are reported, since rules run before any finding
+//! exists.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::quantity::Quantity;
+use super::{Expr, Op, Predicate, Rhs, Rule, Value, glob_matches, is_glob, op_to_str, severity_name};
+use crate::finding::Severity;
+use crate::signal::Unit;
+use crate::signal::catalog::{self, SignalKind};
+
+/// What kind of value a signal holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Number,
+    Bool,
+    Text,
+    Severity,
+}
+
+impl From<SignalKind> for ValueKind {
+    fn from(kind: SignalKind) -> Self {
+        match kind {
+            SignalKind::Counter | SignalKind::Gauge | SignalKind::Rate => ValueKind::Number,
+            SignalKind::Flag => ValueKind::Bool,
+            SignalKind::Text => ValueKind::Text,
+        }
+    }
+}
+
+impl fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ValueKind::Number => "a number",
+            ValueKind::Bool => "a bool",
+            ValueKind::Text => "text",
+            ValueKind::Severity => "a severity",
+        })
+    }
+}
+
+/// Which set of definitions an ID belongs to. User rules replace same-ID
+/// rules from other scopes, user patterns replace same-ID built-in patterns,
+/// and only one workload pack is active at a time, so none of these counts
+/// as a duplicate; everything else does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Scope {
+    Builtin,
+    Workload(String),
+    User,
+    Pattern,
+    UserPattern,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    Parse,
+    UnknownSignal,
+    ImpossibleComparison,
+    UnreachableTier,
+    DuplicateId,
+    Template,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintIssue {
+    /// File or pack the definition came from.
+    pub origin: String,
+    /// Rule or pattern ID; `None` for file-level problems.
+    pub id: Option<String>,
+    pub kind: LintKind,
+    pub message: String,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.id {
+            Some(id) => write!(f, "{}: {}: {}", self.origin, id, self.message),
+            None => write!(f, "{}: {}", self.origin, self.message),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Linter {
+    signals: HashMap<String, ValueKind>,
+    /// Units of the signals declared with one.
+    units: HashMap<String, Unit>,
+    ids: HashMap<String, Vec<(Scope, String)>>,
+    /// Set while checking a pattern, the only place `finding.*` resolves.
+    in_pattern: bool,
+    checked: usize,
+    issues: Vec<LintIssue>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Linter {
+    /// A linter that knows the `host.*` context paths and nothing else.
+    pub fn new() -> Self {
+        let mut linter = Linter {
+            signals: HashMap::new(),
+            units: HashMap::new(),
+            ids: HashMap::new(),
+            in_pattern: false,
+            checked: 0,
+            issues: Vec::new(),
+        };
+        linter.declare("host.cpu_count", ValueKind::Number);
+        for id in ["host.os", "host.kernel", "host.arch", "host.uname"] {
+            linter.declare(id, ValueKind::Text);
+        }
+        linter
+    }
+
+    /// Declare a signal ID, or a glob covering a family of IDs.
+    pub fn declare(&mut self, id: &str, kind: ValueKind) {
+        self.signals.insert(id.to_string(), kind);
+    }
+
+    /// Declare a signal, or a family of signals, measured in `unit`.
+    /// Unit-suffixed literals compared with it must measure the same
+    /// dimension.
+    pub fn declare_with_unit(&mut self, id: &str, kind: ValueKind, unit: Unit) {
+        self.declare(id, kind);
+        self.units.insert(id.to_string(), unit);
+    }
+
+    /// Declare every signal of the built-in collectors, from
+    /// `signal::catalog`.
+    pub fn declare_catalog(&mut self) {
+        for spec in catalog::builtin() {
+            self.declare_with_unit(spec.id, spec.kind.into(), spec.unit);
+        }
+    }
+
+    /// Record a file that failed to parse.
+    pub fn parse_error(&mut self, origin: &str, message: impl Into<String>) {
+        self.push(origin, None, LintKind::Parse, message.into());
+    }
+
+    pub fn check_rules(&mut self, origin: &str, scope: Scope, rules: &[Rule]) {
+        for rule in rules {
+            self.checked += 1;
+            self.check_id(origin, &scope, &rule.id);
+            for (when, _) in rule.tiers() {
+                self.check_predicate(origin, &rule.id, when);
+            }
+            for id in &rule.evidence_ids {
+                self.kinds_or_report(origin, &rule.id, id);
+            }
+            self.check_tiers(origin, rule);
+            let texts = std::iter::once(&rule.summary).chain(&rule.suggest);
+            self.check_templates(origin, &rule.id, texts.map(String::as_str));
+        }
+    }
+
+    /// Report `summary` and `suggest` strings of `id` that do not compile as
+    /// templates.
+    pub fn check_templates<'t>(&mut self, origin: &str, id: &str, texts: impl IntoIterator<Item = &'t str>) {
+        for text in texts {
+            if let Err(e) = super::template::check(text) {
+                let message = format!(
+                    "{}; it is shown verbatim, wrap literal braces in {{% raw %}}…{{% endraw %}}",
+                    e
+                );
+                self.push(origin, Some(id), LintKind::Template, message);
+            }
+        }
+    }
+
+    /// `scope` is `Scope::Pattern` or `Scope::UserPattern`.
+    pub fn check_pattern(&mut self, origin: &str, scope: Scope, id: &str, when: &Predicate) {
+        self.checked += 1;
+        self.check_id(origin, &scope, id);
+        self.in_pattern = true;
+        self.check_predicate(origin, id, when);
+        self.in_pattern = false;
+    }
+
+    /// Number of rules and patterns checked so far.
+    pub fn checked(&self) -> usize {
+        self.checked
+    }
+
+    pub fn finish(self) -> Vec<LintIssue> {
+        self.issues
+    }
+
+    fn push(&mut self, origin: &str, id: Option<&str>, kind: LintKind, message: String) {
+        let issue = LintIssue {
+            origin: origin.to_string(),
+            id: id.map(str::to_string),
+            kind,
+            message,
+        };
+        if !self.issues.contains(&issue) {
+            self.issues.push(issue);
+        }
+    }
+
+    fn check_id(&mut self, origin: &str, scope: &Scope, id: &str) {
+        let seen = self.ids.entry(id.to_string()).or_default();
+        let clash = seen.iter().find(|(s, _)| conflicts(s, scope)).map(|(_, o)| o.clone());
+        seen.push((scope.clone(), origin.to_string()));
+        if let Some(seen_origin) = clash {
+            let message = format!("duplicate ID, already defined in {}", seen_origin);
+            self.push(origin, Some(id), LintKind::DuplicateId, message);
+        }
+    }
+
+    /// Kinds of the signals `id` may refer to, or `None` (after reporting it)
+    /// when no declared signal matches.
+    fn kinds_or_report(&mut self, origin: &str, rule_id: &str, id: &str) -> Option<Vec<ValueKind>> {
+        if let Some(finding) = id.strip_prefix("finding.") {
+            return self.finding_kinds_or_report(origin, rule_id, finding);
+        }
+        let kinds = self.kinds(id);
+        if kinds.is_none() {
+            let message = format!("unknown signal `{}`: no collector or [[command.extract]] emits it", id);
+            self.push(origin, Some(rule_id), LintKind::UnknownSignal, message);
+        }
+        kinds
+    }
+
+    /// Kinds of a `finding.<path>` reference: a bool for `finding.<id>`, a
+    /// severity for `finding.<id>.severity`.
+    fn finding_kinds_or_report(&mut self, origin: &str, rule_id: &str, path: &str) -> Option<Vec<ValueKind>> {
+        if !self.in_pattern {
+            let message = format!(
+                "`finding.{}` is always absent in a rule: rules run before any finding exists",
+                path
+            );
+            self.push(origin, Some(rule_id), LintKind::UnknownSignal, message);
+            return None;
+        }
+        let (id, kind) = match path.strip_suffix(".severity") {
+            Some(id) if self.ids.contains_key(id) => (id, ValueKind::Severity),
+            _ => (path, ValueKind::Bool),
+        };
+        if !self.ids.contains_key(id) {
+            let name = path.strip_suffix(".severity").unwrap_or(path);
+            let message = format!("unknown finding `{}`: no rule or pattern defines it", name);
+            self.push(origin, Some(rule_id), LintKind::UnknownSignal, message);
+            return None;
+        }
+        Some(vec![kind])
+    }
+
+    fn kinds(&self, id: &str) -> Option<Vec<ValueKind>> {
+        let path: Vec<String> = id.split('.').map(str::to_string).collect();
+        let mut kinds: Vec<ValueKind> = Vec::new();
+        for (declared, kind) in &self.signals {
+            let declared_path: Vec<String> = declared.split('.').map(str::to_string).collect();
+            let hit = declared == id
+                || (is_glob(id) && glob_matches(&path, declared))
+                || (is_glob(declared) && glob_matches(&declared_path, id));
+            if hit && !kinds.contains(kind) {
+                kinds.push(*kind);
+            }
+        }
+        if !kinds.is_empty() {
+            return Some(kinds);
+        }
+        // SampleStats and BaselineStats suffixes, as resolved by `resolve_path`.
+        let (base, suffix) = id.rsplit_once('.')?;
+        if let Some(signal) = base.strip_suffix(".baseline") {
+            if matches!(suffix, "p50" | "p95" | "mad" | "z") {
+                return self.kinds(signal).map(|_| vec![ValueKind::Number]);
+            }
+        }
+        match suffix {
+            "p50" | "p95" | "p99" | "min" | "max" | "mean" | "stddev" | "cv" | "slope" | "count" => {
+                self.kinds(base).map(|_| vec![ValueKind::Number])
+            }
+            "trend" => self.kinds(base).map(|_| vec![ValueKind::Text]),
+            _ => None,
+        }
+    }
+
+    /// Unit of the signals `id` refers to, when they were declared with
+    /// one. Statistics suffixes keep the signal's unit, except the unitless
+    /// `.cv`, `.count` and `.baseline.z`.
+    fn unit(&self, id: &str) -> Option<Unit> {
+        let path: Vec<String> = id.split('.').map(str::to_string).collect();
+        let declared = self.units.iter().find(|(declared, _)| {
+            let declared_path: Vec<String> = declared.split('.').map(str::to_string).collect();
+            *declared == id
+                || (is_glob(id) && glob_matches(&path, declared))
+                || (is_glob(declared) && glob_matches(&declared_path, id))
+        });
+        if let Some((_, unit)) = declared {
+            return Some(*unit);
+        }
+        let (base, suffix) = id.rsplit_once('.')?;
+        if let Some(signal) = base.strip_suffix(".baseline") {
+            return match suffix {
+                "z" => self.unit(signal).map(|_| Unit::None),
+                "p50" | "p95" | "mad" => self.unit(signal),
+                _ => None,
+            };
+        }
+        match suffix {
+            "cv" | "count" => self.unit(base).map(|_| Unit::None),
+            "p50" | "p95" | "p99" | "min" | "max" | "mean" | "stddev" | "slope" => self.unit(base),
+            _ => None,
+        }
+    }
+
+    /// A unit-suffixed literal converts to nothing, so the comparison never
+    /// holds, when the signal's unit measures another dimension.
+    fn check_quantity(&mut self, origin: &str, rule_id: &str, lhs_id: &str, op: Op, q: &Quantity) {
+        let Some(unit) = self.unit(lhs_id) else {
+            return;
+        };
+        if q.convert(unit).is_some() {
+            return;
+        }
+        let measured = match unit {
+            Unit::None => "is unitless".to_string(),
+            unit => format!("is in {}", unit),
+        };
+        let message = format!(
+            "`{} {} {}` can never hold: `{}` {}, {} is {}",
+            lhs_id,
+            op_to_str(op),
+            q,
+            lhs_id,
+            measured,
+            q,
+            q.dimension
+        );
+        self.push(origin, Some(rule_id), LintKind::ImpossibleComparison, message);
+    }
+
+    fn check_predicate(&mut self, origin: &str, rule_id: &str, pred: &Predicate) {
+        match pred {
+            Predicate::Cmp { path, op, rhs } => {
+                let lhs_id = path.join(".");
+                let lhs = self.kinds_or_report(origin, rule_id, &lhs_id);
+                let rhs_kinds = self.rhs_kinds(origin, rule_id, rhs);
+                self.check_comparison(origin, rule_id, &lhs_id, lhs, *op, rhs, rhs_kinds);
+                if let Rhs::Value(Value::Quantity(q)) = rhs {
+                    self.check_quantity(origin, rule_id, &lhs_id, *op, q);
+                }
+            }
+            Predicate::Quantified { glob, op, rhs, .. } => {
+                let lhs_id = glob.join(".");
+                let lhs = self.kinds_or_report(origin, rule_id, &lhs_id);
+                let rhs_kinds = self.rhs_kinds(origin, rule_id, rhs);
+                self.check_comparison(origin, rule_id, &lhs_id, lhs, *op, rhs, rhs_kinds);
+                if let Rhs::Value(Value::Quantity(q)) = rhs {
+                    self.check_quantity(origin, rule_id, &lhs_id, *op, q);
+                }
+            }
+            Predicate::Arith { lhs, rhs, .. } => {
+                self.check_expr(origin, rule_id, lhs);
+                self.check_expr(origin, rule_id, rhs);
+            }
+            Predicate::Text { path, .. } => {
+                let id = path.join(".");
+                if let Some(kinds) = self.kinds_or_report(origin, rule_id, &id) {
+                    if !kinds.contains(&ValueKind::Text) {
+                        let message = format!("text test on `{}`, which is {}", id, kinds[0]);
+                        self.push(origin, Some(rule_id), LintKind::ImpossibleComparison, message);
+                    }
+                }
+            }
+            Predicate::And(a, b) | Predicate::Or(a, b) => {
+                self.check_predicate(origin, rule_id, a);
+                self.check_predicate(origin, rule_id, b);
+            }
+            Predicate::Not(a) => self.check_predicate(origin, rule_id, a),
+            Predicate::Sustained { inner, .. } => self.check_predicate(origin, rule_id, inner),
+            Predicate::Temporal { first, second, .. } => {
+                self.check_predicate(origin, rule_id, first);
+                self.check_predicate(origin, rule_id, second);
+            }
+        }
+    }
+
+    fn rhs_kinds(&mut self, origin: &str, rule_id: &str, rhs: &Rhs) -> Option<Vec<ValueKind>> {
+        match rhs {
+            Rhs::Value(Value::Number(_) | Value::Quantity(_)) => Some(vec![ValueKind::Number]),
+            Rhs::Value(Value::Bool(_)) => Some(vec![ValueKind::Bool]),
+            Rhs::Value(Value::Str(_)) => Some(vec![ValueKind::Text]),
+            Rhs::Value(Value::Severity(_)) => Some(vec![ValueKind::Severity]),
+            Rhs::Path(p) => self.kinds_or_report(origin, rule_id, &p.join(".")),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_comparison(
+        &mut self,
+        origin: &str,
+        rule_id: &str,
+        lhs_id: &str,
+        lhs: Option<Vec<ValueKind>>,
+        op: Op,
+        rhs: &Rhs,
+        rhs_kinds: Option<Vec<ValueKind>>,
+    ) {
+        // Unknown sides were reported already.
+        let (Some(lhs), Some(rhs_kinds)) = (lhs, rhs_kinds) else {
+            return;
+        };
+        let ordering = !matches!(op, Op::Eq | Op::Ne);
+        let possible = lhs
+            .iter()
+            .any(|l| rhs_kinds.contains(l) && (matches!(l, ValueKind::Number | ValueKind::Severity) || !ordering));
+        if possible {
+            return;
+        }
+        let rhs_str = match rhs {
+            Rhs::Value(Value::Number(n)) => n.to_string(),
+            Rhs::Value(Value::Bool(b)) => b.to_string(),
+            Rhs::Value(Value::Str(s)) => format!("{:?}", s),
+            Rhs::Value(Value::Severity(s)) => severity_name(*s).to_string(),
+            Rhs::Value(Value::Quantity(q)) => q.to_string(),
+            Rhs::Path(p) => p.join("."),
+        };
+        let message = if lhs[0] == rhs_kinds[0] {
+            format!(
+                "`{} {} {}` can never hold: {} cannot be ordered",
+                lhs_id,
+                op_to_str(op),
+                rhs_str,
+                lhs[0]
+            )
+        } else {
+            format!(
+                "`{} {} {}` can never hold: it compares {} with {}",
+                lhs_id,
+                op_to_str(op),
+                rhs_str,
+                lhs[0],
+                rhs_kinds[0]
+            )
+        };
+        self.push(origin, Some(rule_id), LintKind::ImpossibleComparison, message);
+    }
+
+    fn check_expr(&mut self, origin: &str, rule_id: &str, expr: &Expr) {
+        match expr {
+            Expr::Number(_) => {}
+            Expr::Binary { lhs, rhs, .. } => {
+                self.check_expr(origin, rule_id, lhs);
+                self.check_expr(origin, rule_id, rhs);
+            }
+            Expr::Path(p) | Expr::Aggregate { glob: p, .. } | Expr::Delta(p) => {
+                let id = p.join(".");
+                let counted = matches!(
+                    expr,
+                    Expr::Aggregate {
+                        func: super::AggFunc::Count,
+                        ..
+                    }
+                );
+                if let Some(kinds) = self.kinds_or_report(origin, rule_id, &id) {
+                    if !counted && !kinds.contains(&ValueKind::Number) {
+                        let message = format!("arithmetic on `{}`, which is {}", id, kinds[0]);
+                        self.push(origin, Some(rule_id), LintKind::ImpossibleComparison, message);
+                    }
+                }
+            }
+        }
+    }
+
+    /// A tier can never produce the finding when a tier that beats it (more
+    /// severe, or as severe and earlier) holds whenever it does.
+    fn check_tiers(&mut self, origin: &str, rule: &Rule) {
+        let tiers: Vec<(&Predicate, Severity)> = rule.tiers().collect();
+        for (i, (when, severity)) in tiers.iter().enumerate() {
+            let winner = tiers.iter().enumerate().find(|(j, (other, other_severity))| {
+                let beats = other_severity.rank() < severity.rank() || (other_severity == severity && j < &i);
+                *j != i && beats && implies(when, other)
+            });
+            if let Some((j, (_, other_severity))) = winner {
+                let message = format!(
+                    "tier {} ({}) is unreachable: tier {} ({}) holds whenever it does",
+                    i + 1,
+                    severity,
+                    j + 1,
+                    other_severity
+                );
+                self.push(origin, Some(&rule.id), LintKind::UnreachableTier, message);
+            }
+        }
+    }
+}
+
+fn conflicts(a: &Scope, b: &Scope) -> bool {
+    match (a, b) {
+        (Scope::Pattern, Scope::UserPattern) | (Scope::UserPattern, Scope::Pattern) => false,
+        (Scope::User, Scope::User) | (Scope::User, Scope::Pattern) | (Scope::Pattern, Scope::User) => true,
+        (Scope::User, Scope::UserPattern) | (Scope::UserPattern, Scope::User) => true,
+        (Scope::User, _) | (_, Scope::User) => false,
+        (Scope::Workload(x), Scope::Workload(y)) => x == y,
+        _ => true,
+    }
+}
+
+/// Whether `a` holding guarantees `b` holds. Conservative: `false` means
+/// "could not tell". Understands identical predicates, `AND`/`OR` structure
+/// and numeric thresholds on the same path.
+fn implies(a: &Predicate, b: &Predicate) -> bool {
+    if a == b {
+        return true;
+    }
+    match (a, b) {
+        (_, Predicate::And(p, q)) => implies(a, p) && implies(a, q),
+        (_, Predicate::Or(p, q)) if implies(a, p) || implies(a, q) => true,
+        (Predicate::And(p, q), _) => implies(p, b) || implies(q, b),
+        (
+            Predicate::Cmp {
+                path: pa,
+                op: oa,
+                rhs: Rhs::Value(Value::Number(x)),
+            },
+            Predicate::Cmp {
+                path: pb,
+                op: ob,
+                rhs: Rhs::Value(Value::Number(y)),
+            },
+        ) if pa == pb => match (oa, ob) {
+            (Op::Gt, Op::Gt | Op::Ge) | (Op::Ge, Op::Ge) => x >= y,
+            (Op::Ge, Op::Gt) => x > y,
+            (Op::Lt, Op::Lt | Op::Le) | (Op::Le, Op::Le) => x <= y,
+            (Op::Le, Op::Lt) => x < y,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rule::parse_rules_toml;
+
+    fn linter() -> Linter {
+        let mut linter = Linter::new();
+        linter.declare("disk.*.util_pct", ValueKind::Number);
+        linter.declare("disk.max_util_pct", ValueKind::Number);
+        linter.declare("bpf.runqlat.available", ValueKind::Bool);
+        linter.declare("cgroup.controllers", ValueKind::Text);
+        linter
+    }
+
+    fn lint(toml: &str) -> Vec<LintIssue> {
+        let mut linter = linter();
+        linter.check_rules("test.toml", Scope::User, &parse_rules_toml(toml).expect("parse"));
+        linter.finish()
+    }
+
+    fn kinds(issues: &[LintIssue]) -> Vec<LintKind> {
+        issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn clean_rules_have_no_issues() {
+        let issues = lint(
+            r#"
+[[rule]]
+id = "ok"
+when = "any(disk.*.util_pct) > 90 AND disk.sda.util_pct.p95 > 80 AND bpf.runqlat.available == true AND disk.max_util_pct.baseline.z > 3.5 AND delta(disk.max_util_pct) > 0"
+severity = "warn"
+summary = ""
+evidence = ["disk.*.util_pct", "host.kernel"]
+"#,
+        );
+        assert!(issues.is_empty(), "{:?}", issues);
+    }
+
+    #[test]
+    fn unknown_signals_reported_once_per_rule() {
+        let issues = lint(
+            "[[rule]]\nid = \"pg\"\nwhen = \"pg.lock_waits > 0\"\nseverity = \"warn\"\nsummary = \"\"\nevidence = [\"pg.lock_waits\"]\n",
+        );
+        assert_eq!(kinds(&issues), [LintKind::UnknownSignal]);
+        assert_eq!(
+            issues[0].to_string(),
+            "test.toml: pg: unknown signal `pg.lock_waits`: no collector or [[command.extract]] emits it"
+        );
+    }
+
+    #[test]
+    fn comparisons_across_kinds_are_impossible() {
+        let issues = lint(
+            r#"
+[[rule]]
+id = "a"
+when = "bpf.runqlat.available > 1 OR cgroup.controllers > \"x\" OR disk.max_util_pct =~ \"9\" OR cgroup.controllers / 2 > 1"
+severity = "warn"
+summary = ""
+"#,
+        );
+        assert_eq!(kinds(&issues), [LintKind::ImpossibleComparison; 4]);
+        assert!(
+            issues[0].message.contains("compares a bool with a number"),
+            "{}",
+            issues[0]
+        );
+        assert!(issues[1].message.contains("text cannot be ordered"), "{}", issues[1]);
+    }
+
+    #[test]
+    fn quantities_in_another_dimension_are_impossible() {
+        let mut linter = linter();
+        linter.declare_with_unit("app.queue_depth", ValueKind::Number, Unit::None);
+        linter.declare_with_unit("app.heap_mb", ValueKind::Number, Unit::Megabytes);
+        linter.declare_with_unit("net.*.rx_bytes_per_sec", ValueKind::Number, Unit::BytesPerSec);
+        let rules = parse_rules_toml(
+            r#"
+[[rule]]
+id = "a"
+when = "app.queue_depth > 90% OR app.heap_mb.p95 > 2GiB OR app.queue_depth.p95 > 1GiB OR any(net.*.rx_bytes_per_sec) > 10ms"
+severity = "warn"
+summary = ""
+"#,
+        )
+        .expect("parse");
+        linter.check_rules("test.toml", Scope::User, &rules);
+        let issues = linter.finish();
+        assert_eq!(kinds(&issues), [LintKind::ImpossibleComparison; 3]);
+        assert_eq!(
+            issues[0].message,
+            "`app.queue_depth > 90%` can never hold: `app.queue_depth` is unitless, 90% is a percentage"
+        );
+        assert!(
+            issues[1].message.contains("`app.queue_depth.p95` is unitless"),
+            "{}",
+            issues[1]
+        );
+        assert!(
+            issues[2].message.contains("is in bytes/s, 10ms is a duration"),
+            "{}",
+            issues[2]
+        );
+    }
+
+    #[test]
+    fn tier_shadowed_by_more_severe_tier_is_unreachable() {
+        let issues = lint(
+            r#"
+[[rule]]
+id = "disk"
+summary = ""
+
+[[rule.level]]
+when = "disk.max_util_pct > 95"
+severity = "warn"
+
+[[rule.level]]
+when = "disk.max_util_pct > 80"
+severity = "crit"
+"#,
+        );
+        assert_eq!(kinds(&issues), [LintKind::UnreachableTier]);
+        assert_eq!(
+            issues[0].message,
+            "tier 1 (WARN) is unreachable: tier 2 (CRIT) holds whenever it does"
+        );
+    }
+
+    #[test]
+    fn finding_paths_checked_against_known_ids_and_only_in_patterns() {
+        let rules = parse_rules_toml(
+            "[[rule]]\nid = \"cpu.iowait_elevated\"\nwhen = \"finding.x == true\"\nseverity = \"warn\"\nsummary = \"\"\n",
+        )
+        .expect("parse");
+        let mut linter = linter();
+        linter.check_rules("cpu.toml", Scope::Builtin, &rules);
+        for (id, src) in [
+            (
+                "p1",
+                "finding.cpu.iowait_elevated.severity >= warn AND finding.cpu.iowait_elevated == true",
+            ),
+            ("p2", "finding.cpu.iowait_elevated.severity > 1"),
+            ("p3", "finding.mem.pressure == true"),
+        ] {
+            linter.check_pattern("p.toml", Scope::Pattern, id, &Predicate::parse(src).expect("parse"));
+        }
+        let issues = linter.finish();
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "`finding.x` is always absent in a rule: rules run before any finding exists",
+                "`finding.cpu.iowait_elevated.severity > 1` can never hold: it compares a severity with a number",
+                "unknown finding `mem.pressure`: no rule or pattern defines it",
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_ids_respect_scopes() {
+        let rules = parse_rules_toml(
+            "[[rule]]\nid = \"x\"\nwhen = \"disk.max_util_pct > 1\"\nseverity = \"warn\"\nsummary = \"\"\n",
+        )
+        .expect("parse");
+        let mut linter = linter();
+        linter.check_rules("cpu.toml", Scope::Builtin, &rules);
+        linter.check_rules("postgres", Scope::Workload("postgres".into()), &rules);
+        linter.check_rules("a.toml", Scope::User, &rules);
+        linter.check_rules("b.toml", Scope::User, &rules);
+        let when = Predicate::parse("disk.max_util_pct > 1").expect("parse");
+        linter.check_pattern("time_wait.toml", Scope::Pattern, "p", &when);
+        linter.check_pattern("site.toml", Scope::UserPattern, "p", &when);
+        let issues = linter.finish();
+        assert_eq!(kinds(&issues), [LintKind::DuplicateId; 2]);
+        assert_eq!(issues[0].origin, "postgres");
+        assert_eq!(issues[1].message, "duplicate ID, already defined in a.toml");
+    }
+
+    #[test]
+    fn text_that_does_not_compile_as_a_template_is_reported() {
+        let issues = lint(
+            r#"
+[[rule]]
+id = "docker.restarts"
+when = "disk.max_util_pct > 1"
+severity = "warn"
+summary = "{{ disk.max_util_pct }}% busy"
+suggest = ["docker ps --format '{{.Names}}'", "docker ps --format '{% raw %}{{.Names}}{% endraw %}'"]
+"#,
+        );
+        assert_eq!(kinds(&issues), [LintKind::Template]);
+        assert!(
+            issues[0]
+                .message
+                .starts_with("template \"docker ps --format '{{.Names}}'\": syntax error"),
+            "{}",
+            issues[0].message
+        );
+        assert!(
+            issues[0]
+                .message
+                .ends_with("wrap literal braces in {% raw %}…{% endraw %}")
+        );
+    }
+}
@@ -3,15 +3,72 @@
 //! The predicate grammar (per SDD §333):
 //!
 //! ```text
-//! expr   ::= term (("AND" | "OR") term)*
-//! term   ::= path op value
+//! expr   ::= conj ("OR" conj)*
+//! conj   ::= unary ("AND" unary)*
+//! unary  ::= "NOT" (WS | "(") unary | "(" expr ")" | sust | temp | quant | text | term
+//! sust   ::= "sustained" "(" expr "," (NUMBER "%" | INTEGER) ")"
+//! temp   ::= "together" "(" expr "," expr "," (NUMBER "%" | INTEGER) ")"
+//!          | "leads" "(" expr "," expr "," INTEGER ")"
+//! quant  ::= ("any" | "all") "(" glob ")" op (NUMBER | path | BOOL | STRING)
+//! text   ::= path ("=~" | "!~") STRING
+//!          | ("starts_with" | "ends_with" | "contains") "(" path "," STRING ")"
+//! term   ::= arith op (arith | BOOL | STRING | SEVERITY)
+//! arith  ::= prod (("+" | "-") prod)*
+//! prod   ::= factor (("*" | "/") factor)*
+//! factor ::= NUMBER | agg "(" glob ")" | path | "(" arith ")"
+//! agg    ::= "max" | "min" | "sum" | "count"
 //! path   ::= IDENT ("." IDENT)*
+//! glob   ::= SEGMENT ("." SEGMENT)*   ; SEGMENT may contain "*"
 //! op     ::= ">" | "<" | ">=" | "<=" | "==" | "!="
-//! value  ::= NUMBER | BOOL | STRING
+//! SEVERITY ::= "info" | "warn" | "crit"
 //! ```
 //!
-//! Phase 1 supports bare signal paths and `host.*` paths. SampleStats suffixes
-//! (`.p50`, `.p95`, ...) and z-score paths are deferred to Phase 4.
+//! Globs address per-device signal families such as `disk.*.util_pct` (see
+//! `SignalIndex::matching`). `any()` holds when some match satisfies the
+//! comparison and `all()` when every match does; a glob with no matches is
+//! absent, so both are false. `count()` is the number of matches; `max()`,
+//! `min()` and `sum()` skip non-numeric matches and are absent when nothing
+//! numeric matched.
+//!
+//! `sustained(expr, 80%)` evaluates `expr` once per sample, with each sampled
+//! signal taking its i-th sample value, and holds when it is true in at least
+//! that share of the window (or, with a bare integer, that many samples). It is
+//! false when none of the signals it reads were sampled: one snapshot cannot
+//! show a condition was sustained.
+//!
+//! `together(a, b, 80%)` and `leads(a, b, 3)` relate the samples in which two
+//! conditions hold, over the window their sampled signals share.
+//! `together` holds when, of the samples in which either condition holds, at
+//! least that share (or number) have both: the two breach in the same
+//! windows, not merely somewhere in the run. `leads` holds when `a` starts
+//! holding first and `b` follows 1 to 3 samples later: `a` rose before `b`.
+//! Both are false without samples, as `sustained` is.
+//!
+//! Text tests apply to Text values only. `=~` is an unanchored regex search,
+//! compiled when the rule is loaded so a bad pattern fails the load. An
+//! absent or non-Text value fails `!~` too: it did not "not match", it was
+//! never seen.
+//!
+//! A term whose sides are a single path and a single value or path is kept as
+//! `Predicate::Cmp`; anything involving arithmetic becomes `Predicate::Arith`.
+//! Arithmetic is numeric only: a Bool or Text operand, an absent signal, or a
+//! division by zero makes the expression absent, and absent operands never
+//! match — the same rule `evaluate_cmp` applies to absent signals.
+//!
+//! Precedence is `NOT` > `AND` > `OR`; both binary operators are
+//! left-associative, and parentheses regroup sub-expressions. `NOT` inverts
+//! its operand after evaluation, so a comparison on an absent signal (which is
+//! always false) becomes true under `NOT`.
+//!
+//! Besides signal IDs, paths resolve the `host.*` context values of
+//! `CollectCtx::host_value` (`host.cpu_count`, `host.os`, `host.kernel`,
+//! `host.arch`, `host.uname`) and SampleStats suffixes (`.p50`, `.p95`, ...).
+//!
+//! Patterns, which run after the rule pass, can also read the findings it
+//! produced: `finding.<id>` is true when finding `<id>` fired and false when
+//! it did not, and `finding.<id>.severity` is the severity it fired at,
+//! ordered `info < warn < crit` and absent when it did not fire. Rules see no
+//! findings, so there every `finding.*` path is absent.
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -19,12 +76,21 @@ use std::path::{Path, PathBuf};
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::baseline::stats::sample_stats;
+use crate::baseline::stats::{sample_stats, sample_stats_every};
 use crate::collector::CollectCtx;
-use crate::finding::{Evidence, Finding, FindingKind, Severity, ThresholdInfo, sort_findings};
-use crate::signal::{Signal, SignalValue, Trend};
+use crate::finding::{Evidence, Finding, FindingKind, Severity, ThresholdInfo, ThresholdTier, sort_findings};
+use crate::signal::{Signal, SignalValue, Trend, Unit};
+use fixture::RuleTest;
+use near_miss::NearMiss;
+use quantity::{Quantity, number, quantity};
 
 pub mod builtin;
+pub mod fixture;
+pub mod lint;
+pub mod near_miss;
+pub mod quantity;
+pub(crate) mod template;
+pub mod trace;
 
 #[derive(Debug, Error)]
 pub enum Error {
@@ -32,6 +98,10 @@ pub enum Error {
     Predicate(String),
     #[error("failed to read rules directory {path}: {source}")]
     ReadDir { path: PathBuf, source: std::io::Error },
+    /// A `summary` or `suggest` string that looks like a template but does
+    /// not compile as one.
+    #[error("template {text:?}: {reason}")]
+    Template { text: String, reason: String },
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -46,15 +116,170 @@ pub struct Rule {
     pub suggest: Vec<String>,
     pub description: Option<String>,
     pub links: Vec<String>,
+    /// Further severity tiers (`[[rule.level]]`) besides `when`/`severity`.
+    /// The rule yields one finding at the most severe tier that holds.
+    pub levels: Vec<RuleLevel>,
+    /// IDs of findings this rule's finding suppresses when it fires; `*`
+    /// matches any run of characters (see `finding::inhibit`).
+    pub inhibits: Vec<String>,
+}
+
+/// One severity tier of a multi-level rule.
+#[derive(Debug, Clone)]
+pub struct RuleLevel {
+    pub when: Predicate,
+    pub severity: Severity,
+}
+
+impl Rule {
+    /// Every tier of the rule: `when`/`severity` first, then `levels`.
+    pub fn tiers(&self) -> impl Iterator<Item = (&Predicate, Severity)> {
+        std::iter::once((&self.when, self.severity)).chain(self.levels.iter().map(|l| (&l.when, l.severity)))
+    }
+
+    /// The most severe tier whose predicate holds; on equal severity the
+    /// earlier tier wins.
+    pub fn matching_tier(&self, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Option<(&Predicate, Severity)> {
+        self.tiers().filter(|(when, _)| when.evaluate(signals_index, ctx)).fold(
+            None,
+            |best: Option<(&Predicate, Severity)>, tier| match best {
+                Some(b) if b.1.rank() <= tier.1.rank() => Some(b),
+                _ => Some(tier),
+            },
+        )
+    }
 }
 
 // --- Predicate AST -----------------------------------------------------------
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Predicate {
-    Cmp { path: Vec<String>, op: Op, rhs: Rhs },
+    Cmp {
+        path: Vec<String>,
+        op: Op,
+        rhs: Rhs,
+    },
     And(Box<Predicate>, Box<Predicate>),
     Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+    /// Numeric comparison between arithmetic expressions, e.g.
+    /// `cgroup.memory_bytes / cgroup.memory_limit_bytes > 0.9`.
+    Arith {
+        lhs: Expr,
+        op: Op,
+        rhs: Expr,
+    },
+    /// Comparison applied to every signal whose ID matches `glob`, e.g.
+    /// `any(disk.*.util_pct) > 90`.
+    Quantified {
+        quantifier: Quantifier,
+        glob: Vec<String>,
+        op: Op,
+        rhs: Rhs,
+    },
+    /// Holds when `inner` is true in enough individual samples, e.g.
+    /// `sustained(cpu.iowait_pct > 30, 80%)`.
+    Sustained {
+        inner: Box<Predicate>,
+        min: Sustain,
+    },
+    /// String test on a Text value, e.g. `host.kernel =~ "^5\."` or
+    /// `contains(cgroup.controllers, "memory")`.
+    Text {
+        path: Vec<String>,
+        op: TextOp,
+    },
+    /// Relation between the samples in which two conditions hold, e.g.
+    /// `together(a > 1, b > 1, 80%)` or `leads(a > 1, b > 1, 3)`.
+    Temporal {
+        relation: Relation,
+        first: Box<Predicate>,
+        second: Box<Predicate>,
+    },
+}
+
+/// How many samples a `sustained(...)` term must hold in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Sustain {
+    /// Share of the window, in percent: `80%`.
+    Percent(f64),
+    /// Absolute number of samples: `3`.
+    Samples(usize),
+}
+
+/// How the samples of a `Predicate::Temporal` pair must line up.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Relation {
+    /// Of the samples in which either side holds, this many have both.
+    Together(Sustain),
+    /// `first` starts holding 1 to N samples before `second` does.
+    Leads(usize),
+}
+
+/// Outcome of evaluating both sides of a `Predicate::Temporal` term sample
+/// by sample.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SamplePair {
+    first: Vec<bool>,
+    second: Vec<bool>,
+}
+
+/// Outcome of evaluating a `sustained(...)` term sample by sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SampleRun {
+    samples: usize,
+    breached: usize,
+    longest: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextOp {
+    Matches(Regex),
+    NotMatches(Regex),
+    StartsWith(String),
+    EndsWith(String),
+    Contains(String),
+}
+
+impl TextOp {
+    fn test(&self, text: &str) -> bool {
+        match self {
+            TextOp::Matches(re) => re.0.is_match(text),
+            TextOp::NotMatches(re) => !re.0.is_match(text),
+            TextOp::StartsWith(s) => text.starts_with(s.as_str()),
+            TextOp::EndsWith(s) => text.ends_with(s.as_str()),
+            TextOp::Contains(s) => text.contains(s.as_str()),
+        }
+    }
+}
+
+/// Regular expression compiled when the predicate is parsed. Two values are
+/// equal when their source patterns are.
+#[derive(Debug, Clone)]
+pub struct Regex(regex::Regex);
+
+impl Regex {
+    pub fn new(pattern: &str) -> std::result::Result<Self, regex::Error> {
+        regex::Regex::new(pattern).map(Regex)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for Regex {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantifier {
+    /// At least one matching signal satisfies the comparison.
+    Any,
+    /// At least one signal matches and every match satisfies the comparison.
+    All,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -63,6 +288,138 @@ pub enum Rhs {
     Path(Vec<String>),
 }
 
+/// Arithmetic expression over signal paths and numeric literals.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Path(Vec<String>),
+    Binary {
+        op: ArithOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+    /// Aggregate over every signal whose ID matches `glob`, e.g.
+    /// `max(disk.*.await_ms)`.
+    Aggregate {
+        func: AggFunc,
+        glob: Vec<String>,
+    },
+    /// Change of a signal since the most recent rolling baseline record,
+    /// e.g. `delta(dmesg.oom_count)`.
+    Delta(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggFunc {
+    Max,
+    Min,
+    Sum,
+    Count,
+}
+
+impl Expr {
+    fn collect_ids(&self, ids: &mut Vec<String>) {
+        match self {
+            Expr::Number(_) => {}
+            Expr::Path(p) | Expr::Aggregate { glob: p, .. } | Expr::Delta(p) => ids.push(p.join(".")),
+            Expr::Binary { lhs, rhs, .. } => {
+                lhs.collect_ids(ids);
+                rhs.collect_ids(ids);
+            }
+        }
+    }
+
+    /// Push the signals an aggregate drew on: the extreme signal for
+    /// `max`/`min`, every match for `sum`/`count`.
+    fn collect_matches(&self, signals_index: &SignalIndex<'_>, out: &mut Vec<String>) {
+        match self {
+            Expr::Number(_) | Expr::Path(_) | Expr::Delta(_) => {}
+            Expr::Binary { lhs, rhs, .. } => {
+                lhs.collect_matches(signals_index, out);
+                rhs.collect_matches(signals_index, out);
+            }
+            Expr::Aggregate { func, glob } => {
+                let matches = signals_index.matching(glob);
+                match func {
+                    AggFunc::Sum | AggFunc::Count => out.extend(matches.iter().map(|s| s.id.clone())),
+                    AggFunc::Max | AggFunc::Min => {
+                        let numeric = matches.iter().filter_map(|s| match signals_index.value(s) {
+                            LhsValue::Number(n) => Some((n, s)),
+                            _ => None,
+                        });
+                        let extreme = if *func == AggFunc::Max {
+                            numeric.max_by(|a, b| a.0.total_cmp(&b.0))
+                        } else {
+                            numeric.min_by(|a, b| a.0.total_cmp(&b.0))
+                        };
+                        out.extend(extreme.map(|(_, s)| s.id.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Evaluate to a finite number. `None` when an operand is absent or not
+    /// numeric, or when the result is not finite (division by zero).
+    pub fn evaluate(&self, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Option<f64> {
+        let v = match self {
+            Expr::Number(n) => *n,
+            Expr::Path(p) => match resolve_path(p, signals_index, ctx)? {
+                LhsValue::Number(n) => n,
+                LhsValue::Bool(_) | LhsValue::Text(_) | LhsValue::Severity(_) => return None,
+            },
+            Expr::Binary { op, lhs, rhs } => {
+                let a = lhs.evaluate(signals_index, ctx)?;
+                let b = rhs.evaluate(signals_index, ctx)?;
+                match op {
+                    ArithOp::Add => a + b,
+                    ArithOp::Sub => a - b,
+                    ArithOp::Mul => a * b,
+                    ArithOp::Div if b == 0.0 => return None,
+                    ArithOp::Div => a / b,
+                }
+            }
+            Expr::Aggregate { func, glob } => {
+                let matches = signals_index.matching(glob);
+                if *func == AggFunc::Count {
+                    return Some(matches.len() as f64);
+                }
+                // Non-numeric matches are skipped; with no numeric match at
+                // all the aggregate is absent, like a missing signal.
+                let mut values = matches.iter().filter_map(|s| match signals_index.value(s) {
+                    LhsValue::Number(n) => Some(n),
+                    _ => None,
+                });
+                let first = values.next()?;
+                match func {
+                    AggFunc::Max => values.fold(first, f64::max),
+                    AggFunc::Min => values.fold(first, f64::min),
+                    AggFunc::Sum => values.fold(first, |a, b| a + b),
+                    AggFunc::Count => unreachable!("handled above"),
+                }
+            }
+            // Absent without a rolling record, or when the record predates
+            // the signal.
+            Expr::Delta(p) => {
+                let signal = signals_index.get(&p.join("."))?;
+                let LhsValue::Number(now) = signals_index.value(signal) else {
+                    return None;
+                };
+                now - ctx.baseline.as_ref()?.value_of(signal)?
+            }
+        };
+        v.is_finite().then_some(v)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Op {
     Gt,
@@ -73,27 +430,185 @@ pub enum Op {
     Ne,
 }
 
+impl Op {
+    /// The operator that holds exactly when `self` does not, e.g. `>` → `<=`.
+    /// Used to report thresholds for comparisons nested under `NOT`.
+    pub fn negate(self) -> Op {
+        match self {
+            Op::Gt => Op::Le,
+            Op::Lt => Op::Ge,
+            Op::Ge => Op::Lt,
+            Op::Le => Op::Gt,
+            Op::Eq => Op::Ne,
+            Op::Ne => Op::Eq,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Number(f64),
     Bool(bool),
     Str(String),
+    Severity(Severity),
+    /// A number with a unit, e.g. `2GiB`; see `quantity`.
+    Quantity(Quantity),
 }
 
 impl Predicate {
     pub fn parse(input: &str) -> Result<Self> {
-        expr.parse(input.trim()).map_err(|e| Error::Predicate(e.to_string()))
+        let predicate = expr.parse(input.trim()).map_err(|e| Error::Predicate(e.to_string()))?;
+        predicate.check_units()?;
+        Ok(predicate)
+    }
+
+    /// Reject unit-suffixed literals compared with a catalog signal whose
+    /// unit measures something else, e.g. `mem.free_pct > 2GiB`. Signals
+    /// outside the catalog are left to `rules lint`.
+    fn check_units(&self) -> Result<()> {
+        let (path, op, q) = match self {
+            Predicate::And(a, b) | Predicate::Or(a, b) => return a.check_units().and_then(|_| b.check_units()),
+            Predicate::Not(a) | Predicate::Sustained { inner: a, .. } => return a.check_units(),
+            Predicate::Temporal { first, second, .. } => {
+                return first.check_units().and_then(|_| second.check_units());
+            }
+            Predicate::Cmp {
+                path,
+                op,
+                rhs: Rhs::Value(Value::Quantity(q)),
+            }
+            | Predicate::Quantified {
+                glob: path,
+                op,
+                rhs: Rhs::Value(Value::Quantity(q)),
+                ..
+            } => (path, op, q),
+            _ => return Ok(()),
+        };
+        let Some(unit) = catalog_unit(path) else {
+            return Ok(());
+        };
+        if q.convert(unit).is_some() {
+            return Ok(());
+        }
+        let id = path.join(".");
+        let measured = match unit {
+            Unit::None => "is unitless".to_string(),
+            unit => format!("is in {}", unit),
+        };
+        Err(Error::Predicate(format!(
+            "`{} {} {}`: {} {}, {} is {}",
+            id,
+            op_to_str(*op),
+            q,
+            id,
+            measured,
+            q,
+            q.dimension
+        )))
     }
 
     /// Return all signal IDs referenced by this predicate (left-hand paths plus
     /// any right-hand path comparisons). Used to scope pattern evidence to only
-    /// the signals the pattern actually tested.
+    /// the signals the pattern actually tested. Globs are returned verbatim
+    /// (e.g. `disk.*.util_pct`); see `matched_signal_ids` for concrete IDs.
     pub fn signal_ids(&self) -> Vec<String> {
         let mut ids = Vec::new();
         self.collect_ids(&mut ids);
         ids
     }
 
+    /// Return the concrete signal IDs behind the glob terms that hold against
+    /// `signals_index`: the satisfying signals of `any()`, every match of
+    /// `all()`, `sum()` and `count()`, and the extreme signal of `max()` and
+    /// `min()`. Terms that are false, or sit under `NOT`, contribute nothing.
+    /// Used to name the concrete devices in finding evidence.
+    pub fn matched_signal_ids(&self, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Vec<String> {
+        self.matched_signals(signals_index, ctx)
+            .into_iter()
+            .map(|m| m.id)
+            .collect()
+    }
+
+    /// Like `matched_signal_ids`, but also carries the sampled signals of
+    /// `sustained(...)` terms that hold, with a detail line saying how many
+    /// samples breached. Each ID appears once; the first detail wins.
+    pub fn matched_signals(&self, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Vec<SignalMatch> {
+        let mut matches = Vec::new();
+        self.collect_matches(signals_index, ctx, &mut matches);
+        let mut merged: Vec<SignalMatch> = Vec::new();
+        for m in matches {
+            match merged.iter_mut().find(|e| e.id == m.id) {
+                Some(existing) => {
+                    if existing.detail.is_none() {
+                        existing.detail = m.detail;
+                    }
+                }
+                None => merged.push(m),
+            }
+        }
+        merged
+    }
+
+    fn collect_matches(&self, signals_index: &SignalIndex<'_>, ctx: &CollectCtx, out: &mut Vec<SignalMatch>) {
+        if !self.evaluate(signals_index, ctx) {
+            return;
+        }
+        match self {
+            Predicate::And(a, b) | Predicate::Or(a, b) => {
+                a.collect_matches(signals_index, ctx, out);
+                b.collect_matches(signals_index, ctx, out);
+            }
+            Predicate::Not(_) | Predicate::Cmp { .. } | Predicate::Text { .. } => {}
+            Predicate::Arith { lhs, rhs, .. } => {
+                let mut ids = Vec::new();
+                lhs.collect_matches(signals_index, &mut ids);
+                rhs.collect_matches(signals_index, &mut ids);
+                out.extend(ids.into_iter().map(SignalMatch::plain));
+            }
+            Predicate::Quantified { glob, op, rhs, .. } => {
+                for signal in signals_index.matching(glob) {
+                    let rhs = resolve_rhs(rhs, signal.unit, signals_index, ctx);
+                    if compare_values(Some(signals_index.value(signal)), *op, rhs) {
+                        out.push(SignalMatch::plain(signal.id.clone()));
+                    }
+                }
+            }
+            Predicate::Sustained { inner, .. } => {
+                let Some(run) = inner.sample_run(signals_index, ctx) else {
+                    return;
+                };
+                let detail = run.describe(ctx.interval);
+                for signal in inner.sampled_signals(signals_index) {
+                    out.push(SignalMatch {
+                        id: signal.id.clone(),
+                        detail: Some(detail.clone()),
+                    });
+                }
+            }
+            Predicate::Temporal {
+                relation,
+                first,
+                second,
+            } => {
+                let Some(pair) = sample_pair(first, second, signals_index, ctx) else {
+                    return;
+                };
+                let detail = pair.describe(*relation, ctx.interval);
+                for side in [first, second] {
+                    for signal in side.sampled_signals(signals_index) {
+                        if !out.iter().any(|m| m.id == signal.id) {
+                            out.push(SignalMatch {
+                                id: signal.id.clone(),
+                                detail: Some(detail.clone()),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn collect_ids(&self, ids: &mut Vec<String>) {
         match self {
             Predicate::Cmp { path, rhs, .. } => {
@@ -102,10 +617,26 @@ impl Predicate {
                     ids.push(p.join("."));
                 }
             }
-            Predicate::And(a, b) | Predicate::Or(a, b) => {
+            Predicate::And(a, b)
+            | Predicate::Or(a, b)
+            | Predicate::Temporal {
+                first: a, second: b, ..
+            } => {
                 a.collect_ids(ids);
                 b.collect_ids(ids);
             }
+            Predicate::Not(a) | Predicate::Sustained { inner: a, .. } => a.collect_ids(ids),
+            Predicate::Arith { lhs, rhs, .. } => {
+                lhs.collect_ids(ids);
+                rhs.collect_ids(ids);
+            }
+            Predicate::Text { path, .. } => ids.push(path.join(".")),
+            Predicate::Quantified { glob, rhs, .. } => {
+                ids.push(glob.join("."));
+                if let Rhs::Path(p) = rhs {
+                    ids.push(p.join("."));
+                }
+            }
         }
     }
 
@@ -113,30 +644,285 @@ impl Predicate {
         match self {
             Predicate::And(a, b) => a.evaluate(signals_index, ctx) && b.evaluate(signals_index, ctx),
             Predicate::Or(a, b) => a.evaluate(signals_index, ctx) || b.evaluate(signals_index, ctx),
+            Predicate::Not(a) => !a.evaluate(signals_index, ctx),
             Predicate::Cmp { path, op, rhs } => evaluate_cmp(path, *op, rhs, signals_index, ctx),
+            Predicate::Arith { lhs, op, rhs } => {
+                match (lhs.evaluate(signals_index, ctx), rhs.evaluate(signals_index, ctx)) {
+                    (Some(n), Some(m)) => compare_numbers(n, *op, m),
+                    _ => false,
+                }
+            }
+            // Like `evaluate_cmp`, an absent or non-Text value never matches,
+            // for `!~` as much as for `=~`.
+            Predicate::Text { path, op } => match resolve_path(path, signals_index, ctx) {
+                Some(LhsValue::Text(t)) => op.test(&t),
+                _ => false,
+            },
+            Predicate::Sustained { inner, min } => match inner.sample_run(signals_index, ctx) {
+                Some(run) => match min {
+                    Sustain::Percent(p) => run.breached as f64 * 100.0 >= p * run.samples as f64,
+                    Sustain::Samples(k) => run.breached >= *k,
+                },
+                None => false,
+            },
+            Predicate::Temporal {
+                relation,
+                first,
+                second,
+            } => sample_pair(first, second, signals_index, ctx).is_some_and(|pair| pair.holds(*relation)),
+            Predicate::Quantified {
+                quantifier,
+                glob,
+                op,
+                rhs,
+            } => {
+                let matches = signals_index.matching(glob);
+                let mut results = matches.iter().map(|s| {
+                    let rhs = resolve_rhs(rhs, s.unit, signals_index, ctx);
+                    compare_values(Some(signals_index.value(s)), *op, rhs)
+                });
+                match quantifier {
+                    Quantifier::Any => results.any(|b| b),
+                    // An empty family is absent, not vacuously true.
+                    Quantifier::All => !matches.is_empty() && results.all(|b| b),
+                }
+            }
+        }
+    }
+}
+
+impl Predicate {
+    /// Sampled signals this predicate reads, with globs expanded.
+    fn sampled_signals<'a>(&self, signals_index: &SignalIndex<'a>) -> Vec<&'a Signal> {
+        let mut out: Vec<&'a Signal> = Vec::new();
+        for id in self.signal_ids() {
+            let found = if is_glob(&id) {
+                let glob: Vec<String> = id.split('.').map(str::to_string).collect();
+                signals_index.matching(&glob)
+            } else {
+                signals_index.get(&id).map(|s| vec![*s]).unwrap_or_default()
+            };
+            for s in found {
+                if s.samples.is_some() && !out.iter().any(|o| o.id == s.id) {
+                    out.push(s);
+                }
+            }
+        }
+        out
+    }
+
+    /// Evaluate `self` once per sample. The window is the shortest sample
+    /// vector among the signals read, so every sampled signal has a value at
+    /// each step. `None` when no signal read has samples.
+    fn sample_run(&self, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Option<SampleRun> {
+        let samples = sample_window(&self.sampled_signals(signals_index))?;
+        let mut run = SampleRun {
+            samples,
+            breached: 0,
+            longest: 0,
+        };
+        let mut current = 0;
+        for i in 0..samples {
+            if self.evaluate(&signals_index.at_sample(i), ctx) {
+                run.breached += 1;
+                current += 1;
+                run.longest = run.longest.max(current);
+            } else {
+                current = 0;
+            }
+        }
+        Some(run)
+    }
+}
+
+/// Length of the window every signal in `signals` has a sample for; `None`
+/// when none of them is sampled.
+fn sample_window(signals: &[&Signal]) -> Option<usize> {
+    signals
+        .iter()
+        .filter_map(|s| s.samples.as_ref().map(Vec::len))
+        .min()
+        .filter(|n| *n > 0)
+}
+
+/// Evaluate `first` and `second` once per sample over the window their
+/// sampled signals share. `None` when neither reads a sampled signal.
+fn sample_pair(
+    first: &Predicate,
+    second: &Predicate,
+    signals_index: &SignalIndex<'_>,
+    ctx: &CollectCtx,
+) -> Option<SamplePair> {
+    let mut signals = first.sampled_signals(signals_index);
+    signals.extend(second.sampled_signals(signals_index));
+    let samples = sample_window(&signals)?;
+    let (first, second) = (0..samples)
+        .map(|i| {
+            let at = signals_index.at_sample(i);
+            (first.evaluate(&at, ctx), second.evaluate(&at, ctx))
+        })
+        .unzip();
+    Some(SamplePair { first, second })
+}
+
+impl SamplePair {
+    /// Samples in which both sides hold, and in which either does.
+    fn overlap(&self) -> (usize, usize) {
+        let pairs = || self.first.iter().zip(&self.second);
+        (
+            pairs().filter(|(a, b)| **a && **b).count(),
+            pairs().filter(|(a, b)| **a || **b).count(),
+        )
+    }
+
+    /// First sample in which each side holds.
+    fn onsets(&self) -> (Option<usize>, Option<usize>) {
+        (self.first.iter().position(|b| *b), self.second.iter().position(|b| *b))
+    }
+
+    fn holds(&self, relation: Relation) -> bool {
+        match relation {
+            Relation::Together(min) => {
+                let (both, either) = self.overlap();
+                match min {
+                    Sustain::Percent(p) => either > 0 && both as f64 * 100.0 >= p * either as f64,
+                    Sustain::Samples(k) => both >= k,
+                }
+            }
+            Relation::Leads(n) => match self.onsets() {
+                (Some(i), Some(j)) => j > i && j - i <= n,
+                _ => false,
+            },
+        }
+    }
+
+    fn describe(&self, relation: Relation, interval: Option<std::time::Duration>) -> String {
+        let samples = self.first.len();
+        match relation {
+            Relation::Together(_) => {
+                let (both, either) = self.overlap();
+                let pct = if either == 0 {
+                    0.0
+                } else {
+                    both as f64 * 100.0 / either as f64
+                };
+                match interval {
+                    Some(interval) => format!(
+                        "both breached in {}/{} samples where either did ({:.0}%, {}) of {}",
+                        both,
+                        either,
+                        pct,
+                        format_secs(interval * both as u32),
+                        samples
+                    ),
+                    None => format!(
+                        "both breached in {}/{} samples where either did ({:.0}%) of {}",
+                        both, either, pct, samples
+                    ),
+                }
+            }
+            Relation::Leads(_) => match self.onsets() {
+                (Some(i), Some(j)) if j > i => match interval {
+                    Some(interval) => format!(
+                        "first breached at sample {}, second at sample {}: {} samples later ({})",
+                        i + 1,
+                        j + 1,
+                        j - i,
+                        format_secs(interval * (j - i) as u32)
+                    ),
+                    None => format!(
+                        "first breached at sample {}, second at sample {}: {} samples later",
+                        i + 1,
+                        j + 1,
+                        j - i
+                    ),
+                },
+                (Some(i), Some(j)) => format!(
+                    "first breached at sample {}, second at sample {}: not after the first",
+                    i + 1,
+                    j + 1
+                ),
+                (Some(i), None) => format!(
+                    "first breached at sample {}, second never did in {} samples",
+                    i + 1,
+                    samples
+                ),
+                (None, _) => format!("first never breached in {} samples", samples),
+            },
+        }
+    }
+}
+
+impl SampleRun {
+    fn describe(&self, interval: Option<std::time::Duration>) -> String {
+        let pct = self.breached as f64 * 100.0 / self.samples as f64;
+        match interval {
+            Some(interval) => format!(
+                "breached in {}/{} samples ({:.0}%, {}); longest run {} samples ({})",
+                self.breached,
+                self.samples,
+                pct,
+                format_secs(interval * self.breached as u32),
+                self.longest,
+                format_secs(interval * self.longest as u32),
+            ),
+            None => format!(
+                "breached in {}/{} samples ({:.0}%); longest run {} samples",
+                self.breached, self.samples, pct, self.longest
+            ),
         }
     }
 }
 
+fn format_secs(d: std::time::Duration) -> String {
+    let secs = d.as_secs_f64();
+    if secs.fract() == 0.0 {
+        format!("{}s", secs)
+    } else {
+        format!("{:.1}s", secs)
+    }
+}
+
+/// A concrete signal that helped a predicate hold; see
+/// `Predicate::matched_signals`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignalMatch {
+    pub id: String,
+    pub detail: Option<String>,
+}
+
+impl SignalMatch {
+    fn plain(id: String) -> Self {
+        SignalMatch { id, detail: None }
+    }
+}
+
 fn evaluate_cmp(path: &[String], op: Op, rhs: &Rhs, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> bool {
-    let lhs = resolve_path(path, signals_index, ctx);
-    let rhs_resolved = match rhs {
+    compare_values(
+        resolve_path(path, signals_index, ctx),
+        op,
+        resolve_rhs(rhs, signals_index.unit_of(path), signals_index, ctx),
+    )
+}
+
+/// Resolve the right-hand side of a comparison whose left-hand side is in
+/// `unit`. A quantity in another dimension is absent.
+fn resolve_rhs(rhs: &Rhs, unit: Unit, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Option<LhsValue> {
+    match rhs {
         Rhs::Value(v) => match v {
             Value::Number(n) => Some(LhsValue::Number(*n)),
             Value::Bool(b) => Some(LhsValue::Bool(*b)),
             Value::Str(s) => Some(LhsValue::Text(s.clone())),
+            Value::Severity(sev) => Some(LhsValue::Severity(*sev)),
+            Value::Quantity(q) => q.convert(unit).map(LhsValue::Number),
         },
         Rhs::Path(p) => resolve_path(p, signals_index, ctx),
-    };
-    match (lhs, rhs_resolved) {
-        (Some(LhsValue::Number(n)), Some(LhsValue::Number(m))) => match op {
-            Op::Gt => n > m,
-            Op::Lt => n < m,
-            Op::Ge => n >= m,
-            Op::Le => n <= m,
-            Op::Eq => (n - m).abs() < 1e-9 * (n.abs().max(m.abs()).max(1.0)),
-            Op::Ne => (n - m).abs() >= 1e-9 * (n.abs().max(m.abs()).max(1.0)),
-        },
+    }
+}
+
+fn compare_values(lhs: Option<LhsValue>, op: Op, rhs: Option<LhsValue>) -> bool {
+    match (lhs, rhs) {
+        (Some(LhsValue::Number(n)), Some(LhsValue::Number(m))) => compare_numbers(n, op, m),
         (Some(LhsValue::Bool(b)), Some(LhsValue::Bool(c))) => match op {
             Op::Eq => b == c,
             Op::Ne => b != c,
@@ -147,43 +933,102 @@ fn evaluate_cmp(path: &[String], op: Op, rhs: &Rhs, signals_index: &SignalIndex<
             Op::Ne => t != s,
             _ => false,
         },
+        // Rank runs Crit = 0 .. Info = 2, so compare it reversed.
+        (Some(LhsValue::Severity(a)), Some(LhsValue::Severity(b))) => {
+            compare_numbers(f64::from(b.rank()), op, f64::from(a.rank()))
+        }
         // Absent or type-mismatched signals never match (SDD §453).
         _ => false,
     }
 }
 
+fn compare_numbers(n: f64, op: Op, m: f64) -> bool {
+    match op {
+        Op::Gt => n > m,
+        Op::Lt => n < m,
+        Op::Ge => n >= m,
+        Op::Le => n <= m,
+        Op::Eq => (n - m).abs() < 1e-9 * (n.abs().max(m.abs()).max(1.0)),
+        Op::Ne => (n - m).abs() >= 1e-9 * (n.abs().max(m.abs()).max(1.0)),
+    }
+}
+
+#[derive(Clone)]
 enum LhsValue {
     Number(f64),
     Bool(bool),
     Text(String),
+    Severity(Severity),
 }
 
 fn resolve_path(path: &[String], signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Option<LhsValue> {
     if path.is_empty() {
         return None;
     }
-    if path[0] == "host" && path.len() == 2 && path[1] == "cpu_count" {
-        return Some(LhsValue::Number(ctx.cpu_count as f64));
+    if path[0] == "host" && path.len() == 2 {
+        if let Some(v) = ctx.host_value(&path.join(".")) {
+            return Some(value_of(&v));
+        }
+    }
+    if path[0] == "finding" && path.len() >= 2 {
+        let findings = signals_index.findings.as_ref()?;
+        if path.len() >= 3 && path[path.len() - 1] == "severity" {
+            let id = path[1..path.len() - 1].join(".");
+            if let Some(severity) = findings.get(&id) {
+                return Some(LhsValue::Severity(*severity));
+            }
+        }
+        return Some(LhsValue::Bool(findings.contains_key(&path[1..].join("."))));
+    }
+
+    // `<signal>.baseline.{p50,p95,mad,z}` reads the signal's BaselineStats,
+    // absent when no baseline was loaded or it lacks the signal. Like the
+    // SampleStats suffixes below, the path falls through to a bare lookup
+    // when the prefix is not a signal.
+    if let [prefix @ .., baseline, stat] = path {
+        if baseline == "baseline" && matches!(stat.as_str(), "p50" | "p95" | "mad" | "z") {
+            if let Some(signal) = signals_index.get(&prefix.join(".")) {
+                let stats = signal.baseline.as_ref()?;
+                return Some(LhsValue::Number(match stat.as_str() {
+                    "p50" => stats.p50,
+                    "p95" => stats.p95,
+                    "mad" => stats.mad,
+                    _ => stats.z_score,
+                }));
+            }
+        }
     }
 
-    // Check for SampleStats suffixes (.p50, .p95, .p99, .min, .max, .trend).
+    // Check for SampleStats suffixes (.p50, .p95, .p99, .min, .max, .mean,
+    // .stddev, .cv, .slope, .count, .trend).
     // Only intercept when: (a) the last segment is a known suffix, (b) the
     // prefix path resolves to an actual signal that has samples. If either
     // check fails we fall through to a bare signal lookup, avoiding collisions
     // with signals whose IDs happen to end in a reserved word (e.g. "disk.max").
     let sample_suffix = path.last().map(|s| s.as_str());
-    let is_sample_suffix = matches!(sample_suffix, Some("p50" | "p95" | "p99" | "min" | "max" | "trend"));
+    let is_sample_suffix = matches!(
+        sample_suffix,
+        Some("p50" | "p95" | "p99" | "min" | "max" | "mean" | "stddev" | "cv" | "slope" | "count" | "trend")
+    );
     if is_sample_suffix && path.len() >= 2 {
         let signal_id = path[..path.len() - 1].join(".");
         if let Some(signal) = signals_index.get(&signal_id) {
             if let Some(samples) = signal.samples.as_deref() {
-                if let Some(stats) = sample_stats(samples) {
+                // Without a sampling interval (a report read back from
+                // JSON), samples count as one second apart.
+                let interval = ctx.interval.unwrap_or(std::time::Duration::from_secs(1));
+                if let Some(stats) = sample_stats_every(samples, interval) {
                     return match sample_suffix.unwrap() {
                         "p50" => Some(LhsValue::Number(stats.p50)),
                         "p95" => Some(LhsValue::Number(stats.p95)),
                         "p99" => Some(LhsValue::Number(stats.p99)),
                         "min" => Some(LhsValue::Number(stats.min)),
                         "max" => Some(LhsValue::Number(stats.max)),
+                        "mean" => stats.mean.map(LhsValue::Number),
+                        "stddev" => stats.stddev.map(LhsValue::Number),
+                        "cv" => stats.cv.map(LhsValue::Number),
+                        "slope" => stats.slope.map(LhsValue::Number),
+                        "count" => stats.count.map(|n| LhsValue::Number(n as f64)),
                         "trend" => {
                             let s = match stats.trend {
                                 Trend::Rising => "rising",
@@ -201,26 +1046,27 @@ fn resolve_path(path: &[String], signals_index: &SignalIndex<'_>, ctx: &CollectC
     }
 
     let id = path.join(".");
-    let signal = signals_index.get(&id)?;
-
-    // Bare signal ID with samples: use SampleStats::p50 instead of Signal::value
-    // so that rules comparing a sampled signal see the representative value.
-    if let Some(samples) = signal.samples.as_deref() {
-        if let Some(stats) = sample_stats(samples) {
-            return Some(LhsValue::Number(stats.p50));
-        }
-    }
+    signals_index.get(&id).map(|signal| signals_index.value(signal))
+}
 
-    match &signal.value {
-        SignalValue::F64(v) => Some(LhsValue::Number(*v)),
-        SignalValue::I64(v) => Some(LhsValue::Number(*v as f64)),
-        SignalValue::Bool(v) => Some(LhsValue::Bool(*v)),
-        SignalValue::Text(v) => Some(LhsValue::Text(v.clone())),
+fn value_of(value: &SignalValue) -> LhsValue {
+    match value {
+        SignalValue::F64(v) => LhsValue::Number(*v),
+        SignalValue::I64(v) => LhsValue::Number(*v as f64),
+        SignalValue::Bool(v) => LhsValue::Bool(*v),
+        SignalValue::Text(v) => LhsValue::Text(v.clone()),
     }
 }
 
+#[derive(Clone)]
 pub struct SignalIndex<'a> {
     by_id: std::collections::HashMap<&'a str, &'a Signal>,
+    /// Set while evaluating a `sustained(...)` term: bare sampled signals
+    /// resolve to this sample instead of their p50.
+    sample: Option<usize>,
+    /// Severity of each finding `finding.*` paths can see; `None` outside
+    /// the pattern pass.
+    findings: Option<HashMap<String, Severity>>,
 }
 
 impl<'a> SignalIndex<'a> {
@@ -231,20 +1077,132 @@ impl<'a> SignalIndex<'a> {
                 log::warn!("duplicate signal id '{}'; later value wins", s.id);
             }
         }
-        SignalIndex { by_id }
+        SignalIndex {
+            by_id,
+            sample: None,
+            findings: None,
+        }
+    }
+
+    /// Make `findings` visible to `finding.<id>` paths. When an ID fired more
+    /// than once, the most severe finding counts.
+    pub fn with_findings(mut self, findings: &[Finding]) -> Self {
+        let mut by_id: HashMap<String, Severity> = HashMap::new();
+        for f in findings {
+            let severity = by_id.entry(f.id.clone()).or_insert(f.severity);
+            if f.severity.rank() < severity.rank() {
+                *severity = f.severity;
+            }
+        }
+        self.findings = Some(by_id);
+        self
+    }
+
+    /// Severity of finding `id` if it is visible and fired.
+    pub fn finding(&self, id: &str) -> Option<Severity> {
+        self.findings.as_ref()?.get(id).copied()
+    }
+
+    /// A view of the same signals in which each sampled signal takes the
+    /// value of its `i`-th sample. Signals without samples are unchanged.
+    pub fn at_sample(&self, i: usize) -> Self {
+        SignalIndex {
+            sample: Some(i),
+            ..self.clone()
+        }
+    }
+
+    fn value(&self, signal: &Signal) -> LhsValue {
+        if let Some(samples) = signal.samples.as_deref() {
+            if let Some(v) = self.sample.and_then(|i| samples.get(i)) {
+                return LhsValue::Number(*v);
+            }
+            // Bare signal ID with samples: use SampleStats::p50 instead of
+            // Signal::value so that rules comparing a sampled signal see the
+            // representative value.
+            if let Some(stats) = sample_stats(samples) {
+                return LhsValue::Number(stats.p50);
+            }
+        }
+        value_of(&signal.value)
     }
 
     pub fn get(&self, id: &str) -> Option<&&'a Signal> {
         self.by_id.get(id)
     }
+
+    /// Unit of the signal `path` reads, looking through a stats suffix such
+    /// as `.p95` or `.baseline.p95`. `Unit::None` when no such signal was
+    /// collected, and for unitless statistics such as `.count`.
+    fn unit_of(&self, path: &[String]) -> Unit {
+        if let Some(signal) = self.get(&path.join(".")) {
+            return signal.unit;
+        }
+        if is_unitless_stat(path) {
+            return Unit::None;
+        }
+        signal_prefixes(path)
+            .find_map(|prefix| self.get(&prefix.join(".")))
+            .map_or(Unit::None, |s| s.unit)
+    }
+
+    fn signals(&self) -> impl Iterator<Item = &'a Signal> + '_ {
+        self.by_id.values().copied()
+    }
+
+    /// Signals whose ID matches `glob`, sorted by ID. Globs match segment by
+    /// segment: the segment counts must agree and `*` within a segment
+    /// matches any run of characters, so `disk.*.util_pct` matches
+    /// `disk.sda.util_pct` but not `disk.sda.part1.util_pct`. A label value
+    /// the ID embeds counts as one segment, so the same glob matches
+    /// `disk.dm.0.util_pct` labelled `device = "dm.0"`.
+    pub fn matching(&self, glob: &[String]) -> Vec<&'a Signal> {
+        let mut matches: Vec<&'a Signal> = self
+            .by_id
+            .values()
+            .filter(|s| segments_match(glob, &s.segments()))
+            .copied()
+            .collect();
+        matches.sort_by(|a, b| a.id.cmp(&b.id));
+        matches
+    }
+}
+
+fn glob_matches(glob: &[String], id: &str) -> bool {
+    let segments: Vec<&str> = id.split('.').collect();
+    segments_match(glob, &segments)
+}
+
+fn segments_match(glob: &[String], segments: &[&str]) -> bool {
+    segments.len() == glob.len() && glob.iter().zip(segments).all(|(g, s)| segment_matches(g, s))
+}
+
+pub(crate) fn segment_matches(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, rest)) => {
+            let Some(text) = text.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=text.len())
+                .filter(|&i| text.is_char_boundary(i))
+                .any(|i| segment_matches(rest, &text[i..]))
+        }
+    }
+}
+
+/// True when an ID as written in a rule contains a `*` wildcard.
+pub fn is_glob(id: &str) -> bool {
+    id.contains('*')
 }
 
 // --- Parser (winnow) ---------------------------------------------------------
 
 use winnow::{
-    ModalResult, Parser as _,
-    ascii::{float, multispace0, multispace1},
-    combinator::{alt, delimited, repeat, separated},
+    ModalResult, Parser,
+    ascii::{multispace0, multispace1},
+    combinator::{alt, cut_err, delimited, not, opt, peek, preceded, repeat, terminated},
+    error::{ContextError, ErrMode, StrContext, StrContextValue},
     token::take_while,
 };
 
@@ -256,33 +1214,101 @@ fn ident<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
 
 fn bare_ident<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
     ident
-        .verify(|s: &&str| !matches!(s.to_ascii_uppercase().as_str(), "AND" | "OR" | "TRUE" | "FALSE"))
+        .verify(|s: &&str| !matches!(s.to_ascii_uppercase().as_str(), "AND" | "OR" | "NOT" | "TRUE" | "FALSE"))
         .parse_next(input)
 }
 
+/// Only the first segment is checked against keywords: after a `.` nothing
+/// else can follow, so IDs such as `disk.not.mounted` stay addressable. A
+/// first segment followed by `.` is never a keyword either, so `not.ready`
+/// is a path.
 fn path(input: &mut &str) -> ModalResult<Vec<String>> {
-    separated(1.., bare_ident.map(|s: &str| s.to_string()), '.').parse_next(input)
+    let first = alt((terminated(ident, peek('.')), bare_ident)).parse_next(input)?;
+    let rest: Vec<&str> = repeat(0.., preceded('.', ident)).parse_next(input)?;
+    Ok(std::iter::once(first).chain(rest).map(str::to_string).collect())
 }
 
-fn cmp_op(input: &mut &str) -> ModalResult<Op> {
-    alt((
-        ">=".value(Op::Ge),
-        "<=".value(Op::Le),
-        "==".value(Op::Eq),
-        "!=".value(Op::Ne),
-        ">".value(Op::Gt),
-        "<".value(Op::Lt),
-    ))
-    .parse_next(input)
+fn glob_segment<'i>(input: &mut &'i str) -> ModalResult<&'i str> {
+    take_while(1.., |c: char| c.is_alphanumeric() || c == '_' || c == '*').parse_next(input)
 }
 
-fn bool_literal(input: &mut &str) -> ModalResult<bool> {
-    ident
-        .verify_map(|s: &str| match s.to_ascii_uppercase().as_str() {
-            "TRUE" => Some(true),
-            "FALSE" => Some(false),
-            _ => None,
-        })
+fn glob(input: &mut &str) -> ModalResult<Vec<String>> {
+    let first = glob_segment.parse_next(input)?;
+    let rest: Vec<&str> = repeat(0.., preceded('.', glob_segment)).parse_next(input)?;
+    Ok(std::iter::once(first).chain(rest).map(str::to_string).collect())
+}
+
+/// `name(glob)`, where `name` is recognised by `func`. Function names are
+/// only special when followed by `(`, so a signal called `count` still parses
+/// as a path.
+fn glob_call<'i, F>(
+    func: impl Parser<&'i str, F, ErrMode<ContextError>>,
+) -> impl Parser<&'i str, (F, Vec<String>), ErrMode<ContextError>> {
+    (func, multispace0, '(', multispace0, glob, multispace0, ')').map(|(f, _, _, _, g, _, _)| (f, g))
+}
+
+/// `delta(path)`. A glob has no single previous value, so only paths are
+/// accepted.
+fn delta_call(input: &mut &str) -> ModalResult<Vec<String>> {
+    (
+        ident.verify(|s: &&str| s.eq_ignore_ascii_case("delta")),
+        multispace0,
+        '(',
+        multispace0,
+        cut_err(path),
+        multispace0,
+        ')',
+    )
+        .map(|(_, _, _, _, p, _, _)| p)
+        .parse_next(input)
+}
+
+fn agg_func(input: &mut &str) -> ModalResult<AggFunc> {
+    ident
+        .verify_map(|s: &str| match s.to_ascii_lowercase().as_str() {
+            "max" => Some(AggFunc::Max),
+            "min" => Some(AggFunc::Min),
+            "sum" => Some(AggFunc::Sum),
+            "count" => Some(AggFunc::Count),
+            _ => None,
+        })
+        .parse_next(input)
+}
+
+fn quantifier(input: &mut &str) -> ModalResult<Quantifier> {
+    ident
+        .verify_map(|s: &str| match s.to_ascii_lowercase().as_str() {
+            "any" => Some(Quantifier::Any),
+            "all" => Some(Quantifier::All),
+            _ => None,
+        })
+        .parse_next(input)
+}
+
+fn cmp_op(input: &mut &str) -> ModalResult<Op> {
+    alt((
+        ">=".value(Op::Ge),
+        "<=".value(Op::Le),
+        "==".value(Op::Eq),
+        "!=".value(Op::Ne),
+        ">".value(Op::Gt),
+        "<".value(Op::Lt),
+    ))
+    .parse_next(input)
+}
+
+/// `info`, `warn` or `crit`, not followed by a path segment.
+fn severity_literal(input: &mut &str) -> ModalResult<Severity> {
+    terminated(ident.verify_map(|s: &str| parse_severity(s).ok()), not('.')).parse_next(input)
+}
+
+fn bool_literal(input: &mut &str) -> ModalResult<bool> {
+    ident
+        .verify_map(|s: &str| match s.to_ascii_uppercase().as_str() {
+            "TRUE" => Some(true),
+            "FALSE" => Some(false),
+            _ => None,
+        })
         .parse_next(input)
 }
 
@@ -295,48 +1321,266 @@ fn quoted_string(input: &mut &str) -> ModalResult<String> {
     .parse_next(input)
 }
 
-fn number(input: &mut &str) -> ModalResult<f64> {
-    float(input)
+fn arith_factor(input: &mut &str) -> ModalResult<Expr> {
+    alt((
+        number.map(Expr::Number),
+        glob_call(agg_func).map(|(func, glob)| Expr::Aggregate { func, glob }),
+        delta_call.map(Expr::Delta),
+        path.map(Expr::Path),
+        delimited(('(', multispace0), arith, (multispace0, ')')),
+    ))
+    .parse_next(input)
+}
+
+fn arith_binary<'i>(
+    operand: fn(&mut &'i str) -> ModalResult<Expr>,
+    ops: [(char, ArithOp); 2],
+) -> impl Parser<&'i str, Expr, ErrMode<ContextError>> {
+    move |input: &mut &'i str| {
+        let first = operand(input)?;
+        let op = alt((ops[0].0.value(ops[0].1), ops[1].0.value(ops[1].1)));
+        let rest: Vec<(ArithOp, Expr)> =
+            repeat(0.., (delimited(multispace0, op, multispace0), operand)).parse_next(input)?;
+        Ok(rest.into_iter().fold(first, |lhs, (op, rhs)| Expr::Binary {
+            op,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        }))
+    }
+}
+
+fn arith_product(input: &mut &str) -> ModalResult<Expr> {
+    arith_binary(arith_factor, [('*', ArithOp::Mul), ('/', ArithOp::Div)]).parse_next(input)
+}
+
+fn arith(input: &mut &str) -> ModalResult<Expr> {
+    arith_binary(arith_product, [('+', ArithOp::Add), ('-', ArithOp::Sub)]).parse_next(input)
+}
+
+enum TermRhs {
+    Arith(Expr),
+    Value(Value),
 }
 
-fn rhs(input: &mut &str) -> ModalResult<Rhs> {
+fn term_rhs(input: &mut &str) -> ModalResult<TermRhs> {
     alt((
-        bool_literal.map(|b| Rhs::Value(Value::Bool(b))),
-        number.map(|n| Rhs::Value(Value::Number(n))),
-        quoted_string.map(|s| Rhs::Value(Value::Str(s))),
-        path.map(Rhs::Path),
+        bool_literal.map(|b| TermRhs::Value(Value::Bool(b))),
+        quoted_string.map(|s| TermRhs::Value(Value::Str(s))),
+        severity_literal.map(|s| TermRhs::Value(Value::Severity(s))),
+        quantity.map(|q| TermRhs::Value(Value::Quantity(q))),
+        arith.map(TermRhs::Arith),
     ))
     .parse_next(input)
 }
 
+/// Parse one comparison. Plain `path op value|path` terms keep the
+/// `Predicate::Cmp` shape; Bool, String and unit-suffixed literals are only
+/// accepted there.
 fn term(input: &mut &str) -> ModalResult<Predicate> {
-    let (lhs, _, op, _, rhs) = (path, multispace0, cmp_op, multispace0, rhs).parse_next(input)?;
-    Ok(Predicate::Cmp { path: lhs, op, rhs })
+    let (lhs, _, op, _, rhs) = (arith, multispace0, cmp_op, multispace0, term_rhs).parse_next(input)?;
+    match (lhs, rhs) {
+        (Expr::Path(path), TermRhs::Value(v)) => Ok(Predicate::Cmp {
+            path,
+            op,
+            rhs: Rhs::Value(v),
+        }),
+        (Expr::Path(path), TermRhs::Arith(Expr::Number(n))) => Ok(Predicate::Cmp {
+            path,
+            op,
+            rhs: Rhs::Value(Value::Number(n)),
+        }),
+        (Expr::Path(path), TermRhs::Arith(Expr::Path(p))) => Ok(Predicate::Cmp {
+            path,
+            op,
+            rhs: Rhs::Path(p),
+        }),
+        (lhs, TermRhs::Arith(rhs)) => Ok(Predicate::Arith { lhs, op, rhs }),
+        (_, TermRhs::Value(_)) => {
+            let mut e = ContextError::new();
+            e.push(StrContext::Label("comparison"));
+            e.push(StrContext::Expected(StrContextValue::Description(
+                "a plain number or path: arithmetic expressions do not compare with units, bools or strings",
+            )));
+            Err(ErrMode::Cut(e))
+        }
+    }
 }
 
-fn infix_op(input: &mut &str) -> ModalResult<bool> {
-    delimited(
-        multispace1,
-        ident.verify_map(|s: &str| match s.to_ascii_uppercase().as_str() {
-            "AND" => Some(true),
-            "OR" => Some(false),
-            _ => None,
-        }),
-        multispace1,
+/// Parse `any(glob) op rhs` / `all(glob) op rhs`. The right-hand side takes
+/// the same forms as in `Predicate::Cmp`: a literal or a single path.
+fn quantified(input: &mut &str) -> ModalResult<Predicate> {
+    let ((quantifier, glob), _, op, _, rhs) =
+        (glob_call(quantifier), multispace0, cmp_op, multispace0, term_rhs).parse_next(input)?;
+    let rhs = match rhs {
+        TermRhs::Value(v) => Rhs::Value(v),
+        TermRhs::Arith(Expr::Number(n)) => Rhs::Value(Value::Number(n)),
+        TermRhs::Arith(Expr::Path(p)) => Rhs::Path(p),
+        TermRhs::Arith(_) => return Err(ErrMode::Cut(ContextError::new())),
+    };
+    Ok(Predicate::Quantified {
+        quantifier,
+        glob,
+        op,
+        rhs,
+    })
+}
+
+fn regex_literal(input: &mut &str) -> ModalResult<Regex> {
+    cut_err(quoted_string.try_map(|s| Regex::new(&s))).parse_next(input)
+}
+
+/// Parse `path =~ "re"` / `path !~ "re"`. Regexes are unanchored: `=~` holds
+/// when the pattern matches anywhere in the value.
+fn text_match(input: &mut &str) -> ModalResult<Predicate> {
+    let (path, _, negated, _) = (
+        path,
+        multispace0,
+        alt(("=~".value(false), "!~".value(true))),
+        multispace0,
+    )
+        .parse_next(input)?;
+    let re = regex_literal.parse_next(input)?;
+    let op = if negated {
+        TextOp::NotMatches(re)
+    } else {
+        TextOp::Matches(re)
+    };
+    Ok(Predicate::Text { path, op })
+}
+
+/// Parse `starts_with(path, "s")`, `ends_with(path, "s")` or
+/// `contains(path, "s")`.
+fn text_fn(input: &mut &str) -> ModalResult<Predicate> {
+    let name = ident
+        .verify(|s: &&str| {
+            matches!(
+                s.to_ascii_lowercase().as_str(),
+                "starts_with" | "ends_with" | "contains"
+            )
+        })
+        .parse_next(input)?;
+    let (_, _, _, path, _, _, _, needle, _, _) = (
+        multispace0,
+        '(',
+        multispace0,
+        path,
+        multispace0,
+        ',',
+        multispace0,
+        quoted_string,
+        multispace0,
+        ')',
+    )
+        .parse_next(input)?;
+    let op = match name.to_ascii_lowercase().as_str() {
+        "starts_with" => TextOp::StartsWith(needle),
+        "ends_with" => TextOp::EndsWith(needle),
+        _ => TextOp::Contains(needle),
+    };
+    Ok(Predicate::Text { path, op })
+}
+
+fn sustain(input: &mut &str) -> ModalResult<Sustain> {
+    let (n, percent) = (number, opt((multispace0, '%'))).parse_next(input)?;
+    match percent {
+        Some(_) if n > 0.0 && n <= 100.0 => Ok(Sustain::Percent(n)),
+        None if n >= 1.0 && n.fract() == 0.0 => Ok(Sustain::Samples(n as usize)),
+        _ => Err(ErrMode::Cut(ContextError::new())),
+    }
+}
+
+/// Parse `sustained(expr, 80%)` or `sustained(expr, 3)`.
+fn sustained(input: &mut &str) -> ModalResult<Predicate> {
+    let (_, _, _, _, inner, _, _, _, min, _, _) = (
+        keyword("sustained"),
+        multispace0,
+        '(',
+        multispace0,
+        expr,
+        multispace0,
+        ',',
+        multispace0,
+        sustain,
+        multispace0,
+        ')',
+    )
+        .parse_next(input)?;
+    Ok(Predicate::Sustained {
+        inner: Box::new(inner),
+        min,
+    })
+}
+
+/// Parse `together(a, b, 80%)` or `leads(a, b, 3)`.
+fn temporal(input: &mut &str) -> ModalResult<Predicate> {
+    let name = alt((keyword("together"), keyword("leads"))).parse_next(input)?;
+    let together = name.eq_ignore_ascii_case("together");
+    let comma = || (multispace0, ',', multispace0);
+    let (_, _, _, first, _, second, _, min, _, _) = (
+        multispace0,
+        '(',
+        multispace0,
+        expr,
+        comma(),
+        expr,
+        comma(),
+        sustain,
+        multispace0,
+        ')',
     )
+        .parse_next(input)?;
+    let relation = match (together, min) {
+        (true, min) => Relation::Together(min),
+        (false, Sustain::Samples(n)) => Relation::Leads(n),
+        (false, Sustain::Percent(_)) => return Err(ErrMode::Cut(ContextError::new())),
+    };
+    Ok(Predicate::Temporal {
+        relation,
+        first: Box::new(first),
+        second: Box::new(second),
+    })
+}
+
+fn keyword<'i>(kw: &'static str) -> impl Parser<&'i str, &'i str, ErrMode<ContextError>> {
+    ident.verify(move |s: &&str| s.eq_ignore_ascii_case(kw))
+}
+
+fn binary_op<'i>(kw: &'static str) -> impl Parser<&'i str, &'i str, ErrMode<ContextError>> {
+    delimited(multispace0, keyword(kw), multispace0)
+}
+
+fn parenthesized(input: &mut &str) -> ModalResult<Predicate> {
+    delimited(('(', multispace0), expr, (multispace0, ')')).parse_next(input)
+}
+
+fn unary(input: &mut &str) -> ModalResult<Predicate> {
+    alt((
+        preceded((keyword("NOT"), alt((multispace1, peek("(")))), unary).map(|p| Predicate::Not(Box::new(p))),
+        parenthesized,
+        sustained,
+        temporal,
+        quantified,
+        text_fn,
+        text_match,
+        term,
+    ))
     .parse_next(input)
 }
 
+fn conj(input: &mut &str) -> ModalResult<Predicate> {
+    let first = unary.parse_next(input)?;
+    let rest: Vec<Predicate> = repeat(0.., preceded(binary_op("AND"), unary)).parse_next(input)?;
+    Ok(rest
+        .into_iter()
+        .fold(first, |left, right| Predicate::And(Box::new(left), Box::new(right))))
+}
+
 fn expr(input: &mut &str) -> ModalResult<Predicate> {
-    let first = term.parse_next(input)?;
-    let rest: Vec<(bool, Predicate)> = repeat(0.., (infix_op, term)).parse_next(input)?;
-    Ok(rest.into_iter().fold(first, |left, (is_and, right)| {
-        if is_and {
-            Predicate::And(Box::new(left), Box::new(right))
-        } else {
-            Predicate::Or(Box::new(left), Box::new(right))
-        }
-    }))
+    let first = conj.parse_next(input)?;
+    let rest: Vec<Predicate> = repeat(0.., preceded(binary_op("OR"), conj)).parse_next(input)?;
+    Ok(rest
+        .into_iter()
+        .fold(first, |left, right| Predicate::Or(Box::new(left), Box::new(right))))
 }
 
 // --- Rule engine -------------------------------------------------------------
@@ -365,27 +1609,43 @@ impl RuleEngine {
         let mut findings = Vec::new();
         let mut checked_ok: HashSet<String> = HashSet::new();
         for rule in &self.rules {
-            if rule.when.evaluate(&index, ctx) {
-                let evidence = rule
-                    .evidence_ids
+            if let Some((when, severity)) = rule.matching_tier(&index, ctx) {
+                // Declared evidence first, then the concrete signals behind any
+                // glob terms so the finding names the device that tripped it.
+                let matches = when.matched_signals(&index, ctx);
+                let mut ids = expand_evidence_ids(&rule.evidence_ids, &index);
+                for m in &matches {
+                    if !ids.contains(&m.id) {
+                        ids.push(m.id.clone());
+                    }
+                }
+                let evidence = ids
                     .iter()
                     .filter_map(|sid| evidence_for(sid, &index, ctx, source_map))
-                    .collect();
+                    .map(|mut e| {
+                        e.detail = matches
+                            .iter()
+                            .find(|m| m.id == e.signal_id)
+                            .and_then(|m| m.detail.clone());
+                        e
+                    })
+                    .collect::<Vec<_>>();
+                let vars = template::TemplateContext::new(&index, ctx, &evidence, &matches);
                 findings.push(Finding {
                     id: rule.id.clone(),
                     kind: FindingKind::Rule,
-                    severity: rule.severity,
-                    summary: rule.summary.clone(),
+                    severity,
+                    summary: vars.render(&rule.summary),
                     evidence,
-                    suggest: rule.suggest.clone(),
+                    suggest: rule.suggest.iter().map(|s| vars.render(s)).collect(),
                 });
             } else {
                 let present_ids: std::collections::HashSet<&str> = signals.iter().map(|s| s.id.as_str()).collect();
                 // Only mark a signal as checked-ok if it was actually present
                 // in the signal slice; absent signals were never evaluated.
-                for sid in &rule.evidence_ids {
-                    if present_ids.contains(sid.as_str()) || sid == "host.cpu_count" {
-                        checked_ok.insert(sid.clone());
+                for sid in expand_evidence_ids(&rule.evidence_ids, &index) {
+                    if present_ids.contains(sid.as_str()) || ctx.host_value(&sid).is_some() {
+                        checked_ok.insert(sid);
                     }
                 }
             }
@@ -396,39 +1656,151 @@ impl RuleEngine {
         (findings, checked_ok)
     }
 
+    /// Rules that did not fire but came within `margin` percent of a
+    /// threshold, or had most of their comparisons hold; see `near_miss`.
+    /// For a multi-level rule, the least severe tier that came close.
+    pub fn near_misses(&self, signals: &[Signal], ctx: &CollectCtx, margin: f64) -> Vec<NearMiss> {
+        let index = SignalIndex::build(signals);
+        self.rules
+            .iter()
+            .filter(|rule| rule.matching_tier(&index, ctx).is_none())
+            .filter_map(|rule| {
+                rule.tiers()
+                    .filter_map(|(when, severity)| {
+                        near_miss::near_miss(&rule.id, FindingKind::Rule, severity, when, &index, ctx, margin)
+                    })
+                    .reduce(|best, miss| {
+                        if miss.severity.rank() > best.severity.rank() {
+                            miss
+                        } else {
+                            best
+                        }
+                    })
+            })
+            .collect()
+    }
+
+    /// The `inhibits` declarations of every rule that has any, by rule ID.
+    pub fn inhibits(&self) -> HashMap<String, Vec<String>> {
+        self.rules
+            .iter()
+            .filter(|r| !r.inhibits.is_empty())
+            .map(|r| (r.id.clone(), r.inhibits.clone()))
+            .collect()
+    }
+
+    /// Numeric thresholds per signal across all rules and tiers. Each entry
+    /// carries the most severe threshold plus, in `tiers`, every distinct
+    /// threshold from least to most severe.
     pub fn signal_thresholds(&self) -> HashMap<String, ThresholdInfo> {
-        let mut map = HashMap::new();
+        let mut tiers: HashMap<String, Vec<ThresholdTier>> = HashMap::new();
         for rule in &self.rules {
-            extract_cmp_thresholds(&rule.when, rule.severity, &mut map);
+            for (when, severity) in rule.tiers() {
+                extract_cmp_thresholds(when, severity, false, &mut tiers);
+            }
         }
-        map
+        tiers
+            .into_iter()
+            .map(|(signal_id, mut tiers)| {
+                // First of the most severe, as the strongest tier is reported
+                // on its own by templates that predate `tiers`.
+                let top = tiers
+                    .iter()
+                    .fold(&tiers[0], |best, t| {
+                        if t.severity.rank() < best.severity.rank() {
+                            t
+                        } else {
+                            best
+                        }
+                    })
+                    .clone();
+                tiers.sort_by_key(|t| std::cmp::Reverse(t.severity.rank()));
+                let info = ThresholdInfo {
+                    severity: top.severity,
+                    op: top.op,
+                    value: top.value,
+                    tiers,
+                };
+                (signal_id, info)
+            })
+            .collect()
     }
 }
 
-fn extract_cmp_thresholds(pred: &Predicate, severity: Severity, map: &mut HashMap<String, ThresholdInfo>) {
+/// `path`, then `path` without its last one and two segments: the IDs a
+/// path with a stats suffix may read.
+fn signal_prefixes(path: &[String]) -> impl Iterator<Item = &[String]> {
+    (path.len().saturating_sub(2).max(1)..=path.len())
+        .rev()
+        .map(move |n| &path[..n])
+}
+
+/// Whether `path` ends in a statistic without the signal's unit: a z-score,
+/// a coefficient of variation or a sample count.
+fn is_unitless_stat(path: &[String]) -> bool {
+    match path {
+        [.., baseline, z] if baseline == "baseline" && z == "z" => true,
+        [_, .., stat] => stat == "cv" || stat == "count",
+        _ => false,
+    }
+}
+
+/// Catalog unit of the signal `path` reads, like `SignalIndex::unit_of`.
+fn catalog_unit(path: &[String]) -> Option<Unit> {
+    if let Some(spec) = crate::signal::catalog::lookup(&path.join(".")) {
+        return Some(spec.unit);
+    }
+    if is_unitless_stat(path) {
+        return Some(Unit::None);
+    }
+    let spec = signal_prefixes(path).find_map(|prefix| crate::signal::catalog::lookup(&prefix.join(".")))?;
+    Some(spec.unit)
+}
+
+/// Collect numeric thresholds from `pred`. `negated` is true below an odd
+/// number of `NOT`s, in which case the reported operator is inverted so the
+/// threshold still reads as "fires when".
+fn extract_cmp_thresholds(
+    pred: &Predicate,
+    severity: Severity,
+    negated: bool,
+    map: &mut HashMap<String, Vec<ThresholdTier>>,
+) {
     match pred {
         Predicate::Cmp { path, op, rhs } => {
-            if let Rhs::Value(Value::Number(v)) = rhs {
-                let signal_id = path.join(".");
-                let entry = map.entry(signal_id).or_insert(ThresholdInfo {
+            let value = match rhs {
+                Rhs::Value(Value::Number(v)) => Some(*v),
+                Rhs::Value(Value::Quantity(q)) => catalog_unit(path).and_then(|unit| q.convert(unit)),
+                _ => None,
+            };
+            if let Some(value) = value {
+                let op = if negated { op.negate() } else { *op };
+                let tier = ThresholdTier {
                     severity,
-                    op: op_to_str(*op).to_string(),
-                    value: *v,
-                });
-                // Replace if the new severity is higher (lower rank = higher severity).
-                if severity.rank() < entry.severity.rank() {
-                    *entry = ThresholdInfo {
-                        severity,
-                        op: op_to_str(*op).to_string(),
-                        value: *v,
-                    };
+                    op: op_to_str(op).to_string(),
+                    value,
+                };
+                let tiers = map.entry(path.join(".")).or_default();
+                if !tiers.contains(&tier) {
+                    tiers.push(tier);
                 }
             }
         }
         Predicate::And(a, b) | Predicate::Or(a, b) => {
-            extract_cmp_thresholds(a, severity, map);
-            extract_cmp_thresholds(b, severity, map);
+            extract_cmp_thresholds(a, severity, negated, map);
+            extract_cmp_thresholds(b, severity, negated, map);
         }
+        Predicate::Not(a) => extract_cmp_thresholds(a, severity, !negated, map),
+        Predicate::Sustained { inner, .. } => extract_cmp_thresholds(inner, severity, negated, map),
+        Predicate::Temporal { first, second, .. } => {
+            extract_cmp_thresholds(first, severity, negated, map);
+            extract_cmp_thresholds(second, severity, negated, map);
+        }
+        // Thresholds on derived values (ratios, sums) and on globs do not
+        // belong to any single signal ID, so they are not reported per signal.
+        Predicate::Arith { .. } | Predicate::Quantified { .. } => {}
+        // String tests have no numeric threshold.
+        Predicate::Text { .. } => {}
     }
 }
 
@@ -443,6 +1815,21 @@ fn op_to_str(op: Op) -> &'static str {
     }
 }
 
+/// Replace glob entries in `evidence_ids` with the IDs of the signals they
+/// match; plain IDs pass through unchanged.
+fn expand_evidence_ids(evidence_ids: &[String], index: &SignalIndex<'_>) -> Vec<String> {
+    let mut ids = Vec::new();
+    for id in evidence_ids {
+        if is_glob(id) {
+            let glob: Vec<String> = id.split('.').map(str::to_string).collect();
+            ids.extend(index.matching(&glob).into_iter().map(|s| s.id.clone()));
+        } else {
+            ids.push(id.clone());
+        }
+    }
+    ids
+}
+
 /// Look up the observed value for an `evidence_ids` entry. Resolves both
 /// signal IDs and `host.*` paths (which come from `CollectCtx`). Entries that
 /// resolve to nothing are silently skipped.
@@ -458,16 +1845,15 @@ fn evidence_for(
             signal_id: id.to_string(),
             observed: signal.value.clone(),
             source_commands,
+            detail: None,
         });
     }
-    if id == "host.cpu_count" {
-        return Some(Evidence {
-            signal_id: id.to_string(),
-            observed: SignalValue::I64(ctx.cpu_count as i64),
-            source_commands,
-        });
-    }
-    None
+    ctx.host_value(id).map(|observed| Evidence {
+        signal_id: id.to_string(),
+        observed,
+        source_commands,
+        detail: None,
+    })
 }
 
 // --- TOML loader -------------------------------------------------------------
@@ -476,14 +1862,28 @@ fn evidence_for(
 struct RuleFile {
     #[serde(default)]
     rule: Vec<RuleToml>,
+    #[serde(default)]
+    disable: Vec<DisableToml>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DisableToml {
+    ids: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
 struct RuleToml {
     id: String,
-    when: String,
-    severity: String,
-    summary: String,
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+    #[serde(default)]
+    when: Option<String>,
+    #[serde(default)]
+    severity: Option<String>,
+    #[serde(default)]
+    level: Vec<LevelToml>,
+    #[serde(default)]
+    summary: Option<String>,
     #[serde(default)]
     evidence: Vec<String>,
     #[serde(default)]
@@ -492,26 +1892,71 @@ struct RuleToml {
     description: Option<String>,
     #[serde(default)]
     links: Vec<String>,
+    #[serde(default)]
+    inhibits: Vec<String>,
+    #[serde(default)]
+    test: Vec<fixture::TestToml>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct LevelToml {
+    when: String,
+    severity: String,
 }
 
 impl RuleToml {
     fn into_rule(self) -> Result<Rule> {
-        let when = Predicate::parse(&self.when).map_err(|e| Error::Predicate(format!("rule '{}': {}", self.id, e)))?;
-        let severity =
-            parse_severity(&self.severity).map_err(|e| Error::Predicate(format!("rule '{}': {}", self.id, e)))?;
+        let err = |e: String| Error::Predicate(format!("rule '{}': {}", self.id, e));
+        let summary = self.summary.ok_or_else(|| err("missing `summary`".to_string()))?;
+        let top = match (self.when, self.severity) {
+            (Some(when), Some(severity)) => Some(LevelToml { when, severity }),
+            (None, None) => None,
+            _ => return Err(err("`when` and `severity` must be given together".to_string())),
+        };
+        let mut levels = top
+            .into_iter()
+            .chain(self.level)
+            .map(|l| {
+                Ok(RuleLevel {
+                    when: Predicate::parse(&l.when).map_err(|e| err(e.to_string()))?,
+                    severity: parse_severity(&l.severity).map_err(err)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if levels.is_empty() {
+            return Err(err(
+                "needs `when` and `severity` or at least one [[rule.level]]".to_string()
+            ));
+        }
+        let first = levels.remove(0);
         Ok(Rule {
             id: self.id,
-            when,
-            severity,
-            summary: self.summary,
+            when: first.when,
+            severity: first.severity,
+            summary,
             evidence_ids: self.evidence,
             suggest: self.suggest,
             description: self.description,
             links: self.links,
+            levels,
+            inhibits: self.inhibits,
         })
     }
 }
 
+/// The DSL spelling of `severity`: `info`, `warn` or `crit`.
+pub(crate) fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "info",
+        Severity::Warn => "warn",
+        Severity::Crit => "crit",
+    }
+}
+
 fn parse_severity(s: &str) -> std::result::Result<Severity, String> {
     match s.to_ascii_lowercase().as_str() {
         "info" => Ok(Severity::Info),
@@ -525,9 +1970,70 @@ fn parse_severity(s: &str) -> std::result::Result<Severity, String> {
 /// severity errors are returned as the first failure; the caller (see
 /// `RulesLoader`) is responsible for converting them into `warn` findings so
 /// one bad file does not poison the rest.
+///
+/// Rules marked `enabled = false` and ids listed in `[[disable]]` tables are
+/// not returned; `RulesLoader` reads those through `parse_rules_file`.
 pub fn parse_rules_toml(s: &str) -> Result<Vec<Rule>> {
+    parse_rules_file(s).map(|parsed| parsed.rules)
+}
+
+/// Like `parse_rules_toml`, but also returns the `[[rule.test]]` blocks of
+/// the enabled rules.
+pub fn parse_rules_with_tests(s: &str) -> Result<(Vec<Rule>, Vec<RuleTest>)> {
+    parse_rules_file(s).map(|parsed| (parsed.rules, parsed.tests))
+}
+
+/// The contents of one rule file: the rules it defines, their embedded tests
+/// and the ids it turns off (`[[disable]] ids = [...]` or a `[[rule]]` with
+/// `enabled = false`).
+struct ParsedRules {
+    rules: Vec<Rule>,
+    tests: Vec<RuleTest>,
+    disabled: Vec<String>,
+}
+
+fn parse_rules_file(s: &str) -> Result<ParsedRules> {
     let parsed: RuleFile = toml::from_str(s).map_err(|e| Error::Predicate(e.to_string()))?;
-    parsed.rule.into_iter().map(|r| r.into_rule()).collect()
+    let mut disabled: Vec<String> = parsed.disable.into_iter().flat_map(|d| d.ids).collect();
+    let mut rules = Vec::new();
+    let mut tests = Vec::new();
+    for mut rule in parsed.rule {
+        if rule.enabled {
+            for test in std::mem::take(&mut rule.test) {
+                tests.push(test.into_test(&rule.id)?);
+            }
+            rules.push(rule.into_rule()?);
+        } else {
+            disabled.push(rule.id);
+        }
+    }
+    Ok(ParsedRules { rules, tests, disabled })
+}
+
+/// Where an effective rule was defined.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RuleSource {
+    /// Shipped with the binary.
+    Builtin,
+    /// Read from a user rule file; `overrides_builtin` is set when it
+    /// replaced a built-in rule with the same id.
+    User { path: PathBuf, overrides_builtin: bool },
+}
+
+impl std::fmt::Display for RuleSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleSource::Builtin => write!(f, "built-in"),
+            RuleSource::User {
+                path,
+                overrides_builtin: false,
+            } => write!(f, "{}", path.display()),
+            RuleSource::User {
+                path,
+                overrides_builtin: true,
+            } => write!(f, "{} (overrides built-in)", path.display()),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -540,6 +2046,11 @@ pub struct RulesLoader {
 pub struct RulesLoadResult {
     pub rules: Vec<Rule>,
     pub load_findings: Vec<Finding>,
+    /// Where each rule id was last defined, including ids that were
+    /// subsequently disabled.
+    pub sources: HashMap<String, RuleSource>,
+    /// Disabled rule ids and the file that disabled them.
+    pub disabled: HashMap<String, PathBuf>,
 }
 
 impl RulesLoader {
@@ -560,55 +2071,119 @@ impl RulesLoader {
     /// Load built-in rules first, then user rules from the configured dir
     /// (if any). Per SDD §89: malformed user files do NOT poison built-ins;
     /// each malformed file becomes a `warn` finding.
+    ///
+    /// Files are read in path order. A user rule whose id is already loaded
+    /// replaces the earlier definition in place; disabled ids are dropped
+    /// once every file has been read, so a disable wins regardless of order.
     pub fn load(self) -> RulesLoadResult {
         let mut rules = self.builtins;
+        let mut sources: HashMap<String, RuleSource> =
+            rules.iter().map(|r| (r.id.clone(), RuleSource::Builtin)).collect();
+        let mut disabled = HashMap::new();
         let mut load_findings = Vec::new();
         if let Some(dir) = self.user_dir {
-            match std::fs::read_dir(&dir) {
-                Ok(entries) => {
-                    let mut paths: Vec<PathBuf> = entries
-                        .filter_map(|e| e.ok().map(|e| e.path()))
-                        .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("toml"))
-                        .collect();
-                    paths.sort();
-                    for path in paths {
-                        match std::fs::read_to_string(&path) {
-                            Ok(content) => match parse_rules_toml(&content) {
-                                Ok(mut more) => rules.append(&mut more),
-                                Err(e) => load_findings.push(Finding {
-                                    id: "rules.malformed_user_file".to_string(),
-                                    kind: FindingKind::Rule,
-                                    severity: Severity::Warn,
-                                    summary: format!("skipped malformed rule file {}: {}", path.display(), e),
-                                    evidence: vec![],
-                                    suggest: vec![format!("Check the TOML syntax in {}", path.display())],
-                                }),
-                            },
-                            Err(e) => load_findings.push(Finding {
-                                id: "rules.user_file_unreadable".to_string(),
-                                kind: FindingKind::Rule,
-                                severity: Severity::Warn,
-                                summary: format!("skipped unreadable rule file {}: {}", path.display(), e),
-                                evidence: vec![],
-                                suggest: vec![format!("Check file permissions on {}", path.display())],
-                            }),
-                        }
-                    }
+            load_findings = load_user_files(&dir, FindingKind::Rule, parse_rules_file, |parsed, path| {
+                for rule in parsed.rules {
+                    merge_user_rule(&mut rules, &mut sources, rule, path);
                 }
-                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-                    // Absent directory is not an error.
+                for id in parsed.disabled {
+                    disabled.insert(id, path.to_path_buf());
                 }
-                Err(e) => load_findings.push(Finding {
-                    id: "rules.user_dir_unreadable".to_string(),
-                    kind: FindingKind::Rule,
-                    severity: Severity::Warn,
-                    summary: format!("could not read rules directory {}: {}", dir.display(), e),
-                    evidence: vec![],
-                    suggest: vec![format!("Check directory permissions on {}", dir.display())],
-                }),
-            }
+            });
+        }
+        rules.retain(|r| !disabled.contains_key(&r.id));
+        RulesLoadResult {
+            rules,
+            load_findings,
+            sources,
+            disabled,
+        }
+    }
+}
+
+/// The `*.toml` files in `dir`, in path order — the order `RulesLoader`
+/// reads them in.
+pub fn rule_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("toml"))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Parse every file of `rule_files(dir)` with `parse` and hand the result
+/// to `apply`, in path order. `kind` says whether the files hold rules or
+/// patterns. Malformed and unreadable files, and an unreadable directory,
+/// become `warn` findings; an absent directory is skipped.
+pub(crate) fn load_user_files<T, E: std::fmt::Display>(
+    dir: &Path,
+    kind: FindingKind,
+    parse: impl Fn(&str) -> std::result::Result<T, E>,
+    mut apply: impl FnMut(T, &Path),
+) -> Vec<Finding> {
+    let (prefix, noun) = match kind {
+        FindingKind::Pattern => ("patterns", "pattern"),
+        _ => ("rules", "rule"),
+    };
+    let warn = |id: &str, summary: String, suggest: String| Finding {
+        id: format!("{}.{}", prefix, id),
+        kind,
+        severity: Severity::Warn,
+        summary,
+        evidence: vec![],
+        suggest: vec![suggest],
+    };
+    let paths = match rule_files(dir) {
+        Ok(paths) => paths,
+        // Absent directory is not an error.
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Vec::new(),
+        Err(e) => {
+            return vec![warn(
+                "user_dir_unreadable",
+                format!("could not read {} directory {}: {}", prefix, dir.display(), e),
+                format!("Check directory permissions on {}", dir.display()),
+            )];
+        }
+    };
+    let mut findings = Vec::new();
+    for path in paths {
+        match std::fs::read_to_string(&path).map(|content| parse(&content)) {
+            Ok(Ok(parsed)) => apply(parsed, &path),
+            Ok(Err(e)) => findings.push(warn(
+                "malformed_user_file",
+                format!("skipped malformed {} file {}: {}", noun, path.display(), e),
+                format!("Check the TOML syntax in {}", path.display()),
+            )),
+            Err(e) => findings.push(warn(
+                "user_file_unreadable",
+                format!("skipped unreadable {} file {}: {}", noun, path.display(), e),
+                format!("Check file permissions on {}", path.display()),
+            )),
         }
-        RulesLoadResult { rules, load_findings }
+    }
+    findings
+}
+
+fn merge_user_rule(rules: &mut Vec<Rule>, sources: &mut HashMap<String, RuleSource>, rule: Rule, path: &Path) {
+    let overrides_builtin = matches!(
+        sources.get(&rule.id),
+        Some(RuleSource::Builtin)
+            | Some(RuleSource::User {
+                overrides_builtin: true,
+                ..
+            })
+    );
+    sources.insert(
+        rule.id.clone(),
+        RuleSource::User {
+            path: path.to_path_buf(),
+            overrides_builtin,
+        },
+    );
+    match rules.iter_mut().find(|r| r.id == rule.id) {
+        Some(existing) => *existing = rule,
+        None => rules.push(rule),
     }
 }
 
@@ -617,7 +2192,7 @@ mod tests {
     use chrono::Local;
 
     use super::*;
-    use crate::signal::{Signal, SignalValue, Unit};
+    use crate::signal::{Labels, Signal, SignalValue, Unit};
 
     fn ctx() -> CollectCtx {
         CollectCtx {
@@ -626,6 +2201,7 @@ mod tests {
             cgroup_path: None,
             baseline: None,
             cpu_count: 4,
+            uname: None,
         }
     }
 
@@ -638,6 +2214,7 @@ mod tests {
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         }
     }
 
@@ -675,8 +2252,8 @@ mod tests {
 
     #[test]
     fn predicate_and_or_chained_left_associative() {
-        // Per SDD §333: expr ::= term (("AND" | "OR") term)* — left-associative.
-        // `a > 1 AND b > 1 OR c > 100` parses as `(a > 1 AND b > 1) OR c > 100`.
+        // AND binds tighter than OR, so `a > 1 AND b > 1 OR c > 100` parses as
+        // `(a > 1 AND b > 1) OR c > 100`.
         // a=5, b=5, c=5 → (true AND true) OR false = true.
         let p = Predicate::parse("a > 1 AND b > 1 OR c > 100").expect("parse");
         let signals = vec![signal("a", 5.0), signal("b", 5.0), signal("c", 5.0)];
@@ -684,6 +2261,860 @@ mod tests {
         assert!(p.evaluate(&idx, &ctx()));
     }
 
+    #[test]
+    fn predicate_and_binds_tighter_than_or() {
+        // `a > 1 OR b > 1 AND c > 100` is `a > 1 OR (b > 1 AND c > 100)`.
+        // a=5, b=5, c=5 → true OR (true AND false) = true. A flat left fold
+        // would yield (true OR true) AND false = false.
+        let p = Predicate::parse("a > 1 OR b > 1 AND c > 100").expect("parse");
+        let signals = vec![signal("a", 5.0), signal("b", 5.0), signal("c", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_parentheses_regroup() {
+        let p = Predicate::parse("(a > 1 OR b > 1) AND c > 100").expect("parse");
+        let signals = vec![signal("a", 5.0), signal("b", 5.0), signal("c", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(!p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_not_inverts_and_binds_tighter_than_and() {
+        let p = Predicate::parse("NOT a > 1 AND b > 1").expect("parse");
+        assert_eq!(
+            p,
+            Predicate::And(
+                Box::new(Predicate::Not(Box::new(Predicate::parse("a > 1").unwrap()))),
+                Box::new(Predicate::parse("b > 1").unwrap()),
+            )
+        );
+        let signals = vec![signal("a", 0.0), signal("b", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_not_over_parenthesized_or_with_absent_signals() {
+        // Absent signals make their comparisons false, so NOT (...) is true.
+        let p =
+            Predicate::parse("mem.free_pct < 10 AND NOT (dmesg.oom_count > 0 OR swap.used_mb > 0)").expect("parse");
+        let signals = vec![signal("mem.free_pct", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(p.evaluate(&idx, &ctx()));
+
+        let signals = vec![signal("mem.free_pct", 5.0), signal("swap.used_mb", 512.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(!p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_keywords_are_case_insensitive_and_parens_tolerate_whitespace() {
+        let p = Predicate::parse("not( a > 1 )and ( b > 1 )").expect("parse");
+        let signals = vec![signal("a", 0.0), signal("b", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_unbalanced_parentheses_rejected() {
+        assert!(Predicate::parse("(a > 1 AND b > 1").is_err());
+        assert!(Predicate::parse("a > 1)").is_err());
+        assert!(Predicate::parse("NOT").is_err());
+    }
+
+    #[test]
+    fn predicate_first_segment_named_like_a_keyword_is_a_path() {
+        let p = Predicate::parse("not.something > 1").expect("parse");
+        assert_eq!(p.signal_ids(), vec!["not.something"]);
+        let p = Predicate::parse("NOT Not.ready > 1 AND or.x > 1").expect("parse");
+        assert_eq!(p.signal_ids(), vec!["Not.ready", "or.x"]);
+        let signals = vec![signal("Not.ready", 0.0), signal("or.x", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_keyword_allowed_after_first_path_segment() {
+        let p = Predicate::parse("does.not.exist > 0 OR a.and > 1").expect("parse");
+        assert_eq!(p.signal_ids(), vec!["does.not.exist", "a.and"]);
+    }
+
+    #[test]
+    fn predicate_signal_ids_include_negated_terms() {
+        let p = Predicate::parse("a > 1 AND NOT (b > 1 OR c > d)").expect("parse");
+        assert_eq!(p.signal_ids(), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn signal_thresholds_invert_operator_under_not() {
+        let rule = Rule {
+            id: "negated".to_string(),
+            when: Predicate::parse("NOT (a > 5)").expect("parse"),
+            severity: Severity::Warn,
+            summary: String::new(),
+            evidence_ids: vec![],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let thresholds = RuleEngine::new(vec![rule]).signal_thresholds();
+        assert_eq!(thresholds["a"].op, "<=");
+        assert_eq!(thresholds["a"].value, 5.0);
+    }
+
+    #[test]
+    fn predicate_ratio_of_two_signals() {
+        let p = Predicate::parse("cgroup.memory_bytes / cgroup.memory_limit_bytes > 0.9").expect("parse");
+        let signals = vec![
+            signal("cgroup.memory_bytes", 950.0),
+            signal("cgroup.memory_limit_bytes", 1000.0),
+        ];
+        let idx = SignalIndex::build(&signals);
+        assert!(p.evaluate(&idx, &ctx()));
+        assert_eq!(p.signal_ids(), vec!["cgroup.memory_bytes", "cgroup.memory_limit_bytes"]);
+    }
+
+    #[test]
+    fn predicate_arith_resolves_host_paths_from_ctx() {
+        // ctx().cpu_count == 4 → 9 / 4 = 2.25.
+        let p = Predicate::parse("host.load_avg_1m / host.cpu_count > 2").expect("parse");
+        let signals = vec![signal("host.load_avg_1m", 9.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_arith_precedence_and_grouping() {
+        let signals = vec![signal("a", 2.0), signal("b", 3.0), signal("c", 4.0)];
+        let idx = SignalIndex::build(&signals);
+        // 2 + 3 * 4 = 14, (2 + 3) * 4 = 20, 4 - 3 - 2 = -1 (left-associative).
+        assert!(Predicate::parse("a + b * c == 14").unwrap().evaluate(&idx, &ctx()));
+        assert!(Predicate::parse("(a + b) * c == 20").unwrap().evaluate(&idx, &ctx()));
+        assert!(Predicate::parse("c - b - a == -1").unwrap().evaluate(&idx, &ctx()));
+        assert!(Predicate::parse("a*2 >= b+c/4").unwrap().evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_arith_division_by_zero_is_absent() {
+        let p = Predicate::parse("a / b > 0").expect("parse");
+        let signals = vec![signal("a", 1.0), signal("b", 0.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(!p.evaluate(&idx, &ctx()));
+        // Absent, not false: NOT of an absent comparison is true, as for Cmp.
+        let p = Predicate::parse("a / b <= 0").expect("parse");
+        assert!(!p.evaluate(&idx, &ctx()));
+        let p = Predicate::parse("NOT a / b > 0").expect("parse");
+        assert!(p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_ids_starting_like_inf_or_nan_parse_as_paths() {
+        let signals = vec![signal("info.count", 2.0), signal("nanos.total", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        for src in ["info.count > 1", "nanos.total > 1", "nanos.total / info.count > 2"] {
+            let p = Predicate::parse(src).unwrap_or_else(|e| panic!("{src}: {e}"));
+            assert!(p.evaluate(&idx, &ctx()), "{src}");
+        }
+    }
+
+    #[test]
+    fn predicate_arith_missing_operand_is_absent() {
+        let p = Predicate::parse("a - b < 100").expect("parse");
+        let signals = vec![signal("a", 1.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(!p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_plain_comparison_keeps_cmp_shape() {
+        let p = Predicate::parse("a > b").expect("parse");
+        assert!(matches!(p, Predicate::Cmp { rhs: Rhs::Path(_), .. }));
+        let p = Predicate::parse("a > 1").expect("parse");
+        assert!(matches!(
+            p,
+            Predicate::Cmp {
+                rhs: Rhs::Value(Value::Number(_)),
+                ..
+            }
+        ));
+        let p = Predicate::parse("a > b * 2").expect("parse");
+        assert!(matches!(p, Predicate::Arith { .. }));
+    }
+
+    #[test]
+    fn predicate_arith_against_string_literal_rejected() {
+        assert!(Predicate::parse("a + 1 == \"x\"").is_err());
+        assert!(Predicate::parse("a / > 1").is_err());
+        let err = Predicate::parse("a / b > 2GiB").unwrap_err().to_string();
+        assert!(
+            err.contains("arithmetic expressions do not compare with units, bools or strings"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn signal_thresholds_skip_arith_terms() {
+        let rule = Rule {
+            id: "ratio".to_string(),
+            when: Predicate::parse("a / b > 0.9 AND c > 1").expect("parse"),
+            severity: Severity::Warn,
+            summary: String::new(),
+            evidence_ids: vec![],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let thresholds = RuleEngine::new(vec![rule]).signal_thresholds();
+        assert_eq!(thresholds.len(), 1);
+        assert!(thresholds.contains_key("c"));
+    }
+
+    fn disks() -> Vec<Signal> {
+        vec![
+            signal("disk.sda.util_pct", 40.0),
+            signal("disk.sdb.util_pct", 97.0),
+            signal("disk.nvme0n1.util_pct", 12.0),
+            signal("disk.sda.await_ms", 3.0),
+        ]
+    }
+
+    #[test]
+    fn predicate_any_and_all_over_glob() {
+        let signals = disks();
+        let idx = SignalIndex::build(&signals);
+        assert!(
+            Predicate::parse("any(disk.*.util_pct) > 90")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        assert!(
+            !Predicate::parse("all(disk.*.util_pct) > 90")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        assert!(
+            Predicate::parse("ALL( disk.*.util_pct ) > 10")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        // Wildcards may sit inside a segment.
+        assert!(
+            !Predicate::parse("any(disk.nvme*.util_pct) > 90")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+    }
+
+    #[test]
+    fn predicate_quantifier_over_empty_glob_is_false() {
+        let signals = disks();
+        let idx = SignalIndex::build(&signals);
+        assert!(
+            !Predicate::parse("any(net.*.drops) >= 0")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        assert!(
+            !Predicate::parse("all(net.*.drops) >= 0")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+    }
+
+    #[test]
+    fn glob_treats_embedded_label_values_as_one_segment() {
+        let signals = vec![
+            signal("disk.dm.0.util_pct", 95.0).with_label("device", "dm.0"),
+            signal("disk.sda.part1.util_pct", 99.0),
+        ];
+        let idx = SignalIndex::build(&signals);
+        let glob: Vec<String> = ["disk", "*", "util_pct"].map(String::from).to_vec();
+        let ids: Vec<&str> = idx.matching(&glob).iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["disk.dm.0.util_pct"]);
+    }
+
+    #[test]
+    fn predicate_aggregates_over_glob() {
+        let signals = disks();
+        let idx = SignalIndex::build(&signals);
+        for (src, expected) in [
+            ("max(disk.*.util_pct) == 97", true),
+            ("min(disk.*.util_pct) == 12", true),
+            ("sum(disk.*.util_pct) == 149", true),
+            ("count(disk.*.util_pct) == 3", true),
+            ("count(net.*.drops) == 0", true),
+            ("max(net.*.drops) >= 0", false),
+            ("sum(disk.*.util_pct) / count(disk.*.util_pct) > 50", false),
+        ] {
+            let p = Predicate::parse(src).unwrap_or_else(|e| panic!("{src}: {e}"));
+            assert_eq!(p.evaluate(&idx, &ctx()), expected, "{src}");
+        }
+    }
+
+    #[test]
+    fn predicate_function_names_still_usable_as_signal_ids() {
+        let p = Predicate::parse("count > 3 AND any.thing == true").expect("parse");
+        assert_eq!(p.signal_ids(), vec!["count", "any.thing"]);
+    }
+
+    #[test]
+    fn predicate_quantifier_rejects_arithmetic_rhs() {
+        assert!(Predicate::parse("any(disk.*.util_pct) > a + 1").is_err());
+    }
+
+    #[test]
+    fn matched_signal_ids_name_concrete_signals() {
+        let signals = disks();
+        let idx = SignalIndex::build(&signals);
+        let p = Predicate::parse("any(disk.*.util_pct) > 30").unwrap();
+        assert_eq!(
+            p.matched_signal_ids(&idx, &ctx()),
+            vec!["disk.sda.util_pct", "disk.sdb.util_pct"]
+        );
+        let p = Predicate::parse("min(disk.*.util_pct) < 20 OR any(disk.*.await_ms) > 100").unwrap();
+        assert_eq!(p.matched_signal_ids(&idx, &ctx()), vec!["disk.nvme0n1.util_pct"]);
+        let p = Predicate::parse("NOT any(disk.*.util_pct) > 99").unwrap();
+        assert!(p.matched_signal_ids(&idx, &ctx()).is_empty());
+    }
+
+    #[test]
+    fn rule_engine_evidence_names_matching_device() {
+        let rule = Rule {
+            id: "disk.any_saturated".to_string(),
+            when: Predicate::parse("any(disk.*.util_pct) > 90").expect("parse"),
+            severity: Severity::Warn,
+            summary: "a disk is saturated".to_string(),
+            evidence_ids: vec![],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let (findings, _) = RuleEngine::new(vec![rule]).run(&disks(), &ctx(), &HashMap::new());
+        assert_eq!(findings.len(), 1);
+        let ids: Vec<&str> = findings[0].evidence.iter().map(|e| e.signal_id.as_str()).collect();
+        assert_eq!(ids, vec!["disk.sdb.util_pct"]);
+    }
+
+    #[test]
+    fn rule_engine_expands_glob_evidence_ids() {
+        let rule = Rule {
+            id: "disk.any_saturated".to_string(),
+            when: Predicate::parse("any(disk.*.util_pct) > 99").expect("parse"),
+            severity: Severity::Warn,
+            summary: String::new(),
+            evidence_ids: vec!["disk.*.util_pct".to_string()],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let (findings, checked_ok) = RuleEngine::new(vec![rule]).run(&disks(), &ctx(), &HashMap::new());
+        assert!(findings.is_empty());
+        assert_eq!(
+            checked_ok,
+            vec!["disk.nvme0n1.util_pct", "disk.sda.util_pct", "disk.sdb.util_pct"]
+        );
+    }
+
+    fn text(id: &str, v: &str) -> Signal {
+        Signal {
+            value: SignalValue::Text(v.to_string()),
+            ..signal(id, 0.0)
+        }
+    }
+
+    fn ctx_with_uname() -> CollectCtx {
+        CollectCtx {
+            uname: Some(
+                "Linux web-1 5.15.0-91-generic #101-Ubuntu SMP Tue Nov 14 13:30:08 UTC 2023 x86_64".to_string(),
+            ),
+            ..ctx()
+        }
+    }
+
+    #[test]
+    fn predicate_regex_match_and_negated_match() {
+        let signals = vec![text("cgroup.controllers", "cpuset cpu io memory pids")];
+        let idx = SignalIndex::build(&signals);
+        assert!(
+            Predicate::parse(r#"cgroup.controllers =~ "\bmemory\b""#)
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        assert!(
+            Predicate::parse(r#"cgroup.controllers !~ "hugetlb""#)
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        assert!(
+            !Predicate::parse(r#"cgroup.controllers !~ "^cpuset""#)
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+    }
+
+    #[test]
+    fn predicate_string_functions() {
+        let signals = vec![text("cgroup.controllers", "cpuset cpu io memory pids")];
+        let idx = SignalIndex::build(&signals);
+        for (src, expected) in [
+            (r#"contains(cgroup.controllers, "memory")"#, true),
+            (r#"starts_with( cgroup.controllers , 'cpuset' )"#, true),
+            (r#"ends_with(cgroup.controllers, "io")"#, false),
+            (r#"NOT contains(cgroup.controllers, "hugetlb")"#, true),
+        ] {
+            let p = Predicate::parse(src).unwrap_or_else(|e| panic!("{src}: {e}"));
+            assert_eq!(p.evaluate(&idx, &ctx()), expected, "{src}");
+        }
+    }
+
+    #[test]
+    fn predicate_text_tests_on_absent_or_numeric_values_are_false() {
+        let signals = vec![signal("a", 5.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(!Predicate::parse(r#"a =~ "5""#).unwrap().evaluate(&idx, &ctx()));
+        assert!(!Predicate::parse(r#"missing !~ "x""#).unwrap().evaluate(&idx, &ctx()));
+        assert!(
+            !Predicate::parse(r#"contains(missing, "x")"#)
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+    }
+
+    #[test]
+    fn predicate_invalid_regex_rejected_at_parse() {
+        let err = Predicate::parse(r#"host.kernel =~ "(unclosed""#).unwrap_err();
+        assert!(err.to_string().contains("unclosed"), "{err}");
+    }
+
+    #[test]
+    fn predicate_resolves_host_uname_fields() {
+        let idx = SignalIndex::build(&[]);
+        let ctx = ctx_with_uname();
+        for src in [
+            r#"host.os == "Linux""#,
+            r#"host.kernel =~ "^5\.15\.""#,
+            r#"host.arch == "x86_64""#,
+            r#"contains(host.uname, "Ubuntu")"#,
+        ] {
+            assert!(Predicate::parse(src).unwrap().evaluate(&idx, &ctx), "{src}");
+        }
+        // Without a uname the paths are absent.
+        assert!(
+            !Predicate::parse(r#"host.os != "Linux""#)
+                .unwrap()
+                .evaluate(&idx, &self::ctx())
+        );
+    }
+
+    #[test]
+    fn predicate_resolves_finding_paths_only_when_findings_are_visible() {
+        let finding = |id: &str, severity| Finding {
+            id: id.to_string(),
+            kind: FindingKind::Rule,
+            severity,
+            summary: String::new(),
+            evidence: vec![],
+            suggest: vec![],
+        };
+        let findings = vec![finding("cpu.iowait_elevated", Severity::Warn)];
+        let idx = SignalIndex::build(&[]).with_findings(&findings);
+        for src in [
+            "finding.cpu.iowait_elevated == true",
+            "finding.cpu.iowait_elevated.severity >= warn",
+            "finding.cpu.iowait_elevated.severity < crit",
+            "finding.mem.pressure == false",
+            "NOT finding.mem.pressure.severity >= info",
+        ] {
+            assert!(Predicate::parse(src).unwrap().evaluate(&idx, &ctx()), "{src}");
+        }
+        // Rules see no findings: every finding path is absent.
+        let idx = SignalIndex::build(&[]);
+        assert!(
+            !Predicate::parse("finding.mem.pressure == false")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        // A severity word followed by a segment is still a path.
+        assert_eq!(
+            Predicate::parse("a > warn.level").unwrap(),
+            Predicate::Cmp {
+                path: vec!["a".to_string()],
+                op: Op::Gt,
+                rhs: Rhs::Path(vec!["warn".to_string(), "level".to_string()]),
+            }
+        );
+    }
+
+    #[test]
+    fn rule_engine_reports_host_context_evidence() {
+        let rule = Rule {
+            id: "kernel.old".to_string(),
+            when: Predicate::parse(r#"host.kernel =~ "^5\.""#).expect("parse"),
+            severity: Severity::Info,
+            summary: String::new(),
+            evidence_ids: vec!["host.kernel".to_string()],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let (findings, _) = RuleEngine::new(vec![rule]).run(&[], &ctx_with_uname(), &HashMap::new());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].evidence[0].signal_id, "host.kernel");
+        assert_eq!(
+            findings[0].evidence[0].observed,
+            SignalValue::Text("5.15.0-91-generic".to_string())
+        );
+    }
+
+    fn sampled(id: &str, samples: &[f64]) -> Signal {
+        Signal {
+            samples: Some(samples.to_vec()),
+            ..signal(id, samples.last().copied().unwrap_or_default())
+        }
+    }
+
+    #[test]
+    fn sample_moment_suffixes_resolve() {
+        // Leaks 100 MB per 5 s sample: 20 MB/s.
+        let signals = vec![sampled("mem.used_mb", &[1000.0, 1100.0, 1200.0, 1300.0, 1400.0])];
+        let idx = SignalIndex::build(&signals);
+        let sampling = CollectCtx {
+            interval: Some(std::time::Duration::from_secs(5)),
+            ..ctx()
+        };
+        let holds = |s: &str| Predicate::parse(s).expect("parse").evaluate(&idx, &sampling);
+        assert!(holds("mem.used_mb.slope == 20"));
+        assert!(holds("mem.used_mb.mean == 1200"));
+        assert!(holds("mem.used_mb.count == 5"));
+        assert!(holds("mem.used_mb.stddev > 158 AND mem.used_mb.stddev < 159"));
+        assert!(holds("mem.used_mb.cv > 0.13 AND mem.used_mb.cv < 0.14"));
+        // Without an interval, samples count as one second apart.
+        assert!(
+            Predicate::parse("mem.used_mb.slope == 100")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        // A count has no unit, so comparing it with a size fails to load.
+        assert!(Predicate::parse("mem.used_mb.count < 1GiB").is_err());
+        assert!(Predicate::parse("mem.used_mb.p95 < 1GiB").is_ok());
+    }
+
+    #[test]
+    fn predicate_sustained_percent_ignores_single_spike() {
+        // p95 would trip on the spike; sustained() needs 80% of the window.
+        let signals = vec![sampled("cpu.iowait_pct", &[5.0, 6.0, 90.0, 4.0, 5.0])];
+        let idx = SignalIndex::build(&signals);
+        assert!(
+            Predicate::parse("cpu.iowait_pct.max > 30")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+        assert!(
+            !Predicate::parse("sustained(cpu.iowait_pct > 30, 80%)")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+
+        let signals = vec![sampled("cpu.iowait_pct", &[35.0, 40.0, 90.0, 4.0, 50.0])];
+        let idx = SignalIndex::build(&signals);
+        assert!(
+            Predicate::parse("sustained(cpu.iowait_pct > 30, 80%)")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+    }
+
+    #[test]
+    fn predicate_sustained_sample_count_over_compound_condition() {
+        let signals = vec![
+            sampled("cpu.iowait_pct", &[35.0, 40.0, 45.0, 4.0]),
+            sampled("cpu.run_queue", &[1.0, 9.0, 9.0, 9.0]),
+            signal("host.load_avg_1m", 3.0),
+        ];
+        let idx = SignalIndex::build(&signals);
+        // Both hold together in samples 1 and 2 only.
+        let p = Predicate::parse("sustained(cpu.iowait_pct > 30 AND cpu.run_queue > host.load_avg_1m, 2)").unwrap();
+        assert!(p.evaluate(&idx, &ctx()));
+        let p = Predicate::parse("sustained(cpu.iowait_pct > 30 AND cpu.run_queue > host.load_avg_1m, 3)").unwrap();
+        assert!(!p.evaluate(&idx, &ctx()));
+    }
+
+    #[test]
+    fn predicate_sustained_without_samples_is_false() {
+        let signals = vec![signal("cpu.iowait_pct", 90.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(
+            !Predicate::parse("sustained(cpu.iowait_pct > 30, 1)")
+                .unwrap()
+                .evaluate(&idx, &ctx())
+        );
+    }
+
+    #[test]
+    fn predicate_sustained_rejects_bad_quota() {
+        assert!(Predicate::parse("sustained(a > 1, 0%)").is_err());
+        assert!(Predicate::parse("sustained(a > 1, 150%)").is_err());
+        assert!(Predicate::parse("sustained(a > 1, 2.5)").is_err());
+        assert!(Predicate::parse("sustained(a > 1)").is_err());
+        assert_eq!(
+            Predicate::parse("sustained( a > 1 , 75 % )").unwrap(),
+            Predicate::Sustained {
+                inner: Box::new(Predicate::parse("a > 1").unwrap()),
+                min: Sustain::Percent(75.0),
+            }
+        );
+    }
+
+    #[test]
+    fn rule_engine_sustained_evidence_reports_breached_samples() {
+        let rule = Rule {
+            id: "cpu.iowait_sustained".to_string(),
+            when: Predicate::parse("sustained(cpu.iowait_pct > 30, 60%)").expect("parse"),
+            severity: Severity::Warn,
+            summary: String::new(),
+            evidence_ids: vec!["cpu.iowait_pct".to_string()],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let signals = vec![sampled("cpu.iowait_pct", &[35.0, 40.0, 5.0, 45.0, 50.0, 55.0])];
+        let ctx = CollectCtx {
+            interval: Some(std::time::Duration::from_secs(10)),
+            ..ctx()
+        };
+        let (findings, _) = RuleEngine::new(vec![rule]).run(&signals, &ctx, &HashMap::new());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].evidence.len(), 1);
+        assert_eq!(
+            findings[0].evidence[0].detail.as_deref(),
+            Some("breached in 5/6 samples (83%, 50s); longest run 3 samples (30s)")
+        );
+    }
+
+    #[test]
+    fn predicate_together_needs_breaches_in_the_same_samples() {
+        // Both breach 3 times, but only samples 2 and 3 overlap.
+        let signals = vec![
+            sampled("dmesg.blocked_task_count", &[0.0, 2.0, 3.0, 4.0, 0.0]),
+            sampled("cpu.iowait_pct", &[40.0, 35.0, 50.0, 5.0, 5.0]),
+        ];
+        let idx = SignalIndex::build(&signals);
+        let p = |q| {
+            Predicate::parse(&format!(
+                "together(dmesg.blocked_task_count > 0, cpu.iowait_pct > 10, {q})"
+            ))
+            .unwrap()
+        };
+        assert!(p("50%").evaluate(&idx, &ctx()));
+        assert!(!p("60%").evaluate(&idx, &ctx()));
+        assert!(p("2").evaluate(&idx, &ctx()));
+        assert!(!p("3").evaluate(&idx, &ctx()));
+
+        let pair = sample_pair(
+            &Predicate::parse("dmesg.blocked_task_count > 0").unwrap(),
+            &Predicate::parse("cpu.iowait_pct > 10").unwrap(),
+            &idx,
+            &ctx(),
+        )
+        .unwrap();
+        assert_eq!(
+            pair.describe(Relation::Together(Sustain::Percent(50.0)), None),
+            "both breached in 2/4 samples where either did (50%) of 5"
+        );
+    }
+
+    #[test]
+    fn predicate_leads_needs_first_to_start_within_lag() {
+        let signals = vec![
+            sampled("mem.psi_some_avg10", &[0.0, 20.0, 30.0, 30.0, 30.0]),
+            sampled("cpu.iowait_pct", &[5.0, 5.0, 5.0, 40.0, 40.0]),
+        ];
+        let idx = SignalIndex::build(&signals);
+        let p = |q: &str| Predicate::parse(q).unwrap().evaluate(&idx, &ctx());
+        assert!(p("leads(mem.psi_some_avg10 > 10, cpu.iowait_pct > 30, 2)"));
+        assert!(!p("leads(mem.psi_some_avg10 > 10, cpu.iowait_pct > 30, 1)"));
+        assert!(!p("leads(cpu.iowait_pct > 30, mem.psi_some_avg10 > 10, 5)"));
+        assert!(!p("leads(mem.psi_some_avg10 > 10, mem.psi_some_avg10 > 10, 5)"));
+        assert!(Predicate::parse("leads(a > 1, b > 1, 50%)").is_err());
+        assert!(Predicate::parse("together(a > 1, b > 1)").is_err());
+
+        let signals = vec![signal("mem.psi_some_avg10", 20.0), signal("cpu.iowait_pct", 40.0)];
+        let idx = SignalIndex::build(&signals);
+        assert!(
+            !Predicate::parse("leads(mem.psi_some_avg10 > 10, cpu.iowait_pct > 30, 2)")
+                .unwrap()
+                .evaluate(&idx, &ctx()),
+            "no samples to order"
+        );
+    }
+
+    #[test]
+    fn rule_engine_temporal_evidence_describes_both_sides() {
+        let rule = Rule {
+            id: "mem.reclaim_then_iowait".to_string(),
+            when: Predicate::parse("leads(mem.psi_some_avg10 > 10, cpu.iowait_pct > 30, 3)").expect("parse"),
+            severity: Severity::Warn,
+            summary: String::new(),
+            evidence_ids: vec!["mem.psi_some_avg10".to_string(), "cpu.iowait_pct".to_string()],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let signals = vec![
+            sampled("mem.psi_some_avg10", &[0.0, 20.0, 30.0, 30.0]),
+            sampled("cpu.iowait_pct", &[5.0, 5.0, 5.0, 40.0]),
+        ];
+        let ctx = CollectCtx {
+            interval: Some(std::time::Duration::from_secs(10)),
+            ..ctx()
+        };
+        let (findings, _) = RuleEngine::new(vec![rule]).run(&signals, &ctx, &HashMap::new());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].evidence.len(), 2);
+        for evidence in &findings[0].evidence {
+            assert_eq!(
+                evidence.detail.as_deref(),
+                Some("first breached at sample 2, second at sample 4: 2 samples later (20s)")
+            );
+        }
+    }
+
+    #[test]
+    fn signal_thresholds_look_through_sustained() {
+        let rule = Rule {
+            id: "sustained".to_string(),
+            when: Predicate::parse("sustained(a > 30, 80%)").expect("parse"),
+            severity: Severity::Warn,
+            summary: String::new(),
+            evidence_ids: vec![],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let thresholds = RuleEngine::new(vec![rule]).signal_thresholds();
+        assert_eq!(thresholds["a"].value, 30.0);
+    }
+
+    const TIERED: &str = r#"
+[[rule]]
+id = "disk.util"
+summary = "disk busy"
+evidence = ["disk.max_util_pct"]
+
+[[rule.level]]
+when = "disk.max_util_pct > 80"
+severity = "warn"
+
+[[rule.level]]
+when = "disk.max_util_pct > 95"
+severity = "crit"
+"#;
+
+    #[test]
+    fn multi_level_rule_fires_once_at_highest_matching_level() {
+        let engine = RuleEngine::new(parse_rules_toml(TIERED).expect("parse"));
+        for (util, expected) in [(50.0, None), (85.0, Some(Severity::Warn)), (99.0, Some(Severity::Crit))] {
+            let (findings, _) = engine.run(&[signal("disk.max_util_pct", util)], &ctx(), &HashMap::new());
+            assert_eq!(
+                findings.iter().map(|f| f.severity).collect::<Vec<_>>(),
+                Vec::from_iter(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn multi_level_rule_accepts_top_level_tier() {
+        let rules = parse_rules_toml(
+            r#"
+[[rule]]
+id = "x"
+when = "a > 1"
+severity = "info"
+summary = ""
+
+[[rule.level]]
+when = "a > 10"
+severity = "warn"
+"#,
+        )
+        .expect("parse");
+        assert_eq!(rules[0].severity, Severity::Info);
+        assert_eq!(rules[0].levels.len(), 1);
+    }
+
+    #[test]
+    fn rule_without_any_tier_rejected() {
+        let err = parse_rules_toml("[[rule]]\nid = \"x\"\nsummary = \"\"\n").unwrap_err();
+        assert!(err.to_string().contains("rule 'x'"), "{err}");
+        let err = parse_rules_toml("[[rule]]\nid = \"x\"\nwhen = \"a > 1\"\nsummary = \"\"\n").unwrap_err();
+        assert!(err.to_string().contains("together"), "{err}");
+    }
+
+    #[test]
+    fn templated_summary_and_suggest_render_observed_values() {
+        let rules = parse_rules_toml(
+            r#"
+[[rule]]
+id = "disk.hot"
+when = "max(disk.*.util_pct) > 90"
+severity = "warn"
+summary = "{{ device }} at {{ disk.sdb.util_pct | round(1) }}%"
+suggest = ["iostat -x {{ device }} 1 5", "iotop -ao"]
+"#,
+        )
+        .expect("parse");
+        let signals = [signal("disk.sda.util_pct", 40.0), signal("disk.sdb.util_pct", 97.24)];
+        let (findings, _) = RuleEngine::new(rules).run(&signals, &ctx(), &HashMap::new());
+        assert_eq!(findings[0].summary, "sdb at 97.2%");
+        assert_eq!(findings[0].suggest, ["iostat -x sdb 1 5", "iotop -ao"]);
+    }
+
+    #[test]
+    fn summary_that_is_not_a_template_loads_verbatim() {
+        let rules = parse_rules_toml(
+            r#"
+[[rule]]
+id = "x"
+when = "a > 1"
+severity = "warn"
+summary = "{{ a "
+suggest = ["docker ps --format '{{.Names}}'"]
+"#,
+        )
+        .expect("parse");
+        let (findings, _) = RuleEngine::new(rules).run(&[signal("a", 2.0)], &ctx(), &HashMap::new());
+        assert_eq!(findings[0].summary, "{{ a ");
+        assert_eq!(findings[0].suggest, ["docker ps --format '{{.Names}}'"]);
+    }
+
+    #[test]
+    fn signal_thresholds_expose_every_tier() {
+        let engine = RuleEngine::new(parse_rules_toml(TIERED).expect("parse"));
+        let thresholds = engine.signal_thresholds();
+        let info = &thresholds["disk.max_util_pct"];
+        assert_eq!((info.severity, info.value), (Severity::Crit, 95.0));
+        let tiers: Vec<(Severity, f64)> = info.tiers.iter().map(|t| (t.severity, t.value)).collect();
+        assert_eq!(tiers, vec![(Severity::Warn, 80.0), (Severity::Crit, 95.0)]);
+    }
+
     #[test]
     fn rule_engine_fires_compound_and_predicate() {
         // End-to-end: a Rule with an AND predicate produces a Finding when
@@ -697,6 +3128,8 @@ mod tests {
             suggest: vec![],
             description: None,
             links: vec![],
+            levels: vec![],
+            inhibits: vec![],
         };
         let signals = vec![signal("a", 5.0), signal("b", 5.0)];
         let engine = RuleEngine::new(vec![rule]);
@@ -704,4 +3137,120 @@ mod tests {
         assert_eq!(findings.len(), 1);
         assert_eq!(findings[0].id, "compound.test");
     }
+
+    fn signal_in(id: &str, v: f64, unit: Unit) -> Signal {
+        Signal { unit, ..signal(id, v) }
+    }
+
+    #[test]
+    fn quantities_convert_to_the_signal_unit() {
+        let signals = vec![
+            signal_in("mem.free_mb", 1536.0, Unit::Megabytes),
+            signal_in("cgroup.memory_bytes", 3.0 * 1024.0 * 1024.0 * 1024.0, Unit::Bytes),
+            signal_in("disk.sda.await_ms", 40.0, Unit::MillisPerOp),
+            signal_in("disk.sdb.await_ms", 900.0, Unit::MillisPerOp),
+            signal_in("x.unitless", 5.0, Unit::None),
+        ];
+        let idx = SignalIndex::build(&signals);
+        let holds = |s: &str| Predicate::parse(s).expect("parse").evaluate(&idx, &ctx());
+        assert!(holds("mem.free_mb < 2GiB"));
+        assert!(!holds("mem.free_mb < 1.5GiB"));
+        assert!(holds("cgroup.memory_bytes > 2GiB"));
+        assert!(holds("disk.sda.await_ms < 0.05s"));
+        assert!(holds("any(disk.*.await_ms) > 500ms"));
+        assert!(!holds("all(disk.*.await_ms) > 500ms"));
+        // A quantity cannot be converted to a signal without a unit.
+        assert!(!holds("x.unitless > 1B"));
+        assert!(!holds("x.unitless < 1GiB"));
+        // Unitless multipliers are plain numbers.
+        assert!(holds("mem.free_mb > 1.5k"));
+    }
+
+    #[test]
+    fn quantities_of_the_wrong_dimension_are_rejected_at_load_time() {
+        let err = Predicate::parse("mem.free_pct > 2GiB").expect_err("size vs percent");
+        assert_eq!(
+            err.to_string(),
+            "predicate parse error: `mem.free_pct > 2GiB`: mem.free_pct is in percent, 2GiB is a size"
+        );
+        assert!(Predicate::parse("mem.free_mb > 2GiB OR any(disk.*.await_ms) > 10MHz").is_err());
+        assert!(Predicate::parse("disk.sda.await_ms.p95 > 1GB").is_err());
+        assert!(Predicate::parse("disk.sda.await_ms.p95 > 1s").is_ok());
+        // Signals outside the catalog are only checked when evaluated.
+        assert!(Predicate::parse("custom.thing > 2GiB").is_ok());
+        // Quantities do not take part in arithmetic.
+        assert!(Predicate::parse("mem.free_mb * 2 > 2GiB").is_err());
+    }
+
+    #[test]
+    fn quantity_thresholds_are_reported_in_the_signal_unit() {
+        let rule = Rule {
+            id: "mem.low".to_string(),
+            when: Predicate::parse("mem.free_mb < 2GiB").expect("parse"),
+            severity: Severity::Warn,
+            summary: "low memory".to_string(),
+            evidence_ids: vec![],
+            suggest: vec![],
+            description: None,
+            links: vec![],
+            levels: vec![],
+            inhibits: vec![],
+        };
+        let thresholds = RuleEngine::new(vec![rule]).signal_thresholds();
+        assert_eq!(thresholds["mem.free_mb"].value, 2048.0);
+    }
+
+    #[test]
+    fn baseline_paths_read_the_signal_baseline_stats() {
+        let mut retrans = signal("net.retrans_pct", 3.0);
+        retrans.baseline = Some(crate::signal::BaselineStats {
+            p50: 0.5,
+            p95: 0.8,
+            mad: 0.1,
+            z_score: 16.9,
+        });
+        let signals = vec![retrans, signal("cpu.usr_pct", 10.0)];
+        let idx = SignalIndex::build(&signals);
+        let holds = |s: &str| Predicate::parse(s).expect("parse").evaluate(&idx, &ctx());
+        assert!(holds(
+            "net.retrans_pct > 1 AND net.retrans_pct > 3 * net.retrans_pct.baseline.p95"
+        ));
+        assert!(holds("net.retrans_pct.baseline.z > 3.5"));
+        assert!(holds("net.retrans_pct.baseline.p50 == 0.5"));
+        assert!(holds("net.retrans_pct.baseline.mad < 1"));
+        // Without a baseline the path is absent.
+        assert!(!holds("cpu.usr_pct.baseline.z < 100"));
+        assert!(holds("NOT cpu.usr_pct.baseline.z < 100"));
+    }
+
+    #[test]
+    fn delta_compares_against_the_previous_run() {
+        use crate::baseline::BaselineRecord;
+
+        let mut oom = signal("dmesg.oom_count", 3.0);
+        oom.labels.insert("cgroup".to_string(), "/app".to_string());
+        let signals = vec![oom.clone(), signal("cpu.usr_pct", 10.0)];
+        let previous = BaselineRecord::from_signals(&[Signal {
+            value: SignalValue::F64(1.0),
+            ..oom
+        }]);
+        let idx = SignalIndex::build(&signals);
+        let with_previous = CollectCtx {
+            baseline: Some(std::sync::Arc::new(previous)),
+            ..ctx()
+        };
+        let holds = |s: &str, ctx: &CollectCtx| Predicate::parse(s).expect("parse").evaluate(&idx, ctx);
+        assert!(holds("delta(dmesg.oom_count) == 2", &with_previous));
+        assert!(holds("delta(dmesg.oom_count) > 0", &with_previous));
+        // Absent without a previous run, or when it lacks the signal.
+        assert!(!holds("delta(dmesg.oom_count) > 0", &ctx()));
+        assert!(!holds("delta(cpu.usr_pct) >= 0", &with_previous));
+        // `delta` is only a function when called; globs are rejected.
+        assert!(Predicate::parse("delta > 1").is_ok());
+        assert!(Predicate::parse("delta(dmesg.*) > 1").is_err());
+        assert_eq!(
+            Predicate::parse("delta(dmesg.oom_count) > 0").unwrap().signal_ids(),
+            vec!["dmesg.oom_count"]
+        );
+    }
 }
@@ -0,0 +1,254 @@
+//! Near misses: rules and patterns that did not fire but came close.
+//!
+//! A predicate that does not hold is a near miss when either
+//!
+//! - it would hold with every numeric `<`, `<=`, `>` and `>=` comparison
+//!   given `margin` percent of slack against its threshold, e.g.
+//!   `net.tw_count > 28000` at 25500 with a 10% margin, or
+//! - more than half of its comparisons held, e.g. two of the three terms of
+//!   an `AND`.
+//!
+//! Comparisons under `NOT`, text tests and `sustained(...)`,
+//! `together(...)` and `leads(...)` terms get no slack; they count as
+//! single comparisons.
+
+use serde::{Deserialize, Serialize};
+
+use super::trace::Trace;
+use super::{
+    LhsValue, Op, Predicate, Quantifier, SignalIndex, compare_numbers, compare_values, resolve_path, resolve_rhs,
+};
+use crate::collector::CollectCtx;
+use crate::finding::{FindingKind, Severity};
+
+/// A rule or pattern that did not fire but came close; reported at `info`
+/// as something to watch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearMiss {
+    pub id: String,
+    pub kind: FindingKind,
+    /// Severity of the tier that came close, i.e. what it would fire at.
+    pub severity: Severity,
+    /// Comparisons that held, out of `total`.
+    pub held: usize,
+    pub total: usize,
+    /// Comparisons that did not hold.
+    pub failing: Vec<FailingTerm>,
+}
+
+/// One comparison of a near miss that did not hold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailingTerm {
+    /// The term as written, e.g. `net.tw_count > 28000`.
+    pub term: String,
+    /// Resolved operands and how far off they were, e.g.
+    /// `25000 > 28000, 10.7% off`, or the signals that were absent.
+    pub detail: Option<String>,
+}
+
+/// Check whether `predicate` is a near miss with `margin` percent of slack.
+/// `None` when it holds or is not close.
+pub fn near_miss(
+    id: &str,
+    kind: FindingKind,
+    severity: Severity,
+    predicate: &Predicate,
+    signals_index: &SignalIndex<'_>,
+    ctx: &CollectCtx,
+    margin: f64,
+) -> Option<NearMiss> {
+    if predicate.evaluate(signals_index, ctx) {
+        return None;
+    }
+    let mut leaves = Vec::new();
+    collect_leaves(predicate, false, &mut leaves);
+    let total = leaves.len();
+    let mut held = 0;
+    let mut failing = Vec::new();
+    for (leaf, negated) in leaves {
+        if leaf.evaluate(signals_index, ctx) != negated {
+            held += 1;
+        } else {
+            failing.push(failing_term(leaf, negated, signals_index, ctx));
+        }
+    }
+    if !relaxed(predicate, signals_index, ctx, margin) && held * 2 <= total {
+        return None;
+    }
+    Some(NearMiss {
+        id: id.to_string(),
+        kind,
+        severity,
+        held,
+        total,
+        failing,
+    })
+}
+
+/// The comparisons of `predicate`, each with whether it sits under an odd
+/// number of `NOT`s.
+fn collect_leaves<'p>(predicate: &'p Predicate, negated: bool, out: &mut Vec<(&'p Predicate, bool)>) {
+    match predicate {
+        Predicate::And(a, b) | Predicate::Or(a, b) => {
+            collect_leaves(a, negated, out);
+            collect_leaves(b, negated, out);
+        }
+        Predicate::Not(a) => collect_leaves(a, !negated, out),
+        _ => out.push((predicate, negated)),
+    }
+}
+
+fn failing_term(leaf: &Predicate, negated: bool, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> FailingTerm {
+    let trace = Trace::evaluate(leaf, signals_index, ctx);
+    let term = if negated {
+        format!("NOT {}", trace.term)
+    } else {
+        trace.term
+    };
+    let mut detail = trace.detail;
+    if !negated {
+        if let Some(gap) = numeric_operands(leaf, signals_index, ctx).and_then(|(op, n, m)| gap_pct(op, n, m)) {
+            detail = detail.map(|d| format!("{}, {:.1}% off", d, gap));
+        }
+    }
+    if !trace.absent.is_empty() {
+        let absent = format!("absent: {}", trace.absent.join(", "));
+        detail = Some(match detail {
+            Some(d) => format!("{}; {}", d, absent),
+            None => absent,
+        });
+    }
+    FailingTerm { term, detail }
+}
+
+/// Like `Predicate::evaluate`, with `margin` percent of slack on numeric
+/// ordering comparisons outside `NOT`.
+fn relaxed(predicate: &Predicate, signals_index: &SignalIndex<'_>, ctx: &CollectCtx, margin: f64) -> bool {
+    match predicate {
+        Predicate::And(a, b) => relaxed(a, signals_index, ctx, margin) && relaxed(b, signals_index, ctx, margin),
+        Predicate::Or(a, b) => relaxed(a, signals_index, ctx, margin) || relaxed(b, signals_index, ctx, margin),
+        Predicate::Cmp { .. } | Predicate::Arith { .. } => match numeric_operands(predicate, signals_index, ctx) {
+            Some((op, n, m)) => within(op, n, m, margin),
+            None => predicate.evaluate(signals_index, ctx),
+        },
+        Predicate::Quantified {
+            quantifier,
+            glob,
+            op,
+            rhs,
+        } => {
+            let matches = signals_index.matching(glob);
+            let mut results = matches.iter().map(|s| {
+                let rhs = resolve_rhs(rhs, s.unit, signals_index, ctx);
+                match (signals_index.value(s), rhs) {
+                    (LhsValue::Number(n), Some(LhsValue::Number(m))) => within(*op, n, m, margin),
+                    (lhs, rhs) => compare_values(Some(lhs), *op, rhs),
+                }
+            });
+            match quantifier {
+                Quantifier::Any => results.any(|b| b),
+                Quantifier::All => !matches.is_empty() && results.all(|b| b),
+            }
+        }
+        _ => predicate.evaluate(signals_index, ctx),
+    }
+}
+
+/// Operator and operands of a comparison whose sides are both numbers.
+fn numeric_operands(
+    predicate: &Predicate,
+    signals_index: &SignalIndex<'_>,
+    ctx: &CollectCtx,
+) -> Option<(Op, f64, f64)> {
+    match predicate {
+        Predicate::Cmp { path, op, rhs } => match (
+            resolve_path(path, signals_index, ctx)?,
+            resolve_rhs(rhs, signals_index.unit_of(path), signals_index, ctx)?,
+        ) {
+            (LhsValue::Number(n), LhsValue::Number(m)) => Some((*op, n, m)),
+            _ => None,
+        },
+        Predicate::Arith { lhs, op, rhs } => Some((
+            *op,
+            lhs.evaluate(signals_index, ctx)?,
+            rhs.evaluate(signals_index, ctx)?,
+        )),
+        _ => None,
+    }
+}
+
+/// Whether `n op m` holds, or misses by no more than `margin` percent of `m`.
+fn within(op: Op, n: f64, m: f64, margin: f64) -> bool {
+    compare_numbers(n, op, m) || gap_pct(op, n, m).is_some_and(|gap| gap <= margin)
+}
+
+/// How far `n` is from satisfying `n op m`, in percent of `m`. `None` for
+/// `==`/`!=` and a zero threshold, where no percentage applies.
+fn gap_pct(op: Op, n: f64, m: f64) -> Option<f64> {
+    match op {
+        Op::Eq | Op::Ne => None,
+        _ if m == 0.0 => None,
+        _ => Some((n - m).abs() * 100.0 / m.abs()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+    fn signal(id: &str, v: f64) -> Signal {
+        Signal {
+            id: id.to_string(),
+            value: SignalValue::F64(v),
+            unit: Unit::None,
+            at: chrono::Local::now(),
+            samples: None,
+            stats: None,
+            baseline: None,
+            labels: Labels::new(),
+        }
+    }
+
+    fn check(when: &str, signals: &[Signal], margin: f64) -> Option<NearMiss> {
+        near_miss(
+            "p",
+            FindingKind::Pattern,
+            Severity::Warn,
+            &Predicate::parse(when).expect("parse"),
+            &SignalIndex::build(signals),
+            &CollectCtx::default(),
+            margin,
+        )
+    }
+
+    #[test]
+    fn threshold_within_margin_is_a_near_miss() {
+        let signals = vec![signal("net.tw_count", 25500.0), signal("net.connect_failures", 3.0)];
+        let when = "net.tw_count > 28000 AND net.connect_failures > 0";
+        let miss = check(when, &signals, 10.0).expect("within 10%");
+        assert_eq!((miss.held, miss.total), (1, 2));
+        assert_eq!(miss.failing.len(), 1);
+        assert_eq!(miss.failing[0].term, "net.tw_count > 28000");
+        assert_eq!(miss.failing[0].detail.as_deref(), Some("25500 > 28000, 8.9% off"));
+        assert!(check(when, &signals, 5.0).is_none(), "8.9% is outside 5%");
+        assert!(check("net.tw_count > 20000", &signals, 10.0).is_none(), "holds");
+    }
+
+    #[test]
+    fn most_comparisons_holding_is_a_near_miss() {
+        let signals = vec![signal("a", 5.0), signal("b", 5.0)];
+        let miss = check("a > 1 AND b > 1 AND c > 1", &signals, 0.0).expect("2 of 3");
+        assert_eq!((miss.held, miss.total), (2, 3));
+        assert_eq!(miss.failing[0].detail.as_deref(), Some("absent > 1; absent: c"));
+        assert!(check("a > 1 AND c > 1", &signals, 0.0).is_none(), "1 of 2 is not most");
+    }
+
+    #[test]
+    fn terms_under_not_get_no_slack() {
+        let signals = vec![signal("a", 5.0), signal("b", 95.0)];
+        assert!(check("a > 1 AND NOT b > 90", &signals, 10.0).is_none());
+        let miss = check("a > 1 AND NOT b > 90 AND NOT c > 1", &signals, 10.0).expect("2 of 3");
+        assert_eq!(miss.failing[0].term, "NOT b > 90");
+    }
+}
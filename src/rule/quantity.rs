@@ -0,0 +1,180 @@
+//! Unit-suffixed literals: `2GiB`, `500ms`, `90%`, `1.5k`.
+//!
+//! A literal with a unit is converted to the unit of the signal it is
+//! compared with when the predicate is evaluated, so `mem.free_mb < 2GiB`
+//! compares against 2048 and `cgroup.memory_limit_bytes < 2GiB` against
+//! 2147483648. A signal whose unit measures something else, or has no
+//! declared unit, never matches. `Predicate::parse` rejects literals whose
+//! dimension does not fit the catalog unit of the signal, e.g.
+//! `disk.max_await_ms > 2GiB`.
+//!
+//! The multipliers `k`, `M` and `G` carry no unit: `1.5k` is just 1500.
+
+use std::fmt;
+
+use winnow::{
+    ModalResult, Parser,
+    ascii::float,
+    combinator::{not, peek},
+    token::{one_of, take_while},
+};
+
+use crate::signal::{Dimension, Unit};
+
+/// Suffixes with a dimension, and the size of one of them in the
+/// dimension's base unit (see `Unit::scale`). Longer suffixes come first so
+/// that `2GiB` is not read as `2G` followed by `iB`.
+const SUFFIXES: &[(&str, Dimension, f64)] = &[
+    ("KiB/s", Dimension::Throughput, 1024.0),
+    ("MiB/s", Dimension::Throughput, 1_048_576.0),
+    ("GiB/s", Dimension::Throughput, 1_073_741_824.0),
+    ("kB/s", Dimension::Throughput, 1e3),
+    ("KB/s", Dimension::Throughput, 1e3),
+    ("MB/s", Dimension::Throughput, 1e6),
+    ("GB/s", Dimension::Throughput, 1e9),
+    ("B/s", Dimension::Throughput, 1.0),
+    ("KiB", Dimension::Size, 1024.0),
+    ("MiB", Dimension::Size, 1_048_576.0),
+    ("GiB", Dimension::Size, 1_073_741_824.0),
+    ("TiB", Dimension::Size, 1_099_511_627_776.0),
+    ("kHz", Dimension::Frequency, 1e3),
+    ("MHz", Dimension::Frequency, 1e6),
+    ("GHz", Dimension::Frequency, 1e9),
+    ("min", Dimension::Time, 60.0),
+    ("kB", Dimension::Size, 1e3),
+    ("KB", Dimension::Size, 1e3),
+    ("MB", Dimension::Size, 1e6),
+    ("GB", Dimension::Size, 1e9),
+    ("TB", Dimension::Size, 1e12),
+    ("Hz", Dimension::Frequency, 1.0),
+    ("ns", Dimension::Time, 1e-9),
+    ("us", Dimension::Time, 1e-6),
+    ("µs", Dimension::Time, 1e-6),
+    ("ms", Dimension::Time, 1e-3),
+    ("B", Dimension::Size, 1.0),
+    ("s", Dimension::Time, 1.0),
+    ("h", Dimension::Time, 3600.0),
+    ("%", Dimension::Percent, 1.0),
+];
+
+/// Unitless multipliers.
+const MULTIPLIERS: &[(&str, f64)] = &[("k", 1e3), ("K", 1e3), ("M", 1e6), ("G", 1e9)];
+
+/// A number with a unit suffix, e.g. `2GiB`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    /// The number as written, e.g. `2` of `2GiB`.
+    pub amount: f64,
+    /// The suffix as written, e.g. `GiB`.
+    pub suffix: &'static str,
+    pub dimension: Dimension,
+    /// Size of one `suffix` in the dimension's base unit.
+    scale: f64,
+}
+
+impl Quantity {
+    /// The amount expressed in `unit`; `None` when `unit` does not measure
+    /// the same dimension.
+    pub fn convert(&self, unit: Unit) -> Option<f64> {
+        match unit.scale() {
+            Some((dimension, scale)) if dimension == self.dimension => Some(self.amount * self.scale / scale),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.suffix)
+    }
+}
+
+/// A suffix is only one when no identifier character follows it, so `2s`
+/// is a duration but `2sec` is not.
+fn suffix_end(input: &mut &str) -> ModalResult<()> {
+    not(take_while(1, |c: char| c.is_alphanumeric() || c == '_' || c == '/')).parse_next(input)
+}
+
+/// A number as written in a rule. Only input that starts like a number is
+/// one: `float` alone also reads `inf` and `nan`, the start of IDs such as
+/// `info.count` or `nanos.total`.
+fn literal(input: &mut &str) -> ModalResult<f64> {
+    peek(one_of(|c: char| c.is_ascii_digit() || c == '.' || c == '-' || c == '+')).parse_next(input)?;
+    float.parse_next(input)
+}
+
+/// `NUMBER SUFFIX`, without whitespace in between.
+pub(super) fn quantity(input: &mut &str) -> ModalResult<Quantity> {
+    let amount: f64 = literal.parse_next(input)?;
+    for (suffix, dimension, scale) in SUFFIXES {
+        let checkpoint = *input;
+        if let Some(rest) = input.strip_prefix(suffix) {
+            *input = rest;
+            if suffix_end(input).is_ok() {
+                return Ok(Quantity {
+                    amount,
+                    suffix,
+                    dimension: *dimension,
+                    scale: *scale,
+                });
+            }
+            *input = checkpoint;
+        }
+    }
+    Err(winnow::error::ErrMode::Backtrack(winnow::error::ContextError::new()))
+}
+
+/// A plain number, optionally scaled by a multiplier: `1.5k` is 1500.
+pub(super) fn number(input: &mut &str) -> ModalResult<f64> {
+    let n: f64 = literal.parse_next(input)?;
+    for (suffix, factor) in MULTIPLIERS {
+        let checkpoint = *input;
+        if let Some(rest) = input.strip_prefix(suffix) {
+            *input = rest;
+            if suffix_end(input).is_ok() {
+                return Ok(n * factor);
+            }
+            *input = checkpoint;
+        }
+    }
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Option<Quantity> {
+        quantity.parse(s).ok()
+    }
+
+    #[test]
+    fn quantities_convert_within_their_dimension() {
+        let q = parse("2GiB").expect("2GiB");
+        assert_eq!(q.to_string(), "2GiB");
+        assert_eq!(q.convert(Unit::Bytes), Some(2_147_483_648.0));
+        assert_eq!(q.convert(Unit::Megabytes), Some(2048.0));
+        assert_eq!(q.convert(Unit::MillisPerOp), None);
+        assert_eq!(
+            parse("500ms").and_then(|q| q.convert(Unit::Microseconds)),
+            Some(500_000.0)
+        );
+        assert_eq!(parse("1.5s").and_then(|q| q.convert(Unit::MillisPerOp)), Some(1500.0));
+        assert_eq!(parse("3GHz").and_then(|q| q.convert(Unit::Hz)), Some(3e9));
+        assert_eq!(parse("10MB/s").and_then(|q| q.convert(Unit::BytesPerSec)), Some(1e7));
+        assert_eq!(parse("90%").and_then(|q| q.convert(Unit::Pct)), Some(90.0));
+        assert_eq!(parse("5").map(|q| q.suffix), None);
+        assert_eq!(parse("2sec"), None);
+    }
+
+    #[test]
+    fn multipliers_scale_plain_numbers() {
+        assert_eq!(number.parse("1.5k"), Ok(1500.0));
+        assert_eq!(number.parse("2M"), Ok(2e6));
+        assert_eq!(number.parse("7"), Ok(7.0));
+        assert!(number.parse("2GiB").is_err());
+        assert!(number.parse_peek("info.count").is_err());
+        assert!(number.parse_peek("nanos.total").is_err());
+        assert!(quantity.parse_peek("infs").is_err());
+    }
+}
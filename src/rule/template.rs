@@ -0,0 +1,251 @@
+//! Minijinja templating for finding `summary` and `suggest` strings.
+//!
+//! Templates see every signal under its dotted ID (`{{ net.retrans_pct }}`),
+//! the `host.*` context values, and three finding-specific variables:
+//!
+//! * `evidence` — the finding's evidence, each with `id`, `value` and `detail`;
+//! * `matched` — the concrete signals behind the glob terms that held, each
+//!   with `id`, `value` and `device` (the segments between the family and
+//!   the metric, so `sda` for `disk.sda.util_pct`);
+//! * `device` — the `device` of the first entry in `matched`, e.g. the disk
+//!   picked by `max(disk.*.util_pct)`.
+//!
+//! Sampled signals read as their p50, as they do in predicates. Undefined
+//! names render empty. Text that does not compile as a template, such as
+//! `docker ps --format '{{.Names}}'`, is shown verbatim and reported by
+//! `rules lint`; `{% raw %}…{% endraw %}` marks such text as literal. A
+//! template that fails to render at run time is kept verbatim and logged.
+
+use std::collections::BTreeMap;
+
+use minijinja::{Environment, UndefinedBehavior, Value};
+
+use super::{LhsValue, SignalIndex, SignalMatch};
+use crate::collector::CollectCtx;
+use crate::finding::Evidence;
+use crate::signal::SignalValue;
+
+const HOST_PATHS: [&str; 5] = ["host.cpu_count", "host.os", "host.kernel", "host.arch", "host.uname"];
+
+fn environment() -> Environment<'static> {
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Chainable);
+    env
+}
+
+/// Compile `source` without rendering it. Text that fails is not an error
+/// when the rule runs, only shown verbatim, so this is for `rules lint`.
+pub(crate) fn check(source: &str) -> Result<(), super::Error> {
+    if !is_template(source) {
+        return Ok(());
+    }
+    environment()
+        .template_from_str(source)
+        .map(|_| ())
+        .map_err(|e| super::Error::Template {
+            text: source.to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Variables a finding's templates render against.
+pub(crate) struct TemplateContext(Value);
+
+impl TemplateContext {
+    pub(crate) fn new(
+        signals_index: &SignalIndex<'_>,
+        ctx: &CollectCtx,
+        evidence: &[Evidence],
+        matches: &[SignalMatch],
+    ) -> Self {
+        let mut root = Node::default();
+        for signal in signals_index.signals() {
+            root.insert(&signal.id, lhs_to_value(signals_index.value(signal)));
+        }
+        for path in HOST_PATHS {
+            if let Some(v) = ctx.host_value(path) {
+                root.insert(path, signal_to_value(&v));
+            }
+        }
+        let mut vars = root.into_value_map();
+        let evidence: Vec<Value> = evidence
+            .iter()
+            .map(|e| {
+                Value::from_iter([
+                    ("id", Value::from(e.signal_id.as_str())),
+                    ("value", signal_to_value(&e.observed)),
+                    ("detail", e.detail.as_deref().map(Value::from).unwrap_or_default()),
+                ])
+            })
+            .collect();
+        let matched: Vec<Value> = matches
+            .iter()
+            .map(|m| {
+                let value = signals_index
+                    .get(&m.id)
+                    .map(|s| lhs_to_value(signals_index.value(s)))
+                    .unwrap_or_default();
+                Value::from_iter([
+                    ("id", Value::from(m.id.as_str())),
+                    ("value", value),
+                    ("device", device_of(&m.id).map(Value::from).unwrap_or_default()),
+                ])
+            })
+            .collect();
+        let device = matches.iter().find_map(|m| device_of(&m.id));
+        vars.insert("evidence".to_string(), Value::from(evidence));
+        vars.insert("matched".to_string(), Value::from(matched));
+        vars.insert("device".to_string(), device.map(Value::from).unwrap_or_default());
+        TemplateContext(Value::from_iter(vars))
+    }
+
+    /// Render `source`; text that is not a template, or fails to render,
+    /// is returned unchanged.
+    pub(crate) fn render(&self, source: &str) -> String {
+        if check(source).is_err() {
+            return source.to_string();
+        }
+        match environment().render_str(source, &self.0) {
+            Ok(s) => s,
+            Err(e) => {
+                log::warn!("failed to render template {:?}: {}", source, e);
+                source.to_string()
+            }
+        }
+    }
+}
+
+/// Plain strings skip the template engine, so shell snippets such as
+/// `awk '/x/{print $5}'` never need escaping.
+fn is_template(source: &str) -> bool {
+    source.contains("{{") || source.contains("{%") || source.contains("{#")
+}
+
+/// `sda` for `disk.sda.util_pct`; `None` for IDs with fewer than three segments.
+fn device_of(id: &str) -> Option<String> {
+    let segments: Vec<&str> = id.split('.').collect();
+    (segments.len() >= 3).then(|| segments[1..segments.len() - 1].join("."))
+}
+
+fn lhs_to_value(v: LhsValue) -> Value {
+    match v {
+        LhsValue::Number(n) => Value::from(n),
+        LhsValue::Bool(b) => Value::from(b),
+        LhsValue::Text(s) => Value::from(s),
+        LhsValue::Severity(s) => Value::from(super::severity_name(s)),
+    }
+}
+
+fn signal_to_value(v: &SignalValue) -> Value {
+    match v {
+        SignalValue::F64(n) => Value::from(*n),
+        SignalValue::I64(n) => Value::from(*n),
+        SignalValue::Bool(b) => Value::from(*b),
+        SignalValue::Text(s) => Value::from(s.as_str()),
+    }
+}
+
+/// Dotted IDs folded into nested maps. When an ID is both a value and the
+/// prefix of longer IDs (`disk.max` next to `disk.max.util_pct`), the longer
+/// IDs win so that attribute access keeps working.
+#[derive(Default)]
+struct Node {
+    value: Option<Value>,
+    children: BTreeMap<String, Node>,
+}
+
+impl Node {
+    fn insert(&mut self, id: &str, value: Value) {
+        let mut node = self;
+        for segment in id.split('.') {
+            node = node.children.entry(segment.to_string()).or_default();
+        }
+        node.value = Some(value);
+    }
+
+    fn into_value_map(self) -> BTreeMap<String, Value> {
+        self.children
+            .into_iter()
+            .map(|(k, child)| (k, child.into_value()))
+            .collect()
+    }
+
+    fn into_value(self) -> Value {
+        if self.children.is_empty() {
+            self.value.unwrap_or_default()
+        } else {
+            Value::from_iter(self.into_value_map())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::{Labels, Signal, Unit};
+
+    fn signal(id: &str, v: f64) -> Signal {
+        Signal {
+            id: id.to_string(),
+            value: SignalValue::F64(v),
+            unit: Unit::Pct,
+            at: chrono::Local::now(),
+            samples: None,
+            stats: None,
+            baseline: None,
+            labels: Labels::new(),
+        }
+    }
+
+    #[test]
+    fn renders_signal_paths_filters_and_device() {
+        let signals = vec![signal("net.retrans_pct", 1.23456), signal("net.eth1.rx_drops", 12.0)];
+        let index = SignalIndex::build(&signals);
+        let matches = vec![SignalMatch {
+            id: "net.eth1.rx_drops".to_string(),
+            detail: None,
+        }];
+        let tc = TemplateContext::new(&index, &CollectCtx::default(), &[], &matches);
+        assert_eq!(
+            tc.render("Retransmits at {{ net.retrans_pct | round(2) }}% (threshold 1%)"),
+            "Retransmits at 1.23% (threshold 1%)"
+        );
+        assert_eq!(tc.render("ethtool -S {{ device }}"), "ethtool -S eth1");
+        assert_eq!(tc.render("{{ matched[0].value }}"), "12.0");
+    }
+
+    #[test]
+    fn plain_strings_and_undefined_names_are_harmless() {
+        let signals = vec![signal("disk.max", 1.0), signal("disk.max.util_pct", 2.0)];
+        let index = SignalIndex::build(&signals);
+        let tc = TemplateContext::new(&index, &CollectCtx::default(), &[], &[]);
+        assert_eq!(tc.render("awk '/x/{print $5}'"), "awk '/x/{print $5}'");
+        assert_eq!(tc.render("[{{ no.such.signal }}]"), "[]");
+        assert_eq!(tc.render("{{ disk.max.util_pct }}"), "2.0");
+        assert_eq!(
+            tc.render("{{ disk.max | round(\"x\") }}"),
+            "{{ disk.max | round(\"x\") }}"
+        );
+    }
+
+    #[test]
+    fn check_rejects_bad_syntax() {
+        assert!(check("ok {{ net.retrans_pct }}").is_ok());
+        assert!(check("plain {text}").is_ok());
+        let err = check("broken {{ net.retrans_pct ").unwrap_err();
+        assert!(err.to_string().starts_with("template \"broken"), "{err}");
+    }
+
+    #[test]
+    fn text_that_is_not_a_template_is_shown_verbatim() {
+        let signals = vec![signal("net.retrans_pct", 1.0)];
+        let index = SignalIndex::build(&signals);
+        let tc = TemplateContext::new(&index, &CollectCtx::default(), &[], &[]);
+        let docker = "docker ps --format '{{.Names}}'";
+        assert_eq!(tc.render(docker), docker);
+        assert_eq!(
+            tc.render("docker ps --format '{% raw %}{{.Names}}{% endraw %}' # {{ net.retrans_pct }}"),
+            "docker ps --format '{{.Names}}' # 1.0"
+        );
+    }
+}
@@ -0,0 +1,479 @@
+//! Evaluation traces for `usereport explain <rule-id> --against <report>`.
+//!
+//! A `Trace` mirrors the predicate tree. Each node records the term as
+//! written, whether it held, the values its operands resolved to and the
+//! signals it read that were absent:
+//!
+//! ```text
+//! [false] AND
+//!   [true]  net.retrans_pct > 1  (2.5 > 1)
+//!   [false] net.segs_out > 1000  (absent > 1000) — absent: net.segs_out
+//! ```
+//!
+//! Both sides of `AND` and `OR` are traced even when the first decides the
+//! result. `any()`/`all()` get one child per matching signal. The inner term
+//! of `sustained(...)` is traced against p50 values, the same values a
+//! predicate sees outside `sustained`; the per-sample outcome is in the
+//! node's detail.
+
+use std::fmt;
+
+use super::{
+    AggFunc, ArithOp, Expr, LhsValue, Predicate, Quantifier, Relation, Rhs, SignalIndex, Sustain, TextOp, Value,
+    compare_values, op_to_str, resolve_path, resolve_rhs, sample_pair, severity_name,
+};
+use crate::collector::CollectCtx;
+
+/// One evaluated term of a predicate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trace {
+    /// The term as written, e.g. `net.retrans_pct > 1`; `AND`, `OR` and
+    /// `NOT` for the connectives.
+    pub term: String,
+    pub holds: bool,
+    /// Resolved operands, e.g. `2.5 > 1`.
+    pub detail: Option<String>,
+    /// Signal IDs (or globs without any match) the term read but the
+    /// signals did not contain.
+    pub absent: Vec<String>,
+    pub children: Vec<Trace>,
+}
+
+impl Trace {
+    /// Trace `predicate` against `signals_index`. `holds` of every node is
+    /// what `Predicate::evaluate` returns for that term.
+    pub fn evaluate(predicate: &Predicate, signals_index: &SignalIndex<'_>, ctx: &CollectCtx) -> Self {
+        let holds = predicate.evaluate(signals_index, ctx);
+        let node = |term: String, detail: Option<String>, absent: Vec<String>, children: Vec<Trace>| Trace {
+            term,
+            holds,
+            detail,
+            absent,
+            children,
+        };
+        match predicate {
+            Predicate::And(a, b) | Predicate::Or(a, b) => {
+                let term = if matches!(predicate, Predicate::And(..)) {
+                    "AND"
+                } else {
+                    "OR"
+                };
+                node(
+                    term.to_string(),
+                    None,
+                    Vec::new(),
+                    vec![
+                        Trace::evaluate(a, signals_index, ctx),
+                        Trace::evaluate(b, signals_index, ctx),
+                    ],
+                )
+            }
+            Predicate::Not(a) => node(
+                "NOT".to_string(),
+                None,
+                Vec::new(),
+                vec![Trace::evaluate(a, signals_index, ctx)],
+            ),
+            Predicate::Cmp { path, op, rhs } => {
+                let mut absent = Vec::new();
+                let lhs = resolve_path(path, signals_index, ctx);
+                if lhs.is_none() {
+                    absent.push(path.join("."));
+                }
+                let rhs_value = resolve_rhs(rhs, signals_index.unit_of(path), signals_index, ctx);
+                if let (Rhs::Path(p), None) = (rhs, &rhs_value) {
+                    absent.push(p.join("."));
+                }
+                let detail = format!("{} {} {}", show(&lhs), op_to_str(*op), show(&rhs_value));
+                node(term(predicate), Some(detail), absent, Vec::new())
+            }
+            Predicate::Arith { lhs, op, rhs } => {
+                let mut operands = Vec::new();
+                let mut absent = Vec::new();
+                lhs.trace_operands(signals_index, ctx, &mut operands, &mut absent);
+                rhs.trace_operands(signals_index, ctx, &mut operands, &mut absent);
+                let mut detail = format!(
+                    "{} {} {}",
+                    show_number(lhs.evaluate(signals_index, ctx)),
+                    op_to_str(*op),
+                    show_number(rhs.evaluate(signals_index, ctx))
+                );
+                if !operands.is_empty() {
+                    detail.push_str(&format!(" with {}", operands.join(", ")));
+                }
+                node(term(predicate), Some(detail), absent, Vec::new())
+            }
+            Predicate::Text { path, .. } => {
+                let value = resolve_path(path, signals_index, ctx);
+                let absent = if value.is_none() {
+                    vec![path.join(".")]
+                } else {
+                    Vec::new()
+                };
+                node(term(predicate), Some(show(&value)), absent, Vec::new())
+            }
+            Predicate::Quantified { glob, op, rhs, .. } => {
+                let matches = signals_index.matching(glob);
+                let mut absent = Vec::new();
+                if matches.is_empty() {
+                    absent.push(glob.join("."));
+                }
+                if let Rhs::Path(p) = rhs {
+                    if resolve_path(p, signals_index, ctx).is_none() {
+                        absent.push(p.join("."));
+                    }
+                }
+                let children = matches
+                    .iter()
+                    .map(|s| {
+                        let lhs = Some(signals_index.value(s));
+                        let rhs_value = resolve_rhs(rhs, s.unit, signals_index, ctx);
+                        Trace {
+                            term: format!("{} {} {}", s.id, op_to_str(*op), rhs_term(rhs)),
+                            holds: compare_values(lhs.clone(), *op, rhs_value.clone()),
+                            detail: Some(format!("{} {} {}", show(&lhs), op_to_str(*op), show(&rhs_value))),
+                            absent: Vec::new(),
+                            children: Vec::new(),
+                        }
+                    })
+                    .collect();
+                let detail = format!("{} matching signal(s)", matches.len());
+                node(term(predicate), Some(detail), absent, children)
+            }
+            Predicate::Sustained { inner, min } => {
+                let required = match min {
+                    Sustain::Percent(p) => format!("{}% of samples", p),
+                    Sustain::Samples(k) => format!("{} samples", k),
+                };
+                let detail = match inner.sample_run(signals_index, ctx) {
+                    Some(run) => format!("{}; needs {}", run.describe(ctx.interval), required),
+                    None => "no sampled signal".to_string(),
+                };
+                node(
+                    term(predicate),
+                    Some(detail),
+                    Vec::new(),
+                    vec![Trace::evaluate(inner, signals_index, ctx)],
+                )
+            }
+            Predicate::Temporal {
+                relation,
+                first,
+                second,
+            } => {
+                let detail = match sample_pair(first, second, signals_index, ctx) {
+                    Some(pair) => pair.describe(*relation, ctx.interval),
+                    None => "no sampled signal".to_string(),
+                };
+                node(
+                    term(predicate),
+                    Some(detail),
+                    Vec::new(),
+                    vec![
+                        Trace::evaluate(first, signals_index, ctx),
+                        Trace::evaluate(second, signals_index, ctx),
+                    ],
+                )
+            }
+        }
+    }
+
+    /// Every absent signal in the tree, in order of first appearance.
+    pub fn absent_signals(&self) -> Vec<String> {
+        let mut out = Vec::new();
+        self.collect_absent(&mut out);
+        out
+    }
+
+    fn collect_absent(&self, out: &mut Vec<String>) {
+        for id in &self.absent {
+            if !out.contains(id) {
+                out.push(id.clone());
+            }
+        }
+        for child in &self.children {
+            child.collect_absent(out);
+        }
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        let result = if self.holds { "[true] " } else { "[false]" };
+        write!(f, "{:indent$}{} {}", "", result, self.term, indent = depth * 2)?;
+        if let Some(detail) = &self.detail {
+            write!(f, "  ({})", detail)?;
+        }
+        if !self.absent.is_empty() {
+            write!(f, " — absent: {}", self.absent.join(", "))?;
+        }
+        writeln!(f)?;
+        for child in &self.children {
+            child.fmt_indented(f, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl Expr {
+    /// Push `name = value` for every path and aggregate in the expression,
+    /// and the IDs of those that did not resolve to a number.
+    fn trace_operands(
+        &self,
+        signals_index: &SignalIndex<'_>,
+        ctx: &CollectCtx,
+        operands: &mut Vec<String>,
+        absent: &mut Vec<String>,
+    ) {
+        match self {
+            Expr::Number(_) => {}
+            Expr::Binary { lhs, rhs, .. } => {
+                lhs.trace_operands(signals_index, ctx, operands, absent);
+                rhs.trace_operands(signals_index, ctx, operands, absent);
+            }
+            Expr::Path(p) => {
+                let value = resolve_path(p, signals_index, ctx);
+                if value.is_none() {
+                    absent.push(p.join("."));
+                }
+                operands.push(format!("{} = {}", p.join("."), show(&value)));
+            }
+            Expr::Delta(_) => {
+                let value = self.evaluate(signals_index, ctx);
+                if value.is_none() {
+                    absent.push(expr(self));
+                }
+                operands.push(format!("{} = {}", expr(self), show_number(value)));
+            }
+            Expr::Aggregate { glob, .. } => {
+                if signals_index.matching(glob).is_empty() {
+                    absent.push(glob.join("."));
+                }
+                operands.push(format!(
+                    "{} = {}",
+                    expr(self),
+                    show_number(self.evaluate(signals_index, ctx))
+                ));
+            }
+        }
+    }
+}
+
+/// The term as it would be written in a rule.
+fn term(predicate: &Predicate) -> String {
+    match predicate {
+        Predicate::Cmp { path, op, rhs } => format!("{} {} {}", path.join("."), op_to_str(*op), rhs_term(rhs)),
+        Predicate::And(a, b) => format!("({} AND {})", term(a), term(b)),
+        Predicate::Or(a, b) => format!("({} OR {})", term(a), term(b)),
+        Predicate::Not(a) => format!("NOT {}", term(a)),
+        Predicate::Arith { lhs, op, rhs } => format!("{} {} {}", expr(lhs), op_to_str(*op), expr(rhs)),
+        Predicate::Quantified {
+            quantifier,
+            glob,
+            op,
+            rhs,
+        } => {
+            let q = match quantifier {
+                Quantifier::Any => "any",
+                Quantifier::All => "all",
+            };
+            format!("{}({}) {} {}", q, glob.join("."), op_to_str(*op), rhs_term(rhs))
+        }
+        Predicate::Sustained { inner, min } => {
+            let inner = term(inner);
+            let inner = inner
+                .strip_prefix('(')
+                .and_then(|s| s.strip_suffix(')'))
+                .unwrap_or(&inner);
+            match min {
+                Sustain::Percent(p) => format!("sustained({}, {}%)", inner, p),
+                Sustain::Samples(k) => format!("sustained({}, {})", inner, k),
+            }
+        }
+        Predicate::Temporal {
+            relation,
+            first,
+            second,
+        } => {
+            let (first, second) = (term(first), term(second));
+            match relation {
+                Relation::Together(Sustain::Percent(p)) => format!("together({}, {}, {}%)", first, second, p),
+                Relation::Together(Sustain::Samples(k)) => format!("together({}, {}, {})", first, second, k),
+                Relation::Leads(n) => format!("leads({}, {}, {})", first, second, n),
+            }
+        }
+        Predicate::Text { path, op } => {
+            let path = path.join(".");
+            match op {
+                TextOp::Matches(re) => format!("{} =~ {}", path, quote(re.as_str())),
+                TextOp::NotMatches(re) => format!("{} !~ {}", path, quote(re.as_str())),
+                TextOp::StartsWith(s) => format!("starts_with({}, {})", path, quote(s)),
+                TextOp::EndsWith(s) => format!("ends_with({}, {})", path, quote(s)),
+                TextOp::Contains(s) => format!("contains({}, {})", path, quote(s)),
+            }
+        }
+    }
+}
+
+fn rhs_term(rhs: &Rhs) -> String {
+    match rhs {
+        Rhs::Path(p) => p.join("."),
+        Rhs::Value(Value::Number(n)) => n.to_string(),
+        Rhs::Value(Value::Bool(b)) => b.to_string(),
+        Rhs::Value(Value::Str(s)) => quote(s),
+        Rhs::Value(Value::Severity(s)) => severity_name(*s).to_string(),
+        Rhs::Value(Value::Quantity(q)) => q.to_string(),
+    }
+}
+
+/// The DSL has no escapes: strings are quoted verbatim, in single quotes
+/// when they contain a double quote.
+fn quote(s: &str) -> String {
+    if s.contains('"') {
+        format!("'{}'", s)
+    } else {
+        format!("\"{}\"", s)
+    }
+}
+
+fn expr(e: &Expr) -> String {
+    match e {
+        Expr::Number(n) => n.to_string(),
+        Expr::Path(p) => p.join("."),
+        Expr::Delta(p) => format!("delta({})", p.join(".")),
+        Expr::Aggregate { func, glob } => {
+            let f = match func {
+                AggFunc::Max => "max",
+                AggFunc::Min => "min",
+                AggFunc::Sum => "sum",
+                AggFunc::Count => "count",
+            };
+            format!("{}({})", f, glob.join("."))
+        }
+        Expr::Binary { op, lhs, rhs } => {
+            let o = match op {
+                ArithOp::Add => "+",
+                ArithOp::Sub => "-",
+                ArithOp::Mul => "*",
+                ArithOp::Div => "/",
+            };
+            format!("{} {} {}", operand(lhs, *op, false), o, operand(rhs, *op, true))
+        }
+    }
+}
+
+/// `e` as an operand of `parent`, parenthesized where precedence or the
+/// left associativity of `-` and `/` requires it.
+fn operand(e: &Expr, parent: ArithOp, right: bool) -> String {
+    let rank = |op: ArithOp| match op {
+        ArithOp::Add | ArithOp::Sub => 0,
+        ArithOp::Mul | ArithOp::Div => 1,
+    };
+    match e {
+        Expr::Binary { op, .. } if rank(*op) < rank(parent) || (right && rank(*op) == rank(parent)) => {
+            format!("({})", expr(e))
+        }
+        _ => expr(e),
+    }
+}
+
+fn show(value: &Option<LhsValue>) -> String {
+    match value {
+        None => "absent".to_string(),
+        Some(LhsValue::Number(n)) => n.to_string(),
+        Some(LhsValue::Bool(b)) => b.to_string(),
+        Some(LhsValue::Text(s)) => format!("{:?}", s),
+        Some(LhsValue::Severity(s)) => severity_name(*s).to_string(),
+    }
+}
+
+fn show_number(value: Option<f64>) -> String {
+    value.map(|n| n.to_string()).unwrap_or_else(|| "absent".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signal::{Labels, Signal, SignalValue, Unit};
+
+    fn signal(id: &str, v: f64) -> Signal {
+        Signal {
+            id: id.to_string(),
+            value: SignalValue::F64(v),
+            unit: Unit::None,
+            at: chrono::Local::now(),
+            samples: None,
+            stats: None,
+            baseline: None,
+            labels: Labels::new(),
+        }
+    }
+
+    #[test]
+    fn traces_every_term_with_resolved_values_and_absent_signals() {
+        let signals = vec![signal("net.retrans_pct", 2.5)];
+        let index = SignalIndex::build(&signals);
+        let p = Predicate::parse("net.retrans_pct > 1 AND net.segs_out > 1000").expect("parse");
+        let trace = Trace::evaluate(&p, &index, &CollectCtx::default());
+        assert!(!trace.holds);
+        assert_eq!(
+            trace.to_string(),
+            "[false] AND\n  [true]  net.retrans_pct > 1  (2.5 > 1)\n  [false] net.segs_out > 1000  (absent > 1000) — absent: net.segs_out\n"
+        );
+        assert_eq!(trace.absent_signals(), vec!["net.segs_out".to_string()]);
+    }
+
+    #[test]
+    fn traces_arithmetic_operands_and_quantified_matches() {
+        let signals = vec![
+            signal("mem.used", 900.0),
+            signal("mem.total", 1000.0),
+            signal("disk.sda.util_pct", 95.0),
+            signal("disk.sdb.util_pct", 10.0),
+        ];
+        let index = SignalIndex::build(&signals);
+        let ctx = CollectCtx::default();
+
+        let p = Predicate::parse("mem.used / mem.total > 0.8").expect("parse");
+        let trace = Trace::evaluate(&p, &index, &ctx);
+        assert!(trace.holds);
+        assert_eq!(trace.term, "mem.used / mem.total > 0.8");
+        assert_eq!(
+            trace.detail.as_deref(),
+            Some("0.9 > 0.8 with mem.used = 900, mem.total = 1000")
+        );
+
+        let p = Predicate::parse("all(disk.*.util_pct) > 90").expect("parse");
+        let trace = Trace::evaluate(&p, &index, &ctx);
+        assert!(!trace.holds);
+        let children: Vec<(&str, bool)> = trace.children.iter().map(|c| (c.term.as_str(), c.holds)).collect();
+        assert_eq!(
+            children,
+            vec![("disk.sda.util_pct > 90", true), ("disk.sdb.util_pct > 90", false)]
+        );
+
+        let p = Predicate::parse("any(net.*.rx_drops) > 0").expect("parse");
+        let trace = Trace::evaluate(&p, &index, &ctx);
+        assert_eq!(trace.absent, vec!["net.*.rx_drops".to_string()]);
+    }
+
+    #[test]
+    fn renders_terms_as_written() {
+        for src in [
+            "NOT (a > 1 OR b == \"x\")",
+            "(a - (b - c)) * 2 >= max(disk.*.await_ms)",
+            "sustained(cpu.iowait_pct > 30 AND x == true, 80%)",
+            "together(a > 1 OR b > 1, c < 2, 3)",
+            "leads(a > 1, NOT (b > 1), 2)",
+            "host.kernel =~ \"^5\\.\"",
+        ] {
+            let p = Predicate::parse(src).expect("parse");
+            let rendered = term(&p);
+            assert_eq!(Predicate::parse(&rendered).expect("reparse"), p, "{rendered}");
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Catalog of the signals the built-in collectors emit.
+//!
+//! Every collector module declares its signals in a `SIGNALS` table: ID,
+//! unit, kind, the USE resource and aspect it measures, and a description.
+//! A `*` segment in an ID stands for a device name, as in
+//! `disk.*.util_pct`. `explain`, `rules lint`, USE coverage and the LLM
+//! output read the catalog; `usereport signals` lists it.
+
+use serde::{Deserialize, Serialize};
+
+use super::Unit;
+use crate::collector::{cgroup, cpu, cpufreq, disk, dmesg, host, interrupts, memory, network};
+use crate::report_context::UseDimension;
+
+/// How a signal's value behaves over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignalKind {
+    /// Count of events, e.g. OOM kills or dropped packets.
+    Counter,
+    /// Level at a point in time, e.g. free memory or run queue length.
+    Gauge,
+    /// Events per second, e.g. context switches or IOPS.
+    Rate,
+    /// Yes/no state, e.g. whether a tool is installed.
+    Flag,
+    /// Free text, e.g. the enabled cgroup controllers.
+    Text,
+}
+
+impl SignalKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SignalKind::Counter => "counter",
+            SignalKind::Gauge => "gauge",
+            SignalKind::Rate => "rate",
+            SignalKind::Flag => "flag",
+            SignalKind::Text => "text",
+        }
+    }
+}
+
+/// Catalog entry for one signal, or one per-device family of signals.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SignalSpec {
+    pub id: &'static str,
+    /// ID of the collector that emits it.
+    pub collector: &'static str,
+    pub unit: Unit,
+    pub kind: SignalKind,
+    /// USE resource, e.g. `disk`; `None` for signals outside the USE grid.
+    pub resource: Option<&'static str>,
+    /// USE aspect: `utilization`, `saturation` or `errors`.
+    pub aspect: Option<&'static str>,
+    /// Label key the signal carries, e.g. `device` for `disk.*.util_pct`.
+    pub label: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl SignalSpec {
+    pub const fn new(
+        collector: &'static str,
+        id: &'static str,
+        unit: Unit,
+        kind: SignalKind,
+        description: &'static str,
+    ) -> Self {
+        SignalSpec {
+            id,
+            collector,
+            unit,
+            kind,
+            resource: None,
+            aspect: None,
+            label: None,
+            description,
+        }
+    }
+
+    /// Place the signal in the USE grid.
+    pub const fn with_use(self, resource: &'static str, aspect: &'static str) -> Self {
+        SignalSpec {
+            resource: Some(resource),
+            aspect: Some(aspect),
+            ..self
+        }
+    }
+
+    /// Declare the label key the signal carries; see `signal::label`.
+    pub const fn labelled(self, key: &'static str) -> Self {
+        SignalSpec {
+            label: Some(key),
+            ..self
+        }
+    }
+
+    pub fn use_dimension(&self) -> Option<UseDimension> {
+        Some(UseDimension {
+            resource: self.resource?.to_string(),
+            aspect: self.aspect?.to_string(),
+        })
+    }
+
+    /// Whether `id` is this signal, or a member of its per-device family.
+    pub fn matches(&self, id: &str) -> bool {
+        let pattern: Vec<&str> = self.id.split('.').collect();
+        let segments: Vec<&str> = id.split('.').collect();
+        pattern.len() == segments.len()
+            && pattern
+                .iter()
+                .zip(segments)
+                .all(|(p, s)| crate::rule::segment_matches(p, s))
+    }
+}
+
+/// Signals of the `bpf` collector. Declared here rather than in
+/// `collector::bpf`, which only exists with the `bpf` feature, so that rules
+/// reading them lint the same either way.
+const BPF_SIGNALS: &[SignalSpec] = &[
+    SignalSpec::new(
+        "bpf",
+        "bpf.runqlat.usecs",
+        Unit::Microseconds,
+        SignalKind::Gauge,
+        "Run-queue latency p50 (µs)",
+    )
+    .with_use("cpu", "saturation"),
+    SignalSpec::new(
+        "bpf",
+        "bpf.biolatency.usecs",
+        Unit::Microseconds,
+        SignalKind::Gauge,
+        "Block I/O latency p50 (µs)",
+    )
+    .with_use("disk", "saturation"),
+    SignalSpec::new(
+        "bpf",
+        "bpf.cachestat.usecs",
+        Unit::Microseconds,
+        SignalKind::Gauge,
+        "Page-cache hit latency p50 (µs)",
+    ),
+    SignalSpec::new(
+        "bpf",
+        "bpf.runqlat.available",
+        Unit::None,
+        SignalKind::Flag,
+        "Whether runqlat is available in PATH",
+    ),
+    SignalSpec::new(
+        "bpf",
+        "bpf.biolatency.available",
+        Unit::None,
+        SignalKind::Flag,
+        "Whether biolatency is available in PATH",
+    ),
+    SignalSpec::new(
+        "bpf",
+        "bpf.tcpretrans.available",
+        Unit::None,
+        SignalKind::Flag,
+        "Whether tcpretrans is available in PATH",
+    ),
+    SignalSpec::new(
+        "bpf",
+        "bpf.execsnoop.available",
+        Unit::None,
+        SignalKind::Flag,
+        "Whether execsnoop is available in PATH",
+    ),
+    SignalSpec::new(
+        "bpf",
+        "bpf.cachestat.available",
+        Unit::None,
+        SignalKind::Flag,
+        "Whether cachestat is available in PATH",
+    ),
+];
+
+/// Every built-in signal, grouped by collector.
+pub fn builtin() -> impl Iterator<Item = &'static SignalSpec> {
+    [
+        cpu::SIGNALS,
+        cpufreq::SIGNALS,
+        host::SIGNALS,
+        memory::SIGNALS,
+        network::SIGNALS,
+        disk::SIGNALS,
+        interrupts::SIGNALS,
+        cgroup::SIGNALS,
+        dmesg::SIGNALS,
+        BPF_SIGNALS,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// The catalog entry for `id`: an exact match, else the per-device family
+/// it belongs to, e.g. `disk.*.util_pct` for `disk.sda.util_pct`.
+pub fn lookup(id: &str) -> Option<&'static SignalSpec> {
+    builtin()
+        .find(|s| s.id == id)
+        .or_else(|| builtin().find(|s| s.matches(id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_unique_and_use_aspects_valid() {
+        let mut seen = std::collections::HashSet::new();
+        for spec in builtin() {
+            assert!(seen.insert(spec.id), "duplicate catalog entry {}", spec.id);
+            assert_eq!(spec.resource.is_some(), spec.aspect.is_some(), "{}", spec.id);
+            if let Some(aspect) = spec.aspect {
+                assert!(
+                    ["utilization", "saturation", "errors"].contains(&aspect),
+                    "{}: {aspect}",
+                    spec.id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_resolves_device_families() {
+        assert_eq!(lookup("disk.sda.util_pct").map(|s| s.id), Some("disk.*.util_pct"));
+        assert_eq!(lookup("disk.max_util_pct").map(|s| s.id), Some("disk.max_util_pct"));
+        assert_eq!(lookup("cpu.iowait_pct").map(|s| s.kind), Some(SignalKind::Gauge));
+        assert!(lookup("disk.sda.nvme.util_pct").is_none());
+        assert!(lookup("api.p99_ms").is_none());
+    }
+}
@@ -0,0 +1,408 @@
+//! Typed metric values produced by `Collector` implementations.
+//!
+//! A `Signal` is the unit of evidence the rule engine evaluates. Every signal
+//! has a stable dotted ID (e.g. `cpu.iowait_pct`), a typed value, a unit, and
+//! a timestamp. Sampled signals additionally carry per-sample values; baseline
+//! annotation is added by the baseline subsystem (Phase 2).
+//!
+//! Per-device and per-cgroup signals carry labels naming the dimension they
+//! are broken down by, e.g. `device = "sda"` for `disk.sda.util_pct`. The ID
+//! stays unique on its own so rules, silences and baselines keep addressing
+//! signals by ID; labels let consumers group signals and tell apart signals
+//! whose ID does not embed the dimension, such as `cgroup.memory_bytes` of
+//! two different cgroups.
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+pub mod catalog;
+
+/// Well-known label keys.
+pub mod label {
+    /// Block device, e.g. `sda`.
+    pub const DEVICE: &str = "device";
+    /// Network interface, e.g. `eth0`.
+    pub const IFACE: &str = "iface";
+    /// CPU number, e.g. `3`.
+    pub const CPU: &str = "cpu";
+    /// cgroup directory, e.g. `/sys/fs/cgroup/system.slice/nginx.service`.
+    pub const CGROUP: &str = "cgroup";
+    /// Process ID.
+    pub const PID: &str = "pid";
+}
+
+/// Label key → value, e.g. `device` → `sda`.
+pub type Labels = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Signal {
+    pub id: String,
+    pub value: SignalValue,
+    pub unit: Unit,
+    pub at: DateTime<Local>,
+    pub samples: Option<Vec<f64>>,
+    /// Pre-computed statistics for sampled signals. Set by collectors that
+    /// support sampling; `None` for single-shot signals.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<SampleStats>,
+    pub baseline: Option<BaselineStats>,
+    /// Dimensions the signal is broken down by. Empty for host-wide signals
+    /// and in reports written before labels existed.
+    #[serde(default, skip_serializing_if = "Labels::is_empty")]
+    pub labels: Labels,
+}
+
+impl Signal {
+    pub fn with_label<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn label(&self, key: &str) -> Option<&str> {
+        self.labels.get(key).map(String::as_str)
+    }
+
+    /// The dotted segments of the ID, keeping a label value the ID embeds as
+    /// one segment even when it contains dots: `disk.dm.0.util_pct` with
+    /// `device = "dm.0"` splits into `disk`, `dm.0`, `util_pct`.
+    pub fn segments(&self) -> Vec<&str> {
+        let mut values: Vec<&str> = self
+            .labels
+            .values()
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+            .collect();
+        values.sort_by_key(|v| std::cmp::Reverse(v.len()));
+        let mut segments = Vec::new();
+        let mut rest = self.id.as_str();
+        while !rest.is_empty() {
+            let embedded = values.iter().find(|v| {
+                rest.strip_prefix(**v)
+                    .is_some_and(|tail| tail.is_empty() || tail.starts_with('.'))
+            });
+            let (segment, tail) = match embedded {
+                Some(v) => rest.split_at(v.len()),
+                None => rest.split_at(rest.find('.').unwrap_or(rest.len())),
+            };
+            segments.push(segment);
+            rest = tail.strip_prefix('.').unwrap_or(tail);
+        }
+        segments
+    }
+
+    /// Labels whose value is not a segment of the ID, e.g. the `cgroup` of
+    /// `cgroup.memory_bytes`.
+    fn unembedded_labels(&self) -> impl Iterator<Item = (&String, &String)> {
+        let segments = self.segments();
+        self.labels.iter().filter(move |(_, v)| !segments.contains(&v.as_str()))
+    }
+
+    /// What identifies the signal across reports: the ID, followed by the
+    /// labels it does not embed, e.g.
+    /// `cgroup.memory_bytes{cgroup=/sys/fs/cgroup/app}`. Just the ID for
+    /// signals without such labels.
+    pub fn key(&self) -> String {
+        let extra: Vec<String> = self.unembedded_labels().map(|(k, v)| format!("{k}={v}")).collect();
+        if extra.is_empty() {
+            self.id.clone()
+        } else {
+            format!("{}{{{}}}", self.id, extra.join(","))
+        }
+    }
+}
+
+/// Signals that embed a label in their ID, laid out with one row per label
+/// value and one column per metric, e.g. `disk` by `device` with columns
+/// `read_iops`, `util_pct`, ... Templates get these through the
+/// `label_tables` filter.
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelTable {
+    /// The ID segments before the label, e.g. `disk`.
+    pub prefix: String,
+    /// The label key, e.g. `device`.
+    pub label: String,
+    /// The ID segments after the label, in collection order.
+    pub columns: Vec<String>,
+    /// Unit of each column, for the `human` filter.
+    pub units: Vec<Unit>,
+    /// One row per label value, sorted by value.
+    pub rows: Vec<LabelRow>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelRow {
+    pub value: String,
+    /// Values in `columns` order; `None` where the signal is missing.
+    pub cells: Vec<Option<SignalValue>>,
+}
+
+/// Group the signals that embed a label in their ID into `LabelTable`s.
+pub fn label_tables(signals: &[Signal]) -> Vec<LabelTable> {
+    type Columns = Vec<(String, Unit)>;
+    type Cells = BTreeMap<String, BTreeMap<String, SignalValue>>;
+    let mut tables: BTreeMap<(String, String), (Columns, Cells)> = BTreeMap::new();
+    for signal in signals {
+        let segments = signal.segments();
+        for (key, value) in &signal.labels {
+            let Some(i) = segments.iter().position(|s| s == value) else {
+                continue;
+            };
+            if i + 1 == segments.len() {
+                continue;
+            }
+            let column = segments[i + 1..].join(".");
+            let (columns, rows) = tables.entry((segments[..i].join("."), key.clone())).or_default();
+            if !columns.iter().any(|(c, _)| *c == column) {
+                columns.push((column.clone(), signal.unit));
+            }
+            rows.entry(value.clone())
+                .or_default()
+                .insert(column, signal.value.clone());
+        }
+    }
+    tables
+        .into_iter()
+        .map(|((prefix, label), (columns, rows))| LabelTable {
+            rows: rows
+                .into_iter()
+                .map(|(value, mut cells)| LabelRow {
+                    value,
+                    cells: columns.iter().map(|(c, _)| cells.remove(c)).collect(),
+                })
+                .collect(),
+            prefix,
+            label,
+            units: columns.iter().map(|(_, unit)| *unit).collect(),
+            columns: columns.into_iter().map(|(c, _)| c).collect(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SignalValue {
+    F64(f64),
+    I64(i64),
+    Bool(bool),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Unit {
+    #[serde(alias = "pct")]
+    Pct,
+    #[serde(alias = "ms")]
+    MillisPerOp,
+    #[serde(alias = "bytes_per_sec")]
+    BytesPerSec,
+    #[serde(alias = "count")]
+    Count,
+    #[serde(alias = "mb")]
+    Megabytes,
+    #[serde(alias = "iops")]
+    Iops,
+    #[serde(alias = "microseconds")]
+    Microseconds,
+    #[serde(alias = "hz")]
+    Hz,
+    #[serde(alias = "celsius")]
+    Celsius,
+    #[serde(alias = "none")]
+    None,
+    #[serde(alias = "bytes")]
+    Bytes,
+}
+
+impl Unit {
+    pub fn is_none(&self) -> bool {
+        matches!(self, Unit::None)
+    }
+
+    /// What the unit measures, and the size of one unit in the dimension's
+    /// base unit: bytes, seconds, bytes/s, Hz or percent. `Megabytes` are
+    /// MiB, as `free -m` reports them. `None` for counts, rates of events,
+    /// temperatures and unitless signals.
+    pub fn scale(&self) -> Option<(Dimension, f64)> {
+        match self {
+            Unit::Bytes => Some((Dimension::Size, 1.0)),
+            Unit::Megabytes => Some((Dimension::Size, 1024.0 * 1024.0)),
+            Unit::MillisPerOp => Some((Dimension::Time, 1e-3)),
+            Unit::Microseconds => Some((Dimension::Time, 1e-6)),
+            Unit::BytesPerSec => Some((Dimension::Throughput, 1.0)),
+            Unit::Hz => Some((Dimension::Frequency, 1.0)),
+            Unit::Pct => Some((Dimension::Percent, 1.0)),
+            Unit::Count | Unit::Iops | Unit::Celsius | Unit::None => None,
+        }
+    }
+}
+
+/// What a unit measures. Values convert between units of one dimension only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Size,
+    Time,
+    Throughput,
+    Frequency,
+    Percent,
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Dimension::Size => "a size",
+            Dimension::Time => "a duration",
+            Dimension::Throughput => "a throughput",
+            Dimension::Frequency => "a frequency",
+            Dimension::Percent => "a percentage",
+        })
+    }
+}
+
+/// Format `value`, measured in `unit`, for people: `1.5 GiB`, `250 ms`,
+/// `2.4 GHz`, `1.2k iops`. Sizes use binary prefixes, everything else
+/// decimal ones. Values without a unit are only rounded.
+pub fn humanize(value: f64, unit: Unit) -> String {
+    const SIZE: &[(f64, &str)] = &[
+        (1_099_511_627_776.0, "TiB"),
+        (1_073_741_824.0, "GiB"),
+        (1_048_576.0, "MiB"),
+        (1024.0, "KiB"),
+        (1.0, "B"),
+    ];
+    const TIME: &[(f64, &str)] = &[
+        (3600.0, "h"),
+        (60.0, "min"),
+        (1.0, "s"),
+        (1e-3, "ms"),
+        (1e-6, "µs"),
+        (1e-9, "ns"),
+    ];
+    const FREQUENCY: &[(f64, &str)] = &[(1e9, "GHz"), (1e6, "MHz"), (1e3, "kHz"), (1.0, "Hz")];
+    const COUNT: &[(f64, &str)] = &[(1e9, "G"), (1e6, "M"), (1e3, "k"), (1.0, "")];
+
+    /// `value` in the largest of `steps` it reaches, each step being the
+    /// size of the suffix in the base unit.
+    fn scaled(value: f64, steps: &[(f64, &str)], sep: &str, tail: &str) -> String {
+        let (size, suffix) = steps
+            .iter()
+            .find(|(size, _)| value.abs() >= *size || (value == 0.0 && *size == 1.0))
+            .unwrap_or(&steps[steps.len() - 1]);
+        let n = value / size;
+        let n = if n.fract() == 0.0 {
+            format!("{n}")
+        } else {
+            format!("{n:.1}")
+        };
+        if suffix.is_empty() && tail.is_empty() {
+            n
+        } else {
+            format!("{n}{sep}{suffix}{tail}")
+        }
+    }
+
+    match unit.scale() {
+        Some((Dimension::Size, scale)) => scaled(value * scale, SIZE, " ", ""),
+        Some((Dimension::Throughput, scale)) => scaled(value * scale, SIZE, " ", "/s"),
+        Some((Dimension::Time, scale)) => scaled(value * scale, TIME, " ", ""),
+        Some((Dimension::Frequency, scale)) => scaled(value * scale, FREQUENCY, " ", ""),
+        Some((Dimension::Percent, _)) => format!("{}%", (value * 10.0).round() / 10.0),
+        None => match unit {
+            Unit::Celsius => format!("{:.1} °C", value),
+            Unit::Iops => scaled(value, COUNT, "", " iops"),
+            Unit::None => format!("{}", (value * 1000.0).round() / 1000.0),
+            _ => scaled(value, COUNT, "", ""),
+        },
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Unit::Pct => "percent",
+            Unit::MillisPerOp => "ms",
+            Unit::BytesPerSec => "bytes/s",
+            Unit::Count => "count",
+            Unit::Megabytes => "MB",
+            Unit::Iops => "iops",
+            Unit::Microseconds => "µs",
+            Unit::Hz => "Hz",
+            Unit::Celsius => "celsius",
+            Unit::None => "none",
+            Unit::Bytes => "bytes",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineStats {
+    pub p50: f64,
+    pub p95: f64,
+    pub mad: f64,
+    pub z_score: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampleStats {
+    pub min: f64,
+    pub max: f64,
+    pub p50: f64,
+    pub p95: f64,
+    pub p99: f64,
+    pub trend: Trend,
+    /// The fields below are `None` in reports written before they existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mean: Option<f64>,
+    /// Sample standard deviation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stddev: Option<f64>,
+    /// Coefficient of variation, `stddev / |mean|`; 0 when the mean is 0.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cv: Option<f64>,
+    /// Least-squares regression slope, in units per second.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slope: Option<f64>,
+    /// Number of samples.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Flat,
+}
+
+impl SignalValue {
+    /// Coerce a numeric value to f64 for predicate comparisons. Bool and Text
+    /// return `None`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            SignalValue::F64(v) => Some(*v),
+            SignalValue::I64(v) => Some(*v as f64),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn humanize_picks_the_largest_prefix_reached() {
+        assert_eq!(humanize(1536.0, Unit::Megabytes), "1.5 GiB");
+        assert_eq!(humanize(512.0, Unit::Bytes), "512 B");
+        assert_eq!(humanize(250.0, Unit::MillisPerOp), "250 ms");
+        assert_eq!(humanize(1500.0, Unit::MillisPerOp), "1.5 s");
+        assert_eq!(humanize(0.0, Unit::Microseconds), "0 s");
+        assert_eq!(humanize(2_400_000_000.0, Unit::Hz), "2.4 GHz");
+        assert_eq!(humanize(3_145_728.0, Unit::BytesPerSec), "3 MiB/s");
+        assert_eq!(humanize(12.345, Unit::Pct), "12.3%");
+        assert_eq!(humanize(1234.0, Unit::Iops), "1.2k iops");
+        assert_eq!(humanize(42.0, Unit::Count), "42");
+        assert_eq!(humanize(123_456.789_12, Unit::None), "123456.789");
+    }
+}
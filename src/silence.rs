@@ -0,0 +1,274 @@
+//! Silences: acknowledged findings that stay out of the way until they expire.
+//!
+//! A silences file holds `[[silence]]` tables. Each matches findings by ID,
+//! optionally narrowed to hosts and to an evidence condition, and must give
+//! an expiry date and a reason:
+//!
+//! ```toml
+//! [[silence]]
+//! id      = "net.irq_imbalance"
+//! host    = "edge-*"
+//! when    = "net.max_cpu_irq_pct < 99"
+//! expires = 2026-12-31
+//! reason  = "single-queue NIC; IRQs cannot be spread"
+//! ```
+//!
+//! `id` and `host` are patterns in which `*` matches any run of characters.
+//! `when` is a predicate evaluated against the finding's evidence values
+//! only; a signal the finding carries no evidence for is absent. A silence
+//! applies up to and including its `expires` date.
+
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::collector::CollectCtx;
+use crate::finding::Finding;
+use crate::rule::{Predicate, SignalIndex, segment_matches};
+use crate::signal::{Labels, Signal, Unit};
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("failed to read silences file: {0}")]
+    Read(#[from] std::io::Error),
+    #[error("failed to parse silences TOML: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("silence '{id}': {message}")]
+    Invalid { id: String, message: String },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// One acknowledged finding.
+#[derive(Debug, Clone)]
+pub struct Silence {
+    /// Finding ID pattern.
+    pub id: String,
+    /// Hostname pattern; `None` matches every host.
+    pub host: Option<String>,
+    /// Condition on the finding's evidence; `None` always holds.
+    pub when: Option<Predicate>,
+    pub expires: NaiveDate,
+    pub reason: String,
+}
+
+impl Silence {
+    /// Whether the silence still applies on `today`.
+    pub fn is_active(&self, today: NaiveDate) -> bool {
+        today <= self.expires
+    }
+
+    /// Whether the silence covers `finding` on host `hostname`, expiry aside.
+    pub fn matches(&self, finding: &Finding, hostname: &str) -> bool {
+        if !segment_matches(&self.id, &finding.id) {
+            return false;
+        }
+        if let Some(host) = &self.host {
+            if !segment_matches(host, hostname) {
+                return false;
+            }
+        }
+        match &self.when {
+            None => true,
+            Some(when) => {
+                let evidence: Vec<Signal> = finding
+                    .evidence
+                    .iter()
+                    .map(|e| Signal {
+                        id: e.signal_id.clone(),
+                        value: e.observed.clone(),
+                        unit: Unit::None,
+                        at: chrono::Local::now(),
+                        samples: None,
+                        stats: None,
+                        baseline: None,
+                        labels: Labels::new(),
+                    })
+                    .collect();
+                when.evaluate(&SignalIndex::build(&evidence), &CollectCtx::default())
+            }
+        }
+    }
+}
+
+/// A finding held back by a silence, with the silence's ID pattern, reason
+/// and expiry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SilencedFinding {
+    pub silence: String,
+    pub reason: String,
+    pub expires: NaiveDate,
+    pub finding: Finding,
+}
+
+/// Split `findings` into those that stay and those covered by an active
+/// silence. The first matching silence wins.
+pub fn silence(
+    findings: Vec<Finding>,
+    silences: &[Silence],
+    hostname: &str,
+    today: NaiveDate,
+) -> (Vec<Finding>, Vec<SilencedFinding>) {
+    let mut kept = Vec::new();
+    let mut silenced = Vec::new();
+    for finding in findings {
+        match silences
+            .iter()
+            .find(|s| s.is_active(today) && s.matches(&finding, hostname))
+        {
+            Some(s) => silenced.push(SilencedFinding {
+                silence: s.id.clone(),
+                reason: s.reason.clone(),
+                expires: s.expires,
+                finding,
+            }),
+            None => kept.push(finding),
+        }
+    }
+    (kept, silenced)
+}
+
+#[derive(Debug, Deserialize)]
+struct SilencesFile {
+    #[serde(default)]
+    silence: Vec<SilenceToml>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SilenceToml {
+    id: String,
+    #[serde(default)]
+    host: Option<String>,
+    #[serde(default)]
+    when: Option<String>,
+    #[serde(default)]
+    expires: Option<toml::value::Datetime>,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+impl SilenceToml {
+    fn into_silence(self) -> Result<Silence> {
+        let err = |message: &str| Error::Invalid {
+            id: self.id.clone(),
+            message: message.to_string(),
+        };
+        let expires = match self.expires {
+            Some(toml::value::Datetime { date: Some(d), .. }) => {
+                NaiveDate::from_ymd_opt(d.year.into(), d.month.into(), d.day.into())
+                    .ok_or_else(|| err("invalid `expires` date"))?
+            }
+            Some(_) => return Err(err("`expires` must be a date such as 2026-12-31")),
+            None => return Err(err("missing `expires`")),
+        };
+        let reason = self
+            .reason
+            .clone()
+            .filter(|r| !r.trim().is_empty())
+            .ok_or_else(|| err("missing `reason`"))?;
+        let when = self
+            .when
+            .as_deref()
+            .map(Predicate::parse)
+            .transpose()
+            .map_err(|e| err(&e.to_string()))?;
+        Ok(Silence {
+            id: self.id,
+            host: self.host,
+            when,
+            expires,
+            reason,
+        })
+    }
+}
+
+/// Parse the `[[silence]]` tables of a silences file.
+pub fn parse_silences(s: &str) -> Result<Vec<Silence>> {
+    let file: SilencesFile = toml::from_str(s)?;
+    file.silence.into_iter().map(SilenceToml::into_silence).collect()
+}
+
+/// Read and parse a silences file.
+pub fn load_silences(path: &Path) -> Result<Vec<Silence>> {
+    parse_silences(&std::fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finding::{Evidence, FindingKind, Severity};
+    use crate::signal::SignalValue;
+
+    const SILENCES: &str = r#"
+[[silence]]
+id      = "net.irq_imbalance"
+host    = "edge-*"
+when    = "net.max_cpu_irq_pct < 99"
+expires = 2026-12-31
+reason  = "single-queue NIC"
+
+[[silence]]
+id      = "cpu.*"
+expires = 2026-01-31
+reason  = "batch migration"
+"#;
+
+    fn finding(id: &str, evidence: &[(&str, f64)]) -> Finding {
+        Finding {
+            id: id.to_string(),
+            kind: FindingKind::Rule,
+            severity: Severity::Warn,
+            summary: String::new(),
+            evidence: evidence
+                .iter()
+                .map(|(sid, v)| Evidence {
+                    signal_id: sid.to_string(),
+                    observed: SignalValue::F64(*v),
+                    source_commands: Vec::new(),
+                    detail: None,
+                })
+                .collect(),
+            suggest: Vec::new(),
+        }
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn matches_by_id_host_and_evidence_until_expiry() {
+        let silences = parse_silences(SILENCES).expect("parse");
+        let findings = vec![
+            finding("net.irq_imbalance", &[("net.max_cpu_irq_pct", 92.0)]),
+            finding("cpu.saturation", &[]),
+            finding("mem.pressure", &[]),
+        ];
+        let (kept, silenced) = silence(findings.clone(), &silences, "edge-3", date("2026-01-31"));
+        let kept: Vec<&str> = kept.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(kept, vec!["mem.pressure"]);
+        assert_eq!(silenced[0].reason, "single-queue NIC");
+        assert_eq!(silenced[1].silence, "cpu.*");
+
+        let (kept, _) = silence(findings.clone(), &silences, "core-1", date("2026-02-01"));
+        assert_eq!(kept.len(), 3, "other host, and the cpu silence has expired");
+
+        let hot = vec![finding("net.irq_imbalance", &[("net.max_cpu_irq_pct", 99.5)])];
+        let (kept, _) = silence(hot, &silences, "edge-3", date("2026-01-01"));
+        assert_eq!(kept.len(), 1, "evidence condition no longer holds");
+    }
+
+    #[test]
+    fn expiry_and_reason_are_required() {
+        let missing_expiry = "[[silence]]\nid = \"a\"\nreason = \"r\"\n";
+        let err = parse_silences(missing_expiry).unwrap_err();
+        assert_eq!(err.to_string(), "silence 'a': missing `expires`");
+        let missing_reason = "[[silence]]\nid = \"a\"\nexpires = 2026-12-31\nreason = \" \"\n";
+        let err = parse_silences(missing_reason).unwrap_err();
+        assert_eq!(err.to_string(), "silence 'a': missing `reason`");
+        let time_only = "[[silence]]\nid = \"a\"\nexpires = 10:00:00\nreason = \"r\"\n";
+        assert!(parse_silences(time_only).is_err());
+    }
+}
@@ -22,6 +22,14 @@ pub enum WorkloadError {
 
 pub type Result<T, E = WorkloadError> = std::result::Result<T, E>;
 
+/// Every workload pack as `(name, TOML source)`.
+pub const WORKLOADS: [(&str, &str); 4] = [
+    ("postgres", POSTGRES),
+    ("java", JAVA),
+    ("nginx", NGINX),
+    ("kubelet", KUBELET),
+];
+
 /// Load rules for a named workload. `"none"` returns an empty vec (no error).
 /// Unknown names return `WorkloadError::Unknown`.
 pub fn load_workload_rules(name: &str) -> Result<Vec<Rule>> {
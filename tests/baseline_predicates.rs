@@ -0,0 +1,81 @@
+//! Rules that read a signal's baseline (`.baseline.z`, `.baseline.p95`) and
+//! its change since the previous run (`delta()`).
+#![cfg(feature = "bin")]
+
+mod common;
+
+use common::{analysis, signal};
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::baseline::BaselineRecord;
+use usereport::rule::{RuleEngine, parse_rules_toml};
+use usereport::signal::Signal;
+
+const RULES: &str = r#"
+[[rule]]
+id = "net.retrans_above_normal"
+when = "net.retrans_pct > 1 AND net.retrans_pct > 3 * net.retrans_pct.baseline.p95"
+severity = "warn"
+summary = "Retransmits are 3x above this host's normal."
+
+[[rule]]
+id = "dmesg.new_oom_kills"
+when = "delta(dmesg.oom_count) > 0"
+severity = "crit"
+summary = "The OOM killer fired since the last run."
+"#;
+
+fn record(retrans: f64, oom: f64) -> BaselineRecord {
+    BaselineRecord::from_signals(&[signal("net.retrans_pct", retrans), signal("dmesg.oom_count", oom)])
+}
+
+fn run(signals: Vec<Signal>, history: Vec<BaselineRecord>, previous: Option<BaselineRecord>) -> AnalysisReport {
+    let mut analysis =
+        analysis(signals, RuleEngine::new(parse_rules_toml(RULES).expect("rules"))).with_baseline_records(history);
+    if let Some(previous) = previous {
+        analysis = analysis.with_previous_run(previous);
+    }
+    analysis.run(Context::new()).expect("run ok")
+}
+
+fn rule_findings(report: &AnalysisReport) -> Vec<&str> {
+    report
+        .findings()
+        .iter()
+        .map(|f| f.id.as_str())
+        .filter(|id| !id.starts_with("baseline."))
+        .collect()
+}
+
+#[test]
+fn rules_compare_against_the_hosts_own_baseline() {
+    let history = vec![record(0.4, 2.0), record(0.5, 2.0), record(0.6, 2.0)];
+    let report = run(
+        vec![signal("net.retrans_pct", 2.5), signal("dmesg.oom_count", 2.0)],
+        history.clone(),
+        None,
+    );
+    assert_eq!(rule_findings(&report), vec!["net.retrans_above_normal"]);
+
+    // 1.5% is above the absolute floor but within 3x of the normal p95.
+    let history: Vec<BaselineRecord> = history.into_iter().chain([record(1.0, 2.0)]).collect();
+    let report = run(
+        vec![signal("net.retrans_pct", 1.5), signal("dmesg.oom_count", 2.0)],
+        history,
+        None,
+    );
+    assert!(rule_findings(&report).is_empty(), "{:?}", report.findings());
+}
+
+#[test]
+fn delta_fires_only_when_a_counter_increased() {
+    let signals = || vec![signal("net.retrans_pct", 0.1), signal("dmesg.oom_count", 3.0)];
+    let report = run(signals(), vec![], Some(record(0.1, 2.0)));
+    assert_eq!(rule_findings(&report), vec!["dmesg.new_oom_kills"]);
+
+    let report = run(signals(), vec![], Some(record(0.1, 3.0)));
+    assert!(rule_findings(&report).is_empty(), "{:?}", report.findings());
+
+    // Without a previous run there is nothing to compare against.
+    let report = run(signals(), vec![], None);
+    assert!(rule_findings(&report).is_empty(), "{:?}", report.findings());
+}
@@ -9,7 +9,7 @@ use std::collections::HashMap;
 
 use usereport::collector::CollectCtx;
 use usereport::rule::{RuleEngine, builtin::builtin_rules};
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 fn signal(id: &str, value: f64) -> Signal {
     Signal {
@@ -20,6 +20,7 @@ fn signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
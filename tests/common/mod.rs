@@ -0,0 +1,49 @@
+//! Scaffolding shared by the integration tests that run an `Analysis` on
+//! fixed signals instead of the real collectors.
+#![allow(dead_code)]
+
+use usereport::analysis::Analysis;
+use usereport::collector::{CollectCtx, Collector};
+use usereport::rule::RuleEngine;
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
+use usereport::{Command, ThreadRunner};
+
+/// A collector that returns the same signals on every run.
+#[derive(Debug)]
+pub struct MockCollector(pub Vec<Signal>);
+
+impl Collector for MockCollector {
+    fn id(&self) -> &str {
+        "mock"
+    }
+
+    fn collect(&self, _ctx: &CollectCtx) -> usereport::collector::Result<Vec<Signal>> {
+        Ok(self.0.clone())
+    }
+}
+
+/// A unitless signal, collected now.
+pub fn signal(id: &str, v: f64) -> Signal {
+    signal_in(id, v, Unit::None)
+}
+
+/// A signal in `unit`, collected now.
+pub fn signal_in(id: &str, v: f64, unit: Unit) -> Signal {
+    Signal {
+        id: id.to_string(),
+        value: SignalValue::F64(v),
+        unit,
+        at: chrono::Local::now(),
+        samples: None,
+        stats: None,
+        baseline: None,
+        labels: Labels::new(),
+    }
+}
+
+/// An analysis without commands whose only collector returns `signals`.
+pub fn analysis(signals: Vec<Signal>, engine: RuleEngine) -> Analysis<'static, &'static [Command]> {
+    let none: &'static [Command] = &[];
+    Analysis::new(Box::new(ThreadRunner::new()), none, none)
+        .with_diagnostics(vec![Box::new(MockCollector(signals))], engine)
+}
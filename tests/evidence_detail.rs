@@ -0,0 +1,52 @@
+//! `Evidence::detail` (e.g. the breach count of a `sustained(...)` term) is
+//! rendered next to the observed value in both the Markdown and HTML reports.
+
+use usereport::Renderer;
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::finding::{Evidence, Finding, FindingKind, Severity};
+use usereport::renderer::TemplateRenderer;
+use usereport::signal::SignalValue;
+
+const MARKDOWN: &str = include_str!("../contrib/markdown.j2");
+const HTML: &str = include_str!("../contrib/html.j2");
+
+fn render(template: &str) -> String {
+    let finding = Finding {
+        id: "cpu.iowait_sustained".to_string(),
+        kind: FindingKind::Rule,
+        severity: Severity::Warn,
+        summary: "iowait elevated for most of the window".to_string(),
+        evidence: vec![Evidence {
+            signal_id: "cpu.iowait_pct".to_string(),
+            observed: SignalValue::F64(42.0),
+            source_commands: vec![],
+            detail: Some("breached in 5/6 samples (83%, 50s); longest run 3 samples (30s)".to_string()),
+        }],
+        suggest: vec![],
+    };
+    let report =
+        AnalysisReport::new_with_diagnostics(Context::new(), vec![], vec![], 1, 64, vec![], vec![finding], vec![]);
+    let mut out = Vec::new();
+    TemplateRenderer::new(template)
+        .render(&report, &mut out)
+        .expect("render ok");
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn markdown_evidence_includes_detail() {
+    let s = render(MARKDOWN);
+    assert!(
+        s.contains("(breached in 5/6 samples (83%, 50s); longest run 3 samples (30s))"),
+        "{s}"
+    );
+}
+
+#[test]
+fn html_evidence_includes_detail() {
+    let s = render(HTML);
+    assert!(
+        s.contains("<em>(breached in 5&#x2f;6 samples (83%, 50s); longest run 3 samples (30s))</em>"),
+        "{s}"
+    );
+}
@@ -0,0 +1,47 @@
+//! `explain` reads user patterns from `--patterns-dir`, as reports do.
+#![cfg(feature = "bin")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+const PATTERN: &str = r#"
+[[pattern]]
+id = "site.busy"
+when = "load.avg1 > 4"
+severity = "warn"
+summary = "site is busy"
+"#;
+
+fn usereport(xdg: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_usereport"))
+        .args(args)
+        .env("XDG_CONFIG_HOME", xdg)
+        .output()
+        .expect("run binary")
+}
+
+#[test]
+fn explain_shows_patterns_from_patterns_dir() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let dir = tmp.path().join("patterns");
+    std::fs::create_dir(&dir).expect("create patterns dir");
+    let file = dir.join("site.toml");
+    std::fs::write(&file, PATTERN).expect("write pattern");
+    let dir = dir.to_str().unwrap();
+
+    let output = usereport(tmp.path(), &["--patterns-dir", dir, "explain", "site.busy"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains(&format!("Source:   {}", file.display())), "{stdout}");
+
+    let output = usereport(tmp.path(), &["--patterns-dir", dir, "explain"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("site.busy (pattern)"), "{stdout}");
+
+    let output = usereport(tmp.path(), &["explain", "site.busy"]);
+    assert!(!output.status.success());
+}
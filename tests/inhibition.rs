@@ -0,0 +1,108 @@
+//! Finding inhibition: `inhibits = [...]` on a rule or pattern moves the
+//! findings it names out of `findings` and into `inhibited`.
+#![cfg(feature = "bin")]
+
+mod common;
+
+use common::{analysis, signal};
+use usereport::Renderer;
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::cli::{ExitOn, compute_exit_code};
+use usereport::pattern::PatternEngine;
+use usereport::renderer::TemplateRenderer;
+use usereport::rule::{RuleEngine, parse_rules_toml};
+
+const RULES: &str = r#"
+[[rule]]
+id = "dmesg.oom_kill"
+when = "dmesg.oom_count > 0"
+severity = "warn"
+summary = "OOM killer fired."
+inhibits = ["mem.pressure", "cpu.*"]
+
+[[rule]]
+id = "mem.pressure"
+when = "mem.free_pct < 10"
+severity = "crit"
+summary = "Free memory low."
+
+[[rule]]
+id = "cpu.iowait_elevated"
+when = "cpu.iowait_pct > 20"
+severity = "crit"
+summary = "iowait elevated."
+"#;
+
+const PATTERNS: &str = r#"
+[[pattern]]
+id = "reclaim_storm"
+when = "mem.free_pct < 10 AND cpu.iowait_pct > 20"
+severity = "info"
+summary = "Reclaim storm."
+inhibits = ["dmesg.*"]
+"#;
+
+fn run(patterns: Option<&str>) -> AnalysisReport {
+    let signals = vec![
+        signal("dmesg.oom_count", 1.0),
+        signal("mem.free_pct", 3.0),
+        signal("cpu.iowait_pct", 45.0),
+    ];
+    let mut analysis = analysis(signals, RuleEngine::new(parse_rules_toml(RULES).expect("rules")));
+    if let Some(patterns) = patterns {
+        analysis = analysis.with_pattern_engine(PatternEngine::from_toml(patterns).expect("patterns"));
+    }
+    analysis.run(Context::new()).expect("run ok")
+}
+
+#[test]
+fn root_finding_moves_dependents_to_inhibited_and_out_of_exit_code() {
+    let report = run(None);
+    let ids: Vec<&str> = report.findings().iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, vec!["dmesg.oom_kill"]);
+    let inhibited: Vec<(&str, Vec<&str>)> = report
+        .inhibited()
+        .iter()
+        .map(|i| (i.finding.id.as_str(), i.by.iter().map(String::as_str).collect()))
+        .collect();
+    assert_eq!(
+        inhibited,
+        vec![
+            ("cpu.iowait_elevated", vec!["dmesg.oom_kill"]),
+            ("mem.pressure", vec!["dmesg.oom_kill"]),
+        ]
+    );
+    // Only the WARN root finding counts; the inhibited CRIT findings do not.
+    assert_eq!(compute_exit_code(ExitOn::Crit, report.findings()), 0);
+    assert_eq!(compute_exit_code(ExitOn::Warn, report.findings()), 1);
+}
+
+#[test]
+fn pattern_inhibits_and_inhibited_findings_still_inhibit() {
+    let report = run(Some(PATTERNS));
+    let ids: Vec<&str> = report.findings().iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, vec!["reclaim_storm"]);
+    let oom = report
+        .inhibited()
+        .iter()
+        .find(|i| i.finding.id == "dmesg.oom_kill")
+        .expect("oom inhibited");
+    assert_eq!(oom.by, vec!["reclaim_storm".to_string()]);
+    assert_eq!(report.inhibited().len(), 3);
+}
+
+#[test]
+fn markdown_lists_suppressed_findings_with_their_root() {
+    let report = run(None);
+    let mut out = Vec::new();
+    TemplateRenderer::new(include_str!("../contrib/markdown.j2"))
+        .render(&report, &mut out)
+        .expect("render ok");
+    let s = String::from_utf8(out).unwrap();
+    assert!(s.contains("## Suppressed"), "{s}");
+    assert!(
+        s.contains("- [CRIT] `mem.pressure` — suppressed by `dmesg.oom_kill`"),
+        "{s}"
+    );
+    assert!(!s.contains("### [CRIT] mem.pressure"), "{s}");
+}
@@ -0,0 +1,104 @@
+//! Labelled signals: per-device and per-cgroup signals carry labels that
+//! diff, templates and the JSON schema understand.
+#![cfg(feature = "bin")]
+
+mod common;
+
+use common::{analysis, signal};
+use usereport::Renderer;
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::collector::disk::DiskCollector;
+use usereport::collector::platform::DiskDevSnapshot;
+use usereport::renderer::TemplateRenderer;
+use usereport::rule::RuleEngine;
+use usereport::signal::{Signal, label};
+
+fn run(signals: Vec<Signal>) -> AnalysisReport {
+    analysis(signals, RuleEngine::new(vec![]))
+        .run(Context::new())
+        .expect("run ok")
+}
+
+fn disk(name: &str, ios: u64, io_time_ms: u64) -> DiskDevSnapshot {
+    DiskDevSnapshot {
+        name: name.to_string(),
+        read_ios: ios,
+        write_ios: ios,
+        read_time_ms: Some(ios),
+        write_time_ms: Some(ios),
+        io_time_ms: Some(io_time_ms),
+    }
+}
+
+#[test]
+fn disk_collector_labels_per_device_signals() {
+    let a = vec![disk("sda", 100, 0), disk("nvme0n1", 100, 0)];
+    let b = vec![disk("sda", 200, 500), disk("nvme0n1", 150, 100)];
+    let signals = DiskCollector::from_disk_snapshots(&a, &b, 1.0);
+    let util = signals.iter().find(|s| s.id == "disk.sda.util_pct").expect("util");
+    assert_eq!(util.label(label::DEVICE), Some("sda"));
+    assert_eq!(util.key(), "disk.sda.util_pct");
+    let max = signals.iter().find(|s| s.id == "disk.max_util_pct").expect("max");
+    assert!(max.labels.is_empty());
+}
+
+#[test]
+fn labels_are_optional_in_json() {
+    let json = serde_json::to_value(signal("cpu.iowait_pct", 1.0)).unwrap();
+    assert!(json.get("labels").is_none(), "{json}");
+
+    let mut legacy = json.clone();
+    legacy["id"] = "disk.sda.util_pct".into();
+    let parsed: Signal = serde_json::from_value(legacy).unwrap();
+    assert!(parsed.labels.is_empty());
+
+    let labelled = signal("disk.sda.util_pct", 1.0).with_label(label::DEVICE, "sda");
+    let json = serde_json::to_value(&labelled).unwrap();
+    assert_eq!(json["labels"], serde_json::json!({"device": "sda"}));
+}
+
+#[test]
+fn diff_tells_apart_signals_of_different_cgroups() {
+    let cgroup = |path: &str, v: f64| signal("cgroup.memory_bytes", v).with_label(label::CGROUP, path);
+    let a = run(vec![cgroup("/sys/fs/cgroup/app", 10.0), signal("cpu.iowait_pct", 1.0)]);
+    let b = run(vec![cgroup("/sys/fs/cgroup/db", 20.0), signal("cpu.iowait_pct", 2.0)]);
+    let d = usereport::diff::diff(&a, &b);
+    assert_eq!(
+        d.signals_only_in_a,
+        vec!["cgroup.memory_bytes{cgroup=/sys/fs/cgroup/app}"]
+    );
+    assert_eq!(
+        d.signals_only_in_b,
+        vec!["cgroup.memory_bytes{cgroup=/sys/fs/cgroup/db}"]
+    );
+    assert_eq!(d.signal_deltas.len(), 1);
+    assert_eq!(d.signal_deltas[0].signal_id, "cpu.iowait_pct");
+
+    let sda = |v: f64| signal("disk.sda.util_pct", v).with_label(label::DEVICE, "sda");
+    let d = usereport::diff::diff(&run(vec![sda(10.0)]), &run(vec![sda(30.0)]));
+    assert_eq!(d.signal_deltas[0].signal_id, "disk.sda.util_pct");
+    assert_eq!(
+        d.signal_deltas[0].labels.get(label::DEVICE).map(String::as_str),
+        Some("sda")
+    );
+}
+
+#[test]
+fn markdown_renders_a_table_per_labelled_family() {
+    let dev = |id: &str, device: &str, v: f64| signal(id, v).with_label(label::DEVICE, device);
+    let report = run(vec![
+        dev("disk.sda.read_iops", "sda", 120.0),
+        dev("disk.sda.util_pct", "sda", 35.5),
+        dev("disk.dm.0.read_iops", "dm.0", 4.0),
+        signal("disk.max_util_pct", 35.5),
+    ]);
+    let mut out = Vec::new();
+    TemplateRenderer::new(include_str!("../contrib/markdown.j2"))
+        .render(&report, &mut out)
+        .expect("render ok");
+    let s = String::from_utf8(out).unwrap();
+    assert!(s.contains("### `disk` by device"), "{s}");
+    assert!(s.contains("| device | read_iops | util_pct |"), "{s}");
+    assert!(s.contains("| `dm.0` | 4 | — |"), "{s}");
+    assert!(s.contains("| `sda` | 120 | 35.5 |"), "{s}");
+}
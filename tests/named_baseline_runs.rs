@@ -0,0 +1,137 @@
+//! Named baselines built from several runs: `baseline record --runs`,
+//! `baseline merge` and the `baseline show` summary.
+#![cfg(feature = "bin")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::signal;
+use usereport::baseline::store::{BaselineRecord, BaselineStore};
+use usereport::baseline::{annotate, outlier_findings};
+
+fn record(load: f64) -> BaselineRecord {
+    BaselineRecord::from_signals(&[signal("load.avg1", load)])
+}
+
+fn store(xdg: &Path) -> BaselineStore {
+    BaselineStore::at(xdg.join("usereport").join("baselines"))
+}
+
+fn usereport(xdg: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_usereport"))
+        .args(args)
+        .env("XDG_DATA_HOME", xdg)
+        .output()
+        .expect("run binary")
+}
+
+#[test]
+fn runs_round_trip_and_summary_holds_medians() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let store = store(tmp.path());
+    store
+        .record_runs("green", &[record(1.0), record(2.0), record(6.0)])
+        .expect("record");
+
+    let runs = store.load_runs("green").expect("load").expect("present");
+    let loads: Vec<f64> = runs.iter().map(|r| r.signals["load.avg1"]).collect();
+    assert_eq!(loads, vec![1.0, 2.0, 6.0]);
+
+    let summary = store.load("green").expect("load").expect("present");
+    assert_eq!(summary.signals["load.avg1"], 2.0);
+}
+
+#[test]
+fn single_run_baselines_load_as_one_run() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let store = store(tmp.path());
+    store.record("old", &[signal("load.avg1", 3.0)]).expect("record");
+
+    let raw: serde_json::Value =
+        serde_json::from_slice(&std::fs::read(store.dir().join("old.json")).unwrap()).unwrap();
+    assert!(raw.get("runs").is_none(), "{raw}");
+
+    let runs = store.load_runs("old").expect("load").expect("present");
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].signals["load.avg1"], 3.0);
+}
+
+#[test]
+fn a_baseline_of_several_runs_flags_outliers() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let store = store(tmp.path());
+    store
+        .record_runs("green", &[record(1.0), record(1.2), record(0.8), record(1.1)])
+        .expect("record");
+    let runs = store.load_runs("green").expect("load").expect("present");
+
+    let mut signals = vec![signal("load.avg1", 9.0)];
+    annotate(&mut signals, &runs);
+    let findings = outlier_findings(&signals);
+    assert_eq!(findings.len(), 1, "{findings:?}");
+}
+
+#[test]
+fn merge_combines_runs_and_show_summarizes_them() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let store = store(tmp.path());
+    store.record_runs("mon", &[record(1.0), record(2.0)]).expect("record");
+    store.record_runs("tue", &[record(4.0)]).expect("record");
+
+    let output = usereport(tmp.path(), &["baseline", "merge", "--name", "week", "mon", "tue"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        stdout.contains("merged mon, tue into baseline 'week' (3 runs)"),
+        "{stdout}"
+    );
+
+    // Merging again must not silently replace the result.
+    let output = usereport(tmp.path(), &["baseline", "merge", "--name", "week", "mon"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("already exists"));
+
+    let output = usereport(tmp.path(), &["baseline", "show", "week"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("baseline 'week': 3 runs"), "{stdout}");
+    assert!(stdout.contains("load.avg1"), "{stdout}");
+
+    let output = usereport(tmp.path(), &["baseline", "show", "week", "--output", "json"]);
+    let summary: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json summary");
+    assert_eq!(
+        summary,
+        serde_json::json!([{"id": "load.avg1", "runs": 3, "median": 2.0, "mad": 1.0}])
+    );
+
+    let output = usereport(tmp.path(), &["baseline", "show", "week", "--output", "raw"]);
+    let runs: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json runs");
+    let loads: Vec<f64> = runs
+        .as_array()
+        .expect("runs")
+        .iter()
+        .map(|r| r["signals"]["load.avg1"].as_f64().unwrap())
+        .collect();
+    assert_eq!(loads, vec![1.0, 2.0, 4.0]);
+
+    let output = usereport(tmp.path(), &["baseline", "merge", "--name", "x", "missing"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("baseline 'missing' not found"));
+}
+
+#[test]
+fn show_warns_about_single_run_baselines() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    store(tmp.path()).record_runs("once", &[record(1.0)]).expect("record");
+
+    let output = usereport(tmp.path(), &["baseline", "show", "once"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("baseline 'once': 1 run"), "{stdout}");
+    assert!(stdout.contains("produces no outliers"), "{stdout}");
+}
@@ -0,0 +1,100 @@
+//! Near misses: with `with_near_misses`, rules and patterns that did not fire
+//! but came close are listed in `near_misses` with their failing terms.
+#![cfg(feature = "bin")]
+
+mod common;
+
+use common::{analysis, signal};
+use usereport::Renderer;
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::finding::{FindingKind, Severity};
+use usereport::llm::LlmOutput;
+use usereport::pattern::PatternEngine;
+use usereport::renderer::TemplateRenderer;
+use usereport::rule::{RuleEngine, parse_rules_toml};
+
+const RULES: &str = r#"
+[[rule]]
+id = "mem.pressure"
+when = "mem.free_pct < 10"
+severity = "warn"
+summary = "Free memory low."
+
+[[rule]]
+id = "cpu.iowait_elevated"
+when = "cpu.iowait_pct > 20"
+severity = "warn"
+summary = "iowait elevated."
+"#;
+
+const PATTERNS: &str = r#"
+[[pattern]]
+id = "time_wait_exhaustion"
+when = "net.tw_count > 28000 AND net.connect_failures > 0"
+severity = "crit"
+summary = "TIME_WAIT exhaustion."
+"#;
+
+fn run(margin: Option<f64>) -> AnalysisReport {
+    let signals = vec![
+        signal("mem.free_pct", 10.5),
+        signal("cpu.iowait_pct", 2.0),
+        signal("net.tw_count", 25000.0),
+        signal("net.connect_failures", 4.0),
+    ];
+    let mut analysis = analysis(signals, RuleEngine::new(parse_rules_toml(RULES).expect("rules")))
+        .with_pattern_engine(PatternEngine::from_toml(PATTERNS).expect("patterns"));
+    if let Some(margin) = margin {
+        analysis = analysis.with_near_misses(margin);
+    }
+    analysis.run(Context::new()).expect("run ok")
+}
+
+#[test]
+fn near_misses_list_rules_and_patterns_close_to_firing() {
+    let report = run(Some(15.0));
+    assert!(report.findings().is_empty());
+    let misses: Vec<(&str, FindingKind, Severity)> = report
+        .near_misses()
+        .iter()
+        .map(|n| (n.id.as_str(), n.kind, n.severity))
+        .collect();
+    assert_eq!(
+        misses,
+        vec![
+            ("mem.pressure", FindingKind::Rule, Severity::Warn),
+            ("time_wait_exhaustion", FindingKind::Pattern, Severity::Crit),
+        ]
+    );
+    let tw = &report.near_misses()[1];
+    assert_eq!((tw.held, tw.total), (1, 2));
+    assert_eq!(tw.failing[0].term, "net.tw_count > 28000");
+    assert_eq!(tw.failing[0].detail.as_deref(), Some("25000 > 28000, 10.7% off"));
+
+    let llm = LlmOutput::from_report(&report, false);
+    assert_eq!(llm.near_misses.len(), 2);
+}
+
+#[test]
+fn near_misses_are_off_by_default_and_bounded_by_the_margin() {
+    assert!(run(None).near_misses().is_empty());
+    let ids: Vec<String> = run(Some(6.0)).near_misses().iter().map(|n| n.id.clone()).collect();
+    assert_eq!(ids, vec!["mem.pressure"]);
+}
+
+#[test]
+fn markdown_lists_near_misses_as_watch_entries() {
+    let report = run(Some(15.0));
+    let mut out = Vec::new();
+    TemplateRenderer::new(include_str!("../contrib/markdown.j2"))
+        .render(&report, &mut out)
+        .expect("render ok");
+    let s = String::from_utf8(out).unwrap();
+    assert!(s.contains("## Watch"), "{s}");
+    assert!(
+        s.contains(
+            "- [INFO] `time_wait_exhaustion` — 1/2 conditions held, would be CRIT; failing: `net.tw_count > 28000` (25000 > 28000, 10.7% off)"
+        ),
+        "{s}"
+    );
+}
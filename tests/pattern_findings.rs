@@ -0,0 +1,112 @@
+//! Patterns over rule findings: `finding.<id>` and `finding.<id>.severity`
+//! resolve against the rule pass, and the findings they read become evidence.
+#![cfg(feature = "bin")]
+
+mod common;
+
+use common::{analysis, signal};
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::finding::{FindingKind, Severity};
+use usereport::pattern::PatternEngine;
+use usereport::rule::{RuleEngine, parse_rules_toml};
+use usereport::signal::SignalValue;
+
+const RULES: &str = r#"
+[[rule]]
+id = "cpu.iowait_elevated"
+summary = "iowait at {{ cpu.iowait_pct }}%."
+
+[[rule.level]]
+when = "cpu.iowait_pct > 20"
+severity = "warn"
+
+[[rule.level]]
+when = "cpu.iowait_pct > 50"
+severity = "crit"
+
+[[rule]]
+id = "net.conntrack_full"
+when = "net.conntrack_pct > 90"
+severity = "warn"
+summary = "Conntrack table nearly full."
+"#;
+
+const PATTERNS: &str = r#"
+[[pattern]]
+id = "storage_stall"
+when = "finding.cpu.iowait_elevated.severity >= crit AND api.p99_ms > 500"
+severity = "crit"
+summary = "API latency tracks a storage stall."
+
+[[pattern]]
+id = "conntrack_latency"
+when = "finding.net.conntrack_full == true AND api.p99_ms > 500"
+severity = "crit"
+summary = "API latency with a full conntrack table."
+
+[[pattern.test]]
+name = "needs the rule finding"
+signals = { "api.p99_ms" = 900 }
+fires = false
+
+[[pattern.test]]
+signals = { "api.p99_ms" = 900 }
+findings = { "net.conntrack_full" = "warn" }
+fires = true
+"#;
+
+fn run(iowait: f64) -> AnalysisReport {
+    let signals = vec![
+        signal("cpu.iowait_pct", iowait),
+        signal("net.conntrack_pct", 95.0),
+        signal("api.p99_ms", 900.0),
+    ];
+    analysis(signals, RuleEngine::new(parse_rules_toml(RULES).expect("rules")))
+        .with_pattern_engine(PatternEngine::from_toml(PATTERNS).expect("patterns"))
+        .run(Context::new())
+        .expect("run ok")
+}
+
+fn pattern_ids(report: &AnalysisReport) -> Vec<&str> {
+    report
+        .findings()
+        .iter()
+        .filter(|f| f.kind == FindingKind::Pattern)
+        .map(|f| f.id.as_str())
+        .collect()
+}
+
+#[test]
+fn patterns_fire_on_rule_findings_and_their_severity() {
+    assert_eq!(pattern_ids(&run(30.0)), vec!["conntrack_latency"]);
+    assert_eq!(pattern_ids(&run(60.0)), vec!["conntrack_latency", "storage_stall"]);
+}
+
+#[test]
+fn evidence_links_back_to_contributing_findings() {
+    let report = run(60.0);
+    let stall = report
+        .findings()
+        .iter()
+        .find(|f| f.id == "storage_stall")
+        .expect("storage_stall fired");
+    let linked = stall
+        .evidence
+        .iter()
+        .find(|e| e.signal_id == "finding.cpu.iowait_elevated")
+        .expect("finding evidence");
+    assert!(matches!(&linked.observed, SignalValue::Text(s) if s == "crit"));
+    assert_eq!(linked.detail.as_deref(), Some("iowait at 60.0%."));
+    assert!(stall.evidence.iter().any(|e| e.signal_id == "api.p99_ms"));
+}
+
+#[test]
+fn pattern_tests_supply_rule_findings() {
+    let engine = PatternEngine::from_toml(PATTERNS).expect("patterns");
+    for test in engine.tests() {
+        let findings = engine.run_with_findings(&test.signals, &test.findings, &test.ctx);
+        let fired = findings.iter().find(|f| f.id == test.id).map(|f| f.severity);
+        assert_eq!(test.check(fired), Ok(()), "{:?}", test.name);
+    }
+    assert_eq!(engine.tests()[1].findings[0].severity, Severity::Warn);
+}
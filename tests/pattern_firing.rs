@@ -7,7 +7,7 @@
 
 use usereport::collector::CollectCtx;
 use usereport::pattern::PatternEngine;
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 fn signal(id: &str, value: f64) -> Signal {
     Signal {
@@ -18,6 +18,7 @@ fn signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
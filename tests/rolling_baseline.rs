@@ -0,0 +1,119 @@
+//! `--baseline rolling`: outlier detection against the last runs on this
+//! host, once the rolling window holds enough of them.
+#![cfg(feature = "bin")]
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::{analysis, signal};
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::baseline::store::{BaselineRecord, BaselineStore};
+use usereport::rule::RuleEngine;
+use usereport::signal::Signal;
+
+fn run(signals: Vec<Signal>, history: Vec<BaselineRecord>, min_history: usize) -> AnalysisReport {
+    analysis(signals, RuleEngine::new(vec![]))
+        .with_baseline_records(history)
+        .with_baseline_min_history(min_history)
+        .run(Context::new())
+        .expect("run ok")
+}
+
+#[test]
+fn signals_with_too_little_history_are_not_compared() {
+    // load.avg1 is in all five records, net.conns only in the last two,
+    // e.g. because its collector was added recently.
+    let history: Vec<BaselineRecord> = (0..5)
+        .map(|i| {
+            let mut signals = vec![signal("load.avg1", 1.0 + 0.1 * i as f64)];
+            if i >= 3 {
+                signals.push(signal("net.conns", 100.0 + i as f64));
+            }
+            BaselineRecord::from_signals(&signals)
+        })
+        .collect();
+    let report = run(vec![signal("load.avg1", 40.0), signal("net.conns", 9000.0)], history, 3);
+    let baselined: Vec<&str> = report
+        .signals()
+        .iter()
+        .filter(|s| s.baseline.is_some())
+        .map(|s| s.id.as_str())
+        .collect();
+    assert_eq!(baselined, vec!["load.avg1"]);
+    let ids: Vec<&str> = report.findings().iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids.len(), 1, "{ids:?}");
+    assert!(ids[0].contains("load.avg1"), "{ids:?}");
+}
+
+fn usereport(xdg: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_usereport"))
+        .args(args)
+        .env("XDG_DATA_HOME", xdg)
+        .output()
+        .expect("run binary")
+}
+
+fn baselined_ids(output: &Output) -> Vec<String> {
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json report");
+    report["signals"]
+        .as_array()
+        .expect("signals")
+        .iter()
+        .filter(|s| !s["baseline"].is_null())
+        .map(|s| s["id"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn rolling_baseline_waits_for_enough_runs() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let output = usereport(
+        tmp.path(),
+        &["--baseline", "rolling", "--output", "json", "--no-progress"],
+    );
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("the rolling baseline holds 0 of the 5 runs needed"),
+        "{stderr}"
+    );
+    assert!(baselined_ids(&output).is_empty());
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn rolling_baseline_annotates_signals_once_the_window_is_full() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let store = BaselineStore::at(tmp.path().join("usereport").join("baselines"));
+    for n in 1..=5 {
+        store
+            .append_rolling(&[signal("host.cpu_count", n as f64)], 24)
+            .expect("append");
+    }
+
+    let output = usereport(
+        tmp.path(),
+        &["--baseline", "rolling", "--output", "json", "--no-progress"],
+    );
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(baselined_ids(&output), vec!["host.cpu_count"]);
+}
+
+#[test]
+fn rolling_is_not_a_baseline_name() {
+    let tmp = tempfile::tempdir().expect("create tempdir");
+    let output = usereport(tmp.path(), &["baseline", "record", "--name", "rolling"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("reserved for the rolling baseline"));
+}
@@ -0,0 +1,129 @@
+//! User rule files can replace a built-in rule by id and turn rules off with
+//! `[[disable]]` or `enabled = false`; the loader records where each rule came
+//! from.
+
+use usereport::finding::Severity;
+use usereport::rule::{Predicate, Rule, RuleSource, RulesLoader};
+
+fn builtin(id: &str) -> Rule {
+    Rule {
+        id: id.to_string(),
+        when: Predicate::parse("cpu.run_queue > 8").expect("parse"),
+        severity: Severity::Warn,
+        summary: "built-in".to_string(),
+        evidence_ids: vec![],
+        suggest: vec![],
+        description: None,
+        links: vec![],
+        levels: vec![],
+        inhibits: vec![],
+    }
+}
+
+#[test]
+fn user_rule_with_same_id_replaces_builtin() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let path = tmp.path().join("site.toml");
+    std::fs::write(
+        &path,
+        r#"
+[[rule]]
+id = "cpu.saturated"
+when = "cpu.run_queue > 32"
+severity = "crit"
+summary = "site override"
+"#,
+    )
+    .unwrap();
+
+    let loaded = RulesLoader::new()
+        .with_builtins(vec![builtin("cpu.saturated"), builtin("mem.pressure")])
+        .with_user_dir(tmp.path())
+        .load();
+
+    let ids: Vec<&str> = loaded.rules.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, ["cpu.saturated", "mem.pressure"]);
+    assert_eq!(loaded.rules[0].summary, "site override");
+    assert_eq!(loaded.rules[0].severity, Severity::Crit);
+    assert_eq!(
+        loaded.sources["cpu.saturated"],
+        RuleSource::User {
+            path: path.clone(),
+            overrides_builtin: true,
+        }
+    );
+    assert_eq!(loaded.sources["mem.pressure"], RuleSource::Builtin);
+    assert_eq!(
+        loaded.sources["cpu.saturated"].to_string(),
+        format!("{} (overrides built-in)", path.display())
+    );
+}
+
+#[test]
+fn disable_table_and_enabled_false_turn_rules_off() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    let path = tmp.path().join("quiet.toml");
+    std::fs::write(
+        &path,
+        r#"
+[[disable]]
+ids = ["cpu.saturated"]
+
+[[rule]]
+id = "mem.pressure"
+enabled = false
+"#,
+    )
+    .unwrap();
+
+    let loaded = RulesLoader::new()
+        .with_builtins(vec![
+            builtin("cpu.saturated"),
+            builtin("mem.pressure"),
+            builtin("disk.busy"),
+        ])
+        .with_user_dir(tmp.path())
+        .load();
+
+    assert!(loaded.load_findings.is_empty(), "{:?}", loaded.load_findings);
+    let ids: Vec<&str> = loaded.rules.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, ["disk.busy"]);
+    assert_eq!(loaded.disabled["cpu.saturated"], path);
+    assert_eq!(loaded.disabled["mem.pressure"], path);
+}
+
+#[test]
+fn disable_applies_to_rules_defined_in_later_files() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(tmp.path().join("a.toml"), "[[disable]]\nids = [\"site.custom\"]\n").unwrap();
+    std::fs::write(
+        tmp.path().join("b.toml"),
+        r#"
+[[rule]]
+id = "site.custom"
+when = "cpu.run_queue > 1"
+severity = "info"
+summary = "custom"
+"#,
+    )
+    .unwrap();
+
+    let loaded = RulesLoader::new().with_user_dir(tmp.path()).load();
+    assert!(loaded.rules.is_empty(), "{:?}", loaded.rules);
+    assert!(loaded.disabled.contains_key("site.custom"));
+}
+
+#[test]
+fn enabled_rule_without_summary_is_malformed() {
+    let tmp = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        tmp.path().join("bad.toml"),
+        "[[rule]]\nid = \"x\"\nwhen = \"cpu.run_queue > 1\"\nseverity = \"warn\"\n",
+    )
+    .unwrap();
+
+    let loaded = RulesLoader::new().with_user_dir(tmp.path()).load();
+    assert!(loaded.rules.is_empty());
+    assert_eq!(loaded.load_findings.len(), 1);
+    assert!(loaded.load_findings[0].summary.contains("missing `summary`"));
+}
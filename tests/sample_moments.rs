@@ -0,0 +1,53 @@
+//! Mean, standard deviation, coefficient of variation, slope and count of
+//! sampled signals.
+#![cfg(feature = "bin")]
+
+use std::time::Duration;
+
+mod common;
+
+use common::{analysis, signal_in};
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::rule::{RuleEngine, parse_rules_toml};
+use usereport::signal::{SampleStats, Signal, Unit};
+
+const RULES: &str = r#"
+[[rule]]
+id = "mem.leak"
+when = "mem.used_mb.slope > 50"
+severity = "warn"
+summary = "Memory use grows by more than 50 MB/s."
+"#;
+
+fn sampled(id: &str, samples: &[f64]) -> Signal {
+    Signal {
+        samples: Some(samples.to_vec()),
+        ..signal_in(id, *samples.last().unwrap(), Unit::Megabytes)
+    }
+}
+
+fn run(signals: Vec<Signal>, interval: Duration) -> AnalysisReport {
+    analysis(signals, RuleEngine::new(parse_rules_toml(RULES).expect("rules")))
+        .with_sample_duration(interval * 4, interval)
+        .run(Context::new())
+        .expect("run ok")
+}
+
+#[test]
+fn slope_is_measured_per_second_of_the_capture() {
+    // +1000 MB per sample: 200 MB/s at a 5 s interval, 20 MB/s at 50 s.
+    let used = || vec![sampled("mem.used_mb", &[1000.0, 2000.0, 3000.0, 4000.0, 5000.0])];
+    let report = run(used(), Duration::from_secs(5));
+    let ids: Vec<&str> = report.findings().iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, vec!["mem.leak"]);
+
+    let report = run(used(), Duration::from_secs(50));
+    assert!(report.findings().is_empty(), "{:?}", report.findings());
+}
+
+#[test]
+fn stats_from_older_reports_still_load() {
+    let json = r#"{"min": 1.0, "max": 3.0, "p50": 2.0, "p95": 3.0, "p99": 3.0, "trend": "Rising"}"#;
+    let stats: SampleStats = serde_json::from_str(json).expect("legacy stats");
+    assert_eq!((stats.mean, stats.slope, stats.count), (None, None, None));
+}
@@ -8,7 +8,7 @@ use std::collections::HashMap;
 use usereport::collector::CollectCtx;
 use usereport::finding::Severity;
 use usereport::rule::{Op, Predicate, Rhs, Rule, RuleEngine, Value};
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 fn make_signal(id: &str, value: f64) -> Signal {
     Signal {
@@ -19,6 +19,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -37,6 +38,8 @@ fn rule_engine_returns_checked_ok_when_rule_does_not_fire() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
     let engine = RuleEngine::new(vec![rule]);
     let signals = vec![make_signal("cpu.iowait_pct", 5.0)];
@@ -8,7 +8,7 @@ use usereport::Renderer;
 use usereport::analysis::{AnalysisReport, Context, ThresholdInfo};
 use usereport::finding::Severity;
 use usereport::renderer::TemplateRenderer;
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 const HTML: &str = include_str!("../contrib/html.j2");
 
@@ -21,6 +21,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -33,6 +34,7 @@ fn threshold_column_in_html_shows_value_and_severity() {
             severity: Severity::Warn,
             op: ">".to_string(),
             value: 20.0,
+            tiers: vec![],
         },
     );
     let mut report = AnalysisReport::new_with_diagnostics(
@@ -7,7 +7,7 @@ use std::collections::HashMap;
 use usereport::collector::CollectCtx;
 use usereport::finding::Severity;
 use usereport::rule::{Op, Predicate, Rhs, Rule, RuleEngine, Value};
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 fn make_signal(id: &str, value: f64) -> Signal {
     Signal {
@@ -18,6 +18,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -36,6 +37,8 @@ fn source_commands_populated_in_evidence() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
     let engine = RuleEngine::new(vec![rule]);
     let signals = vec![make_signal("cpu.iowait_pct", 25.0)];
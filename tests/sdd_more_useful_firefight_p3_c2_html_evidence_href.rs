@@ -22,6 +22,7 @@ fn html_evidence_renders_href_to_command() {
             signal_id: "disk.util_pct".to_string(),
             observed: SignalValue::F64(90.0),
             source_commands: vec!["iostat".to_string()],
+            detail: None,
         }],
         suggest: vec![],
     };
@@ -34,11 +34,13 @@ fn html_evidence_commands_appear_before_details() {
                 signal_id: "disk.util_pct".to_string(),
                 observed: SignalValue::F64(90.0),
                 source_commands: vec!["iostat".to_string()],
+                detail: None,
             },
             Evidence {
                 signal_id: "net.drops".to_string(),
                 observed: SignalValue::I64(5),
                 source_commands: vec!["sar_dev".to_string()],
+                detail: None,
             },
         ],
         suggest: vec![],
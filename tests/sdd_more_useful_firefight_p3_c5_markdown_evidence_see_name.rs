@@ -22,6 +22,7 @@ fn markdown_evidence_appends_see_source_command_name() {
             signal_id: "cpu.iowait_pct".to_string(),
             observed: SignalValue::F64(25.0),
             source_commands: vec!["iostat".to_string()],
+            detail: None,
         }],
         suggest: vec![],
     };
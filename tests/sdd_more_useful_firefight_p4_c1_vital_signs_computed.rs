@@ -5,7 +5,7 @@
 
 use usereport::analysis::compute_vital_signs;
 use usereport::finding::{Evidence, Finding, FindingKind, Severity};
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 fn make_signal(id: &str, value: f64) -> Signal {
     Signal {
@@ -16,6 +16,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -31,6 +32,7 @@ fn vital_signs_computed_from_signals_and_findings() {
             signal_id: "cpu.iowait_pct".to_string(),
             observed: SignalValue::F64(23.4),
             source_commands: vec![],
+            detail: None,
         }],
         suggest: vec![],
     };
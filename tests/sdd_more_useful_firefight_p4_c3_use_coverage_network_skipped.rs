@@ -25,7 +25,7 @@ fn use_coverage_network_all_skipped_is_not_covered() {
         skipped_with_dim("sar_tcp", "network", "saturation"),
         skipped_with_dim("sar_edev", "network", "errors"),
     ];
-    let coverage = compute_use_coverage(&results);
+    let coverage = compute_use_coverage(&results, &[]);
     let net_entries: Vec<_> = coverage.iter().filter(|e| e.resource == "network").collect();
     assert_eq!(net_entries.len(), 3, "must have 3 network entries");
     for e in net_entries {
@@ -18,7 +18,7 @@ fn use_coverage_network_saturation_covered_when_sar_tcp_succeeded() {
         stdout: "output".to_string(),
         run_time_ms: 5,
     }];
-    let coverage = compute_use_coverage(&results);
+    let coverage = compute_use_coverage(&results, &[]);
     let entry = coverage
         .iter()
         .find(|e| e.resource == "network" && e.aspect == "saturation")
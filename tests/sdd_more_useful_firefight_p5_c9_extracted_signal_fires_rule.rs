@@ -36,6 +36,8 @@ fn extracted_signal_fires_rule() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
     let engine = RuleEngine::new(vec![rule]);
     let ctx = CollectCtx::default();
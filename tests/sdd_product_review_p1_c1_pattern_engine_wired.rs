@@ -11,7 +11,7 @@
 
 use usereport::collector::CollectCtx;
 use usereport::pattern::PatternEngine;
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 #[test]
 fn pattern_engine_fires_time_wait_exhaustion() {
@@ -33,6 +33,7 @@ fn pattern_engine_fires_time_wait_exhaustion() {
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         },
         Signal {
             id: "net.connect_failures".to_string(),
@@ -42,6 +43,7 @@ fn pattern_engine_fires_time_wait_exhaustion() {
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         },
     ];
 
@@ -11,7 +11,7 @@ use usereport::collector::{CollectCtx, Collector};
 use usereport::finding::{Evidence, Finding, FindingKind, Severity};
 use usereport::renderer::JsonRenderer;
 use usereport::rule::{Predicate, Rule, RuleEngine};
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 const FREE_OUTPUT_LINUX: &str = "\
               total        used        free      shared  buff/cache   available
@@ -26,6 +26,7 @@ fn ctx(cpu_count: usize) -> CollectCtx {
         cgroup_path: None,
         baseline: None,
         cpu_count,
+        uname: None,
     }
 }
 
@@ -38,6 +39,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -93,6 +95,8 @@ fn ac_2_rule_predicate_match_emits_finding() {
         suggest: vec!["pidstat 1 5".to_string()],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
 
     let signals = vec![make_signal("cpu.run_queue", 8.0)];
@@ -131,6 +135,8 @@ fn ac_3_rule_predicate_non_match_emits_no_finding() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
 
     let signals = vec![make_signal("cpu.run_queue", 2.0)];
@@ -153,6 +159,8 @@ fn ac_3_rule_with_absent_signal_emits_no_finding() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
 
     let signals: Vec<Signal> = vec![];
@@ -244,6 +252,8 @@ fn ac_5_malformed_user_rule_does_not_block_builtins() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     }];
 
     let load_result = RulesLoader::new()
@@ -287,6 +297,8 @@ fn ac_7_rule_engine_is_deterministic_across_runs() {
             suggest: vec![],
             description: None,
             links: vec![],
+            levels: vec![],
+            inhibits: vec![],
         },
         Rule {
             id: "b.rule".to_string(),
@@ -297,6 +309,8 @@ fn ac_7_rule_engine_is_deterministic_across_runs() {
             suggest: vec![],
             description: None,
             links: vec![],
+            levels: vec![],
+            inhibits: vec![],
         },
     ];
 
@@ -331,6 +345,7 @@ fn ac_10_json_renderer_includes_signals_findings_checked_ok() {
             signal_id: "cpu.iowait_pct".to_string(),
             observed: SignalValue::F64(25.0),
             source_commands: Vec::new(),
+            detail: None,
         }],
         suggest: vec!["iotop -ao".to_string()],
     };
@@ -451,6 +466,7 @@ fn markdown_template_renders_summary_and_findings_sections() {
             signal_id: "cpu.iowait_pct".to_string(),
             observed: SignalValue::F64(42.0),
             source_commands: Vec::new(),
+            detail: None,
         }],
         suggest: vec!["iotop -ao".to_string()],
     };
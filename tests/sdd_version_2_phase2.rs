@@ -9,7 +9,7 @@ use usereport::baseline::store::{BaselineRecord, BaselineStore};
 use usereport::baseline::{annotate, outlier_findings};
 use usereport::diff::{DiffReport, diff};
 use usereport::finding::{Finding, FindingKind, Severity};
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 fn make_signal(id: &str, value: f64) -> Signal {
     Signal {
@@ -20,6 +20,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -147,6 +148,7 @@ fn ac_phase2_6_annotate_populates_baseline_stats() {
             BaselineRecord {
                 captured_at: chrono::Local::now(),
                 signals: sigs,
+                labels: HashMap::new(),
             }
         })
         .collect();
@@ -9,7 +9,7 @@ use usereport::collector::disk::DiskCollector;
 use usereport::collector::{CollectCtx, Collector};
 use usereport::finding::Severity;
 use usereport::rule::{Predicate, Rule, RuleEngine};
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 use usereport::{Command, ThreadRunner};
 
 const PROC_STAT_T0: &str = "\
@@ -53,6 +53,7 @@ fn ctx(cpu_count: usize) -> CollectCtx {
         cgroup_path: None,
         baseline: None,
         cpu_count,
+        uname: None,
     }
 }
 
@@ -65,6 +66,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -201,6 +203,8 @@ fn ac_phase3_5_analysis_run_populates_signals_and_findings() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
     let engine = RuleEngine::new(vec![rule]);
 
@@ -243,6 +247,8 @@ fn ac_phase3_6_pipeline_findings_are_severity_ordered() {
             suggest: vec![],
             description: None,
             links: vec![],
+            levels: vec![],
+            inhibits: vec![],
         },
         Rule {
             id: "crit.x".to_string(),
@@ -253,6 +259,8 @@ fn ac_phase3_6_pipeline_findings_are_severity_ordered() {
             suggest: vec![],
             description: None,
             links: vec![],
+            levels: vec![],
+            inhibits: vec![],
         },
     ];
     let engine = RuleEngine::new(rules);
@@ -315,6 +323,8 @@ fn ac_phase3_8_compute_exit_code_returns_one_when_pipeline_emits_warn() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
     let engine = RuleEngine::new(vec![rule]);
     let hostinfos: Vec<Command> = vec![];
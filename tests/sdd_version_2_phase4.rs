@@ -6,7 +6,7 @@ use usereport::baseline::stats::sample_stats;
 use usereport::collector::CollectCtx;
 use usereport::finding::Severity;
 use usereport::rule::{Predicate, Rule, RuleEngine, SignalIndex};
-use usereport::signal::{Signal, SignalValue, Trend, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Trend, Unit};
 
 fn make_signal(id: &str, value: f64) -> Signal {
     Signal {
@@ -17,6 +17,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -29,6 +30,7 @@ fn make_sampled_signal(id: &str, samples: Vec<f64>) -> Signal {
         samples: Some(samples),
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -39,6 +41,7 @@ fn ctx() -> CollectCtx {
         cgroup_path: None,
         baseline: None,
         cpu_count: 4,
+        uname: None,
     }
 }
 
@@ -291,6 +294,8 @@ fn ac_phase4_9_trend_rule_finding_evidence_cites_signal() {
         suggest: vec![],
         description: None,
         links: vec![],
+        levels: vec![],
+        inhibits: vec![],
     };
     let engine = RuleEngine::new(vec![rule]);
 
@@ -330,6 +335,7 @@ fn ac_phase4_10_markdown_shows_trend_for_sampled_signal() {
         samples: Some(vals),
         stats: Some(stats),
         baseline: None,
+        labels: Labels::new(),
     };
 
     let report =
@@ -5,7 +5,7 @@ use usereport::collector::CollectCtx;
 use usereport::collector::dmesg::DmesgCollector;
 use usereport::finding::FindingKind;
 use usereport::pattern::PatternEngine;
-use usereport::signal::{Signal, SignalValue, Unit};
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
 fn ctx() -> CollectCtx {
     CollectCtx {
@@ -14,6 +14,7 @@ fn ctx() -> CollectCtx {
         cgroup_path: None,
         baseline: None,
         cpu_count: 4,
+        uname: None,
     }
 }
 
@@ -26,6 +27,7 @@ fn make_signal(id: &str, value: f64) -> Signal {
         samples: None,
         stats: None,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
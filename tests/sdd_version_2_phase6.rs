@@ -21,6 +21,7 @@ fn ac_phase6_1_llm_output_required_fields_serialize() {
         },
         signals: vec![],
         findings: vec![],
+        near_misses: vec![],
         checked_ok: vec![],
         raw_excerpts: vec![],
     };
@@ -104,6 +105,7 @@ fn ac_phase6_4_redact_replaces_hostname_and_ip() {
         },
         signals: vec![],
         findings: vec![],
+        near_misses: vec![],
         checked_ok: vec![],
         raw_excerpts: vec![usereport::llm::LlmExcerpt {
             command: "dmesg".to_string(),
@@ -163,6 +165,7 @@ fn ac_phase6_6_schema_version_is_1() {
         },
         signals: vec![],
         findings: vec![],
+        near_misses: vec![],
         checked_ok: vec![],
         raw_excerpts: vec![],
     };
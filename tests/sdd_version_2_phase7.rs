@@ -1,7 +1,7 @@
 //! Phase 7 acceptance-criteria tests — eBPF opt-in collectors.
 
 use usereport::baseline::stats::sample_stats;
-use usereport::signal::{SampleStats, Signal, SignalValue, Unit};
+use usereport::signal::{Labels, SampleStats, Signal, SignalValue, Unit};
 
 fn dummy_signal_with_samples(id: &str, samples: Vec<f64>) -> Signal {
     use chrono::Local;
@@ -14,6 +14,7 @@ fn dummy_signal_with_samples(id: &str, samples: Vec<f64>) -> Signal {
         samples: Some(samples),
         stats,
         baseline: None,
+        labels: Labels::new(),
     }
 }
 
@@ -144,6 +145,7 @@ mod bpf_tests {
                 samples: None,
                 stats: None,
                 baseline: None,
+                labels: usereport::signal::Labels::new(),
             })
             .collect();
 
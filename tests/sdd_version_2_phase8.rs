@@ -48,7 +48,7 @@ fn ac_phase8_5_postgres_rules_fire_on_fixture_signals() {
     use usereport::collector::CollectCtx;
     use usereport::finding::Severity;
     use usereport::rule::RuleEngine;
-    use usereport::signal::{Signal, SignalValue, Unit};
+    use usereport::signal::{Labels, Signal, SignalValue, Unit};
 
     let rules = load_workload_rules("postgres").expect("postgres workload");
     let engine = RuleEngine::new(rules);
@@ -66,6 +66,7 @@ fn ac_phase8_5_postgres_rules_fire_on_fixture_signals() {
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         },
         Signal {
             id: "pg.cache_hit_pct".to_string(),
@@ -75,6 +76,7 @@ fn ac_phase8_5_postgres_rules_fire_on_fixture_signals() {
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         },
         Signal {
             id: "pg.lock_waits".to_string(),
@@ -84,6 +86,7 @@ fn ac_phase8_5_postgres_rules_fire_on_fixture_signals() {
             samples: None,
             stats: None,
             baseline: None,
+            labels: Labels::new(),
         },
     ];
 
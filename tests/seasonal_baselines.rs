@@ -0,0 +1,99 @@
+//! Time-of-day seasonal baselines: a nightly batch job is compared with
+//! previous nights, not with the afternoon.
+#![cfg(feature = "bin")]
+
+use chrono::{DateTime, Local, TimeZone};
+
+mod common;
+
+use common::{analysis, signal_in};
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::baseline::{BaselineRecord, Seasonality};
+use usereport::rule::RuleEngine;
+use usereport::signal::{Signal, Unit};
+
+/// `hour`:00 on `day` October 2026.
+fn at(day: u32, hour: u32) -> DateTime<Local> {
+    Local.with_ymd_and_hms(2026, 10, day, hour, 0, 0).earliest().unwrap()
+}
+
+fn signal(v: f64, at: DateTime<Local>) -> Signal {
+    Signal {
+        at,
+        ..signal_in("disk.max_util_pct", v, Unit::Pct)
+    }
+}
+
+/// Two weeks of runs: the backup saturates the disks at 03:00, the day is
+/// quiet.
+fn history() -> Vec<BaselineRecord> {
+    let mut records = Vec::new();
+    for day in 5..=16 {
+        let quiet = 10.0 + (day % 3) as f64;
+        for (hour, util) in [
+            (3, 92.0 + (day % 4) as f64),
+            (9, quiet),
+            (12, quiet),
+            (15, quiet),
+            (18, quiet),
+        ] {
+            let mut r = BaselineRecord::from_signals(&[signal(util, at(day, hour))]);
+            r.captured_at = at(day, hour);
+            records.push(r);
+        }
+    }
+    records
+}
+
+fn run(observed: Signal, seasonality: Option<Seasonality>) -> AnalysisReport {
+    let mut analysis = analysis(vec![observed], RuleEngine::new(vec![])).with_baseline_records(history());
+    if let Some(seasonality) = seasonality {
+        analysis = analysis.with_baseline_seasonality(seasonality);
+    }
+    analysis.run(Context::new()).expect("run ok")
+}
+
+fn finding_ids(report: &AnalysisReport) -> Vec<&str> {
+    report.findings().iter().map(|f| f.id.as_str()).collect()
+}
+
+#[test]
+fn nightly_jobs_are_compared_with_previous_nights() {
+    let tonight = || signal(94.0, at(17, 3));
+
+    // Against the whole window, mostly quiet daytime runs, the backup looks
+    // like an anomaly.
+    let report = run(tonight(), None);
+    assert_eq!(finding_ids(&report), vec!["baseline.outlier.disk.max_util_pct"]);
+
+    let report = run(tonight(), Some(Seasonality::Hourly));
+    assert!(report.findings().is_empty(), "{:?}", report.findings());
+
+    // The same load in the afternoon is still an outlier.
+    let report = run(signal(94.0, at(17, 15)), Some(Seasonality::Hourly));
+    assert_eq!(finding_ids(&report), vec!["baseline.outlier.disk.max_util_pct"]);
+}
+
+#[test]
+fn sparse_weekly_buckets_fall_back_to_the_whole_window() {
+    // Saturdays 03:00: only 10 October is in the history.
+    let report = run(signal(94.0, at(17, 3)), Some(Seasonality::Weekly));
+    let baseline = report.signals()[0].baseline.as_ref().expect("annotated");
+    assert!(baseline.p50 < 92.0, "p50 = {}", baseline.p50);
+
+    // Fridays 03:00 have two previous weeks, still too few.
+    let report = run(signal(94.0, at(16, 3)), Some(Seasonality::Weekly));
+    let baseline = report.signals()[0].baseline.as_ref().expect("annotated");
+    assert!(baseline.p50 < 92.0, "p50 = {}", baseline.p50);
+}
+
+#[test]
+fn unknown_seasonality_is_rejected() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_usereport"))
+        .args(["--baseline-seasonality", "daily"])
+        .output()
+        .expect("run binary");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("hourly") && stderr.contains("weekly"), "{stderr}");
+}
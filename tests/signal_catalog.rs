@@ -0,0 +1,68 @@
+//! Signal catalog: collector-declared signal metadata feeds USE coverage, the
+//! LLM output and `usereport signals`.
+#![cfg(feature = "bin")]
+
+use usereport::analysis::compute_use_coverage;
+use usereport::llm::LlmSignal;
+use usereport::signal::catalog::SignalKind;
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
+
+fn signal(id: &str, v: f64) -> Signal {
+    Signal {
+        id: id.to_string(),
+        value: SignalValue::F64(v),
+        unit: Unit::Pct,
+        at: chrono::Local::now(),
+        samples: None,
+        stats: None,
+        baseline: None,
+        labels: Labels::new(),
+    }
+}
+
+#[test]
+fn collected_signals_cover_their_use_cells() {
+    let coverage = compute_use_coverage(&[], &[signal("disk.sda.util_pct", 40.0), signal("api.p99_ms", 3.0)]);
+    let covered: Vec<(&str, &str)> = coverage
+        .iter()
+        .filter(|e| e.covered)
+        .map(|e| (e.resource.as_str(), e.aspect.as_str()))
+        .collect();
+    assert_eq!(covered, vec![("disk", "utilization")]);
+}
+
+#[test]
+fn llm_signals_carry_kind_and_description() {
+    let known = LlmSignal::from(signal("mem.free_pct", 12.0));
+    assert_eq!(known.kind, Some(SignalKind::Gauge));
+    assert!(known.description.is_some());
+    let json = serde_json::to_string(&LlmSignal::from(signal("api.p99_ms", 3.0))).unwrap();
+    assert!(!json.contains("kind") && !json.contains("description"), "{json}");
+}
+
+#[test]
+fn signals_subcommand_filters_by_pattern_and_resource() {
+    let ids = |pattern, resource| -> Vec<&str> {
+        usereport::cli::select_signals(pattern, resource)
+            .iter()
+            .map(|s| s.id)
+            .collect()
+    };
+    assert!(ids(Some("disk.*"), None).contains(&"disk.*.util_pct"));
+    assert!(ids(Some("disk.*"), None).iter().all(|id| id.starts_with("disk.")));
+    assert_eq!(ids(Some("disk.sda.util_pct"), None), vec!["disk.*.util_pct"]);
+    assert!(ids(None, Some("memory")).contains(&"mem.free_pct"));
+    assert!(ids(Some("nope.*"), None).is_empty());
+
+    let mut out = Vec::new();
+    usereport::cli::show_signals_inner(
+        &usereport::cli::select_signals(Some("mem.free_pct"), None),
+        false,
+        &mut out,
+    );
+    let s = String::from_utf8(out).unwrap();
+    assert!(
+        s.contains("mem.free_pct") && s.contains("gauge") && s.contains("memory/utilization"),
+        "{s}"
+    );
+}
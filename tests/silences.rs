@@ -0,0 +1,87 @@
+//! Silences: findings matched by a `[[silence]]` entry move out of
+//! `findings` and into `silenced` until the entry expires.
+#![cfg(feature = "bin")]
+
+mod common;
+
+use common::{analysis, signal};
+use usereport::Renderer;
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::cli::{ExitOn, compute_exit_code};
+use usereport::llm::LlmOutput;
+use usereport::renderer::TemplateRenderer;
+use usereport::rule::{RuleEngine, parse_rules_toml};
+use usereport::silence::parse_silences;
+
+const RULES: &str = r#"
+[[rule]]
+id = "net.irq_imbalance"
+when = "net.max_cpu_irq_pct > 80"
+severity = "crit"
+summary = "IRQs concentrated on one CPU."
+evidence = ["net.max_cpu_irq_pct"]
+
+[[rule]]
+id = "mem.pressure"
+when = "mem.free_pct < 10"
+severity = "warn"
+summary = "Free memory low."
+"#;
+
+fn run(silences: &str) -> AnalysisReport {
+    let signals = vec![signal("net.max_cpu_irq_pct", 92.0), signal("mem.free_pct", 3.0)];
+    analysis(signals, RuleEngine::new(parse_rules_toml(RULES).expect("rules")))
+        .with_silences(parse_silences(silences).expect("silences"))
+        .run(Context::new())
+        .expect("run ok")
+}
+
+const ACTIVE: &str = r#"
+[[silence]]
+id      = "net.*"
+when    = "net.max_cpu_irq_pct < 99"
+expires = 9999-12-31
+reason  = "single-queue NIC"
+"#;
+
+#[test]
+fn silenced_finding_leaves_findings_exit_code_and_llm_output() {
+    let report = run(ACTIVE);
+    let ids: Vec<&str> = report.findings().iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, vec!["mem.pressure"]);
+    assert_eq!(report.silenced().len(), 1);
+    assert_eq!(report.silenced()[0].finding.id, "net.irq_imbalance");
+    assert_eq!(report.silenced()[0].reason, "single-queue NIC");
+
+    // The silenced CRIT finding no longer counts.
+    assert_eq!(compute_exit_code(ExitOn::Crit, report.findings()), 0);
+    assert_eq!(compute_exit_code(ExitOn::Warn, report.findings()), 1);
+
+    let llm = LlmOutput::from_report(&report, false);
+    let llm_ids: Vec<&str> = llm.findings.iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(llm_ids, vec!["mem.pressure"]);
+}
+
+#[test]
+fn expired_silence_no_longer_applies() {
+    let expired = ACTIVE.replace("9999-12-31", "2000-01-01");
+    let report = run(&expired);
+    assert!(report.silenced().is_empty());
+    assert!(report.findings().iter().any(|f| f.id == "net.irq_imbalance"));
+}
+
+#[test]
+fn markdown_lists_silenced_findings_with_reason_and_expiry() {
+    let report = run(ACTIVE);
+    let mut out = Vec::new();
+    TemplateRenderer::new(include_str!("../contrib/markdown.j2"))
+        .render(&report, &mut out)
+        .expect("render ok");
+    let s = String::from_utf8(out).unwrap();
+    assert!(s.contains("## Silenced"), "{s}");
+    assert!(
+        s.contains("- [CRIT] `net.irq_imbalance` — single-queue NIC (until 9999-12-31)"),
+        "{s}"
+    );
+    assert!(!s.contains("### [CRIT] net.irq_imbalance"), "{s}");
+}
@@ -0,0 +1,85 @@
+//! Temporal patterns: `together(...)` needs both conditions in the same
+//! samples, `leads(...)` needs the first to start before the second.
+#![cfg(feature = "bin")]
+
+use usereport::collector::CollectCtx;
+use usereport::pattern::PatternEngine;
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
+
+const PATTERNS: &str = r#"
+[[pattern]]
+id = "blocked_with_iowait"
+when = "together(dmesg.blocked_task_count > 0, cpu.iowait_pct > 10, 80%)"
+severity = "warn"
+summary = "Blocked tasks and iowait in the same intervals."
+
+[[pattern.test]]
+name = "in step"
+signals = { "dmesg.blocked_task_count" = [0, 2, 3, 4], "cpu.iowait_pct" = [5, 40, 35, 50] }
+fires = true
+
+[[pattern.test]]
+name = "out of step"
+signals = { "dmesg.blocked_task_count" = [2, 3, 0, 0], "cpu.iowait_pct" = [5, 5, 40, 50] }
+fires = false
+
+[[pattern]]
+id = "busy_then_iowait"
+when = "leads(cpu.usr_pct > 80, cpu.iowait_pct > 30, 2)"
+severity = "warn"
+summary = "iowait followed user CPU saturation."
+
+[[pattern.test]]
+signals = { "cpu.usr_pct" = [50, 90, 95, 90], "cpu.iowait_pct" = [5, 5, 40, 45] }
+fires = true
+
+[[pattern.test]]
+name = "iowait came first"
+signals = { "cpu.usr_pct" = [50, 90, 95, 90], "cpu.iowait_pct" = [40, 5, 40, 45] }
+fires = false
+"#;
+
+fn sampled(id: &str, samples: &[f64]) -> Signal {
+    Signal {
+        id: id.to_string(),
+        value: SignalValue::F64(*samples.last().unwrap()),
+        unit: Unit::None,
+        at: chrono::Local::now(),
+        samples: Some(samples.to_vec()),
+        stats: None,
+        baseline: None,
+        labels: Labels::new(),
+    }
+}
+
+#[test]
+fn pattern_tests_cover_both_relations() {
+    let engine = PatternEngine::from_toml(PATTERNS).expect("patterns");
+    assert_eq!(engine.tests().len(), 4);
+    for test in engine.tests() {
+        let findings = engine.run_with_findings(&test.signals, &test.findings, &test.ctx);
+        let fired = findings.iter().find(|f| f.id == test.id).map(|f| f.severity);
+        assert_eq!(test.check(fired), Ok(()), "{:?}", test.name);
+    }
+}
+
+#[test]
+fn evidence_names_both_sides_with_the_overlap() {
+    let engine = PatternEngine::from_toml(PATTERNS).expect("patterns");
+    let signals = vec![
+        sampled("dmesg.blocked_task_count", &[0.0, 2.0, 3.0, 4.0, 1.0]),
+        sampled("cpu.iowait_pct", &[5.0, 40.0, 35.0, 50.0, 60.0]),
+    ];
+    let ctx = CollectCtx {
+        interval: Some(std::time::Duration::from_secs(5)),
+        ..CollectCtx::default()
+    };
+    let findings = engine.run(&signals, &ctx);
+    assert_eq!(findings.len(), 1);
+    let ids: Vec<&str> = findings[0].evidence.iter().map(|e| e.signal_id.as_str()).collect();
+    assert_eq!(ids, vec!["dmesg.blocked_task_count", "cpu.iowait_pct"]);
+    assert_eq!(
+        findings[0].evidence[0].detail.as_deref(),
+        Some("both breached in 4/4 samples where either did (100%, 20s) of 5")
+    );
+}
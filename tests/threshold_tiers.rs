@@ -0,0 +1,51 @@
+//! Multi-level rules: the Threshold column lists every tier of a signal.
+
+use std::collections::HashMap;
+
+use chrono::Local;
+use usereport::Renderer;
+use usereport::analysis::{AnalysisReport, Context, ThresholdInfo};
+use usereport::finding::{Severity, ThresholdTier};
+use usereport::renderer::TemplateRenderer;
+use usereport::signal::{Labels, Signal, SignalValue, Unit};
+
+const MARKDOWN: &str = include_str!("../contrib/markdown.j2");
+
+#[test]
+fn markdown_threshold_column_lists_all_tiers() {
+    let tier = |severity, value| ThresholdTier {
+        severity,
+        op: ">".to_string(),
+        value,
+    };
+    let mut thresholds = HashMap::new();
+    thresholds.insert(
+        "disk.max_util_pct".to_string(),
+        ThresholdInfo {
+            severity: Severity::Crit,
+            op: ">".to_string(),
+            value: 95.0,
+            tiers: vec![tier(Severity::Warn, 80.0), tier(Severity::Crit, 95.0)],
+        },
+    );
+    let signal = Signal {
+        id: "disk.max_util_pct".to_string(),
+        value: SignalValue::F64(85.0),
+        unit: Unit::Pct,
+        at: Local::now(),
+        samples: None,
+        stats: None,
+        baseline: None,
+        labels: Labels::new(),
+    };
+    let mut report =
+        AnalysisReport::new_with_diagnostics(Context::new(), vec![], vec![], 1, 64, vec![signal], vec![], vec![]);
+    report.signal_thresholds = thresholds;
+
+    let mut out = Vec::new();
+    TemplateRenderer::new(MARKDOWN)
+        .render(&report, &mut out)
+        .expect("render ok");
+    let s = String::from_utf8(out).unwrap();
+    assert!(s.contains("> 80% (Warn), > 95% (Crit)"), "{s}");
+}
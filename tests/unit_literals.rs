@@ -0,0 +1,99 @@
+//! Unit-suffixed literals in rules, and humanized values in the templates.
+#![cfg(feature = "bin")]
+
+mod common;
+
+use common::{analysis, signal_in as signal};
+use usereport::Renderer;
+use usereport::analysis::{AnalysisReport, Context};
+use usereport::renderer::TemplateRenderer;
+use usereport::rule::{RuleEngine, parse_rules_toml};
+use usereport::signal::{Signal, Unit, label};
+
+const RULES: &str = r#"
+[[rule]]
+id = "mem.low"
+when = "mem.free_mb < 2GiB"
+severity = "warn"
+summary = "Free memory low."
+
+[[rule]]
+id = "disk.slow"
+when = "any(disk.*.await_ms) > 500ms"
+severity = "crit"
+summary = "Disk latency high."
+"#;
+
+fn run(signals: Vec<Signal>) -> AnalysisReport {
+    analysis(signals, RuleEngine::new(parse_rules_toml(RULES).expect("rules")))
+        .run(Context::new())
+        .expect("run ok")
+}
+
+#[test]
+fn rules_compare_quantities_in_the_signal_unit() {
+    let report = run(vec![
+        signal("mem.free_mb", 1536.0, Unit::Megabytes),
+        signal("disk.sda.await_ms", 1200.0, Unit::MillisPerOp).with_label(label::DEVICE, "sda"),
+    ]);
+    let ids: Vec<&str> = report.findings().iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, vec!["disk.slow", "mem.low"]);
+
+    let report = run(vec![
+        signal("mem.free_mb", 4096.0, Unit::Megabytes),
+        signal("disk.sda.await_ms", 20.0, Unit::MillisPerOp).with_label(label::DEVICE, "sda"),
+    ]);
+    assert!(report.findings().is_empty(), "{:?}", report.findings());
+}
+
+#[test]
+fn rules_with_mismatched_units_fail_to_load() {
+    let rules = r#"
+[[rule]]
+id = "mem.bad"
+when = "mem.free_pct < 2GiB"
+severity = "warn"
+summary = "Never loads."
+"#;
+    let err = parse_rules_toml(rules).expect_err("percent vs size");
+    assert!(
+        err.to_string().contains("mem.free_pct is in percent, 2GiB is a size"),
+        "{err}"
+    );
+}
+
+#[test]
+fn host_memory_compares_as_a_size() {
+    let rules = r#"
+[[rule]]
+id = "host.small"
+when = "host.mem_total_bytes < 8GiB"
+severity = "info"
+summary = "Small host."
+"#;
+    let engine = RuleEngine::new(parse_rules_toml(rules).expect("bytes vs size"));
+    let report = analysis(
+        vec![signal("host.mem_total_bytes", 4_294_967_296.0, Unit::Bytes)],
+        engine,
+    )
+    .run(Context::new())
+    .expect("run ok");
+    let ids: Vec<&str> = report.findings().iter().map(|f| f.id.as_str()).collect();
+    assert_eq!(ids, vec!["host.small"]);
+}
+
+#[test]
+fn markdown_humanizes_values_and_thresholds() {
+    let report = run(vec![
+        signal("mem.free_mb", 1536.0, Unit::Megabytes),
+        signal("disk.sda.await_ms", 1200.0, Unit::MillisPerOp).with_label(label::DEVICE, "sda"),
+    ]);
+    let mut out = Vec::new();
+    TemplateRenderer::new(include_str!("../contrib/markdown.j2"))
+        .render(&report, &mut out)
+        .expect("render ok");
+    let s = String::from_utf8(out).unwrap();
+    assert!(s.contains("| `mem.free_mb` | 1.5 GiB | — | < 2 GiB (Warn) |"), "{s}");
+    assert!(s.contains("`disk.sda.await_ms` = `1.2 s`"), "{s}");
+    assert!(s.contains("| `sda` | 1.2 s |"), "{s}");
+}